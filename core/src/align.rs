@@ -9,6 +9,36 @@ pub enum Align {
 
     /// Align at the end of the axis.
     End,
+
+    /// Align the first baselines of the contents.
+    ///
+    /// Only rows can align their children by their baseline. Anywhere else,
+    /// it aligns at the start of the axis.
+    Baseline,
+}
+
+/// The distribution of the contents on the main axis of a container.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Justify {
+    /// Pack the contents at the start of the axis.
+    Start,
+
+    /// Pack the contents at the center of the axis.
+    Center,
+
+    /// Pack the contents at the end of the axis.
+    End,
+
+    /// Distribute the free space between the contents, leaving none at the
+    /// edges.
+    SpaceBetween,
+
+    /// Distribute the free space around every element of the contents, so
+    /// the edges get half the space found between two elements.
+    SpaceAround,
+
+    /// Distribute the free space evenly between the contents and the edges.
+    SpaceEvenly,
 }
 
 /// The horizontal alignment of some resource.
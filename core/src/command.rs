@@ -1,3 +1,6 @@
+//! Perform actions in the background.
+use crate::{font, Font};
+
 use futures::future::{BoxFuture, Future, FutureExt};
 
 /// A collection of async operations.
@@ -7,7 +10,7 @@ use futures::future::{BoxFuture, Future, FutureExt};
 ///
 /// [`Command`]: struct.Command.html
 pub struct Command<T> {
-    futures: Vec<BoxFuture<'static, T>>,
+    actions: Vec<Action<T>>,
 }
 
 impl<T> Command<T> {
@@ -18,7 +21,7 @@ impl<T> Command<T> {
     /// [`Command`]: struct.Command.html
     pub fn none() -> Self {
         Self {
-            futures: Vec::new(),
+            actions: Vec::new(),
         }
     }
 
@@ -30,27 +33,59 @@ impl<T> Command<T> {
         f: impl Fn(T) -> A + 'static + Send,
     ) -> Command<A> {
         Command {
-            futures: vec![future.map(f).boxed()],
+            actions: vec![Action::Future(future.map(f).boxed())],
+        }
+    }
+
+    /// Creates a [`Command`] that loads a font at runtime.
+    ///
+    /// Once loaded, the font is delivered as a [`Font::Loaded`] in the
+    /// message produced by the given function. The font is also preferred
+    /// over the fonts installed in the system when a [`Family::Name`] with
+    /// the given name is used.
+    ///
+    /// This is useful for fonts that are downloaded or read from disk. If
+    /// your font is known at compile time, consider using a
+    /// [`Font::External`] instead.
+    ///
+    /// [`Command`]: struct.Command.html
+    /// [`Font::Loaded`]: ../enum.Font.html#variant.Loaded
+    /// [`Family::Name`]: ../font/enum.Family.html#variant.Name
+    /// [`Font::External`]: ../enum.Font.html#variant.External
+    pub fn load_font(
+        name: impl Into<String>,
+        bytes: impl Into<Vec<u8>>,
+        f: impl FnOnce(Font) -> T,
+    ) -> Self {
+        let id = font::Id::unique();
+
+        Self {
+            actions: vec![Action::LoadFont {
+                id,
+                name: name.into(),
+                bytes: bytes.into(),
+                message: f(Font::Loaded(id)),
+            }],
         }
     }
 
     /// Creates a [`Command`] that performs the actions of all the givens
-    /// futures.
+    /// commands.
     ///
     /// Once this command is run, all the futures will be exectued at once.
     ///
     /// [`Command`]: struct.Command.html
     pub fn batch(commands: impl Iterator<Item = Command<T>>) -> Self {
         Self {
-            futures: commands.flat_map(|command| command.futures).collect(),
+            actions: commands.flat_map(|command| command.actions).collect(),
         }
     }
 
-    /// Converts a [`Command`] into its underlying list of futures.
+    /// Converts a [`Command`] into its underlying list of actions.
     ///
     /// [`Command`]: struct.Command.html
-    pub fn futures(self) -> Vec<BoxFuture<'static, T>> {
-        self.futures
+    pub fn actions(self) -> Vec<Action<T>> {
+        self.actions
     }
 }
 
@@ -60,7 +95,7 @@ where
 {
     fn from(future: A) -> Self {
         Self {
-            futures: vec![future.boxed()],
+            actions: vec![Action::Future(future.boxed())],
         }
     }
 }
@@ -70,3 +105,41 @@ impl<T> std::fmt::Debug for Command<T> {
         f.debug_struct("Command").finish()
     }
 }
+
+/// An operation of a [`Command`].
+///
+/// Runtimes need to handle every kind of action when running a [`Command`].
+///
+/// [`Command`]: struct.Command.html
+pub enum Action<T> {
+    /// Runs a future, producing a message once it finishes.
+    Future(BoxFuture<'static, T>),
+
+    /// Loads a font at runtime, producing a message once it is loaded.
+    LoadFont {
+        /// The identifier of the font
+        id: font::Id,
+
+        /// The name of the font
+        name: String,
+
+        /// The bytes of the font
+        bytes: Vec<u8>,
+
+        /// The message to produce once the font is loaded
+        message: T,
+    },
+}
+
+impl<T> std::fmt::Debug for Action<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Action::Future(_) => f.debug_tuple("Future").finish(),
+            Action::LoadFont { id, name, .. } => f
+                .debug_struct("LoadFont")
+                .field("id", id)
+                .field("name", name)
+                .finish(),
+        }
+    }
+}
@@ -0,0 +1,34 @@
+/// The strategy used to fit the contents of a widget to its bounding box.
+///
+/// It mirrors the [`object-fit`] property of CSS.
+///
+/// [`object-fit`]: https://developer.mozilla.org/en-US/docs/Web/CSS/object-fit
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ContentFit {
+    /// Scale the contents as big as possible while keeping their aspect
+    /// ratio, without overflowing the bounds.
+    Contain,
+
+    /// Scale the contents to cover the whole bounds while keeping their
+    /// aspect ratio. Any overflow is clipped.
+    Cover,
+
+    /// Stretch the contents to fill the bounds, ignoring their aspect ratio.
+    Fill,
+
+    /// Behave like [`None`] or [`Contain`], whichever makes the contents
+    /// smaller.
+    ///
+    /// [`None`]: #variant.None
+    /// [`Contain`]: #variant.Contain
+    ScaleDown,
+
+    /// Keep the original size of the contents. Any overflow is clipped.
+    None,
+}
+
+impl Default for ContentFit {
+    fn default() -> ContentFit {
+        ContentFit::Contain
+    }
+}
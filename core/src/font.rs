@@ -1,3 +1,5 @@
+//! Choose the fonts of your text.
+
 /// A font.
 #[derive(Debug, Clone, Copy)]
 pub enum Font {
@@ -15,4 +17,283 @@ pub enum Font {
         /// The bytes of the external font
         bytes: &'static [u8],
     },
+
+    /// A font loaded at runtime with [`Command::load_font`].
+    ///
+    /// The [`Id`] is produced by the command and delivered in its message
+    /// once the font is loaded.
+    ///
+    /// [`Command::load_font`]: ../struct.Command.html#method.load_font
+    /// [`Id`]: struct.Id.html
+    Loaded(Id),
+
+    /// A font described by its family and properties.
+    ///
+    /// Renderers look for the closest match among the available fonts,
+    /// falling back to the default font when none is found.
+    Descriptor(Descriptor),
+}
+
+/// The identifier of a font loaded at runtime.
+///
+/// [`Command::load_font`] creates a unique [`Id`] for every font it loads.
+///
+/// [`Command::load_font`]: ../struct.Command.html#method.load_font
+/// [`Id`]: struct.Id.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Id(u64);
+
+impl Id {
+    #[cfg(feature = "command")]
+    pub(crate) fn unique() -> Id {
+        use std::sync::atomic::{AtomicU64, Ordering};
+
+        static NEXT: AtomicU64 = AtomicU64::new(0);
+
+        Id(NEXT.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+impl std::hash::Hash for Font {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+
+        match self {
+            Font::Default => {}
+            // The bytes of an external font are identified by their address,
+            // as hashing all of them would be too expensive
+            Font::External { name, bytes } => {
+                name.hash(state);
+                bytes.as_ptr().hash(state);
+                bytes.len().hash(state);
+            }
+            Font::Loaded(id) => id.hash(state),
+            Font::Descriptor(descriptor) => descriptor.hash(state),
+        }
+    }
+}
+
+impl From<Descriptor> for Font {
+    fn from(descriptor: Descriptor) -> Font {
+        Font::Descriptor(descriptor)
+    }
+}
+
+/// The description of a font, used to find the closest match among the
+/// available fonts.
+///
+/// # Example
+///
+/// ```
+/// use iced_core::font::{Descriptor, Family, Style};
+/// use iced_core::{Font, Weight};
+///
+/// let font: Font = Descriptor::new(Family::Name("Fira Sans"))
+///     .weight(Weight::Bold)
+///     .style(Style::Italic)
+///     .into();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Descriptor {
+    /// The [`Family`] of the font
+    ///
+    /// [`Family`]: enum.Family.html
+    pub family: Family,
+
+    /// The [`Weight`] of the font
+    ///
+    /// [`Weight`]: enum.Weight.html
+    pub weight: Weight,
+
+    /// The [`Stretch`] of the font
+    ///
+    /// [`Stretch`]: enum.Stretch.html
+    pub stretch: Stretch,
+
+    /// The [`Style`] of the font
+    ///
+    /// [`Style`]: enum.Style.html
+    pub style: Style,
+}
+
+impl Descriptor {
+    /// Creates a new [`Descriptor`] of a font of the given [`Family`] with
+    /// normal properties.
+    ///
+    /// [`Descriptor`]: struct.Descriptor.html
+    /// [`Family`]: enum.Family.html
+    pub fn new(family: Family) -> Self {
+        Descriptor {
+            family,
+            weight: Weight::Normal,
+            stretch: Stretch::Normal,
+            style: Style::Normal,
+        }
+    }
+
+    /// Sets the [`Weight`] of the [`Descriptor`].
+    ///
+    /// [`Weight`]: enum.Weight.html
+    /// [`Descriptor`]: struct.Descriptor.html
+    pub fn weight(mut self, weight: Weight) -> Self {
+        self.weight = weight;
+        self
+    }
+
+    /// Sets the [`Stretch`] of the [`Descriptor`].
+    ///
+    /// [`Stretch`]: enum.Stretch.html
+    /// [`Descriptor`]: struct.Descriptor.html
+    pub fn stretch(mut self, stretch: Stretch) -> Self {
+        self.stretch = stretch;
+        self
+    }
+
+    /// Sets the [`Style`] of the [`Descriptor`].
+    ///
+    /// [`Style`]: enum.Style.html
+    /// [`Descriptor`]: struct.Descriptor.html
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+/// The family of a font.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Family {
+    /// A specific family, like "Fira Sans".
+    ///
+    /// Fonts loaded at runtime with this name are preferred over the fonts
+    /// installed in the system.
+    Name(&'static str),
+    /// Fonts with serifs.
+    Serif,
+    /// Fonts without serifs.
+    SansSerif,
+    /// Fonts whose characters all have the same width.
+    Monospace,
+    /// Fonts imitating handwriting.
+    Cursive,
+    /// Decorative fonts.
+    Fantasy,
+}
+
+/// The weight of a font.
+///
+/// The variants follow the common CSS weight names, from the thinnest to the
+/// boldest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Weight {
+    /// A weight of 100.
+    Thin,
+    /// A weight of 200.
+    ExtraLight,
+    /// A weight of 300.
+    Light,
+    /// A weight of 400.
+    Normal,
+    /// A weight of 500.
+    Medium,
+    /// A weight of 600.
+    Semibold,
+    /// A weight of 700.
+    Bold,
+    /// A weight of 800.
+    ExtraBold,
+    /// A weight of 900.
+    Black,
+}
+
+impl Default for Weight {
+    fn default() -> Weight {
+        Weight::Normal
+    }
+}
+
+impl Weight {
+    /// Returns the numeric value of the [`Weight`], between 100 and 900.
+    ///
+    /// [`Weight`]: enum.Weight.html
+    pub fn value(self) -> u16 {
+        match self {
+            Weight::Thin => 100,
+            Weight::ExtraLight => 200,
+            Weight::Light => 300,
+            Weight::Normal => 400,
+            Weight::Medium => 500,
+            Weight::Semibold => 600,
+            Weight::Bold => 700,
+            Weight::ExtraBold => 800,
+            Weight::Black => 900,
+        }
+    }
+}
+
+/// The width of the characters of a font.
+///
+/// The variants follow the common CSS stretch names, from the narrowest to
+/// the widest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Stretch {
+    /// A width of 50%.
+    UltraCondensed,
+    /// A width of 62.5%.
+    ExtraCondensed,
+    /// A width of 75%.
+    Condensed,
+    /// A width of 87.5%.
+    SemiCondensed,
+    /// A width of 100%.
+    Normal,
+    /// A width of 112.5%.
+    SemiExpanded,
+    /// A width of 125%.
+    Expanded,
+    /// A width of 150%.
+    ExtraExpanded,
+    /// A width of 200%.
+    UltraExpanded,
+}
+
+impl Default for Stretch {
+    fn default() -> Stretch {
+        Stretch::Normal
+    }
+}
+
+impl Stretch {
+    /// Returns the width of the [`Stretch`], relative to a normal font.
+    ///
+    /// [`Stretch`]: enum.Stretch.html
+    pub fn value(self) -> f32 {
+        match self {
+            Stretch::UltraCondensed => 0.5,
+            Stretch::ExtraCondensed => 0.625,
+            Stretch::Condensed => 0.75,
+            Stretch::SemiCondensed => 0.875,
+            Stretch::Normal => 1.0,
+            Stretch::SemiExpanded => 1.125,
+            Stretch::Expanded => 1.25,
+            Stretch::ExtraExpanded => 1.5,
+            Stretch::UltraExpanded => 2.0,
+        }
+    }
+}
+
+/// The slant of a font.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Style {
+    /// An upright font.
+    Normal,
+    /// A cursive, slanted font.
+    Italic,
+    /// A slanted version of an upright font.
+    Oblique,
+}
+
+impl Default for Style {
+    fn default() -> Style {
+        Style::Normal
+    }
 }
@@ -15,26 +15,31 @@
 #![deny(unsafe_code)]
 #![deny(rust_2018_idioms)]
 
+pub mod font;
+
 mod align;
 mod background;
 mod color;
-mod font;
+mod content_fit;
 mod length;
 mod point;
 mod rectangle;
+mod text;
 mod vector;
 
-pub use align::{Align, HorizontalAlignment, VerticalAlignment};
+pub use align::{Align, HorizontalAlignment, Justify, VerticalAlignment};
 pub use background::Background;
 pub use color::Color;
-pub use font::Font;
+pub use content_fit::ContentFit;
+pub use font::{Font, Weight};
 pub use length::Length;
 pub use point::Point;
 pub use rectangle::Rectangle;
+pub use text::{Overflow, Wrap};
 pub use vector::Vector;
 
 #[cfg(feature = "command")]
-mod command;
+pub mod command;
 
 #[cfg(feature = "command")]
 pub use command::Command;
@@ -0,0 +1,43 @@
+/// The strategy used to break the lines of some text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Wrap {
+    /// Break lines between words. Words longer than a line overflow.
+    Word,
+
+    /// Break lines between any characters.
+    Character,
+
+    /// Never break lines, except on explicit line breaks.
+    None,
+}
+
+impl Default for Wrap {
+    fn default() -> Wrap {
+        Wrap::Word
+    }
+}
+
+/// The strategy used to handle text that does not fit its bounds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Overflow {
+    /// Draw the text outside of the bounds.
+    Visible,
+
+    /// Cut the text at the bounds.
+    Clip,
+
+    /// Truncate the text at its end and append an ellipsis (`…`).
+    Ellipsis,
+
+    /// Truncate the middle of every line and replace it with an ellipsis
+    /// (`…`), keeping both the start and the end visible.
+    ///
+    /// This is useful for file names and paths.
+    MiddleEllipsis,
+}
+
+impl Default for Overflow {
+    fn default() -> Overflow {
+        Overflow::Visible
+    }
+}
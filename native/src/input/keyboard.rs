@@ -1,6 +1,8 @@
 //! Build keyboard events.
 mod event;
 mod key_code;
+mod modifiers_state;
 
 pub use event::Event;
 pub use key_code::KeyCode;
+pub use modifiers_state::ModifiersState;
@@ -1,4 +1,4 @@
-use super::KeyCode;
+use super::{KeyCode, ModifiersState};
 use crate::input::ButtonState;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -16,6 +16,9 @@ pub enum Event {
 
         /// The key identifier
         key_code: KeyCode,
+
+        /// The state of the modifier keys
+        modifiers: ModifiersState,
     },
 
     /// A unicode character was received.
@@ -1,11 +1,14 @@
 //! Position your widgets properly.
 mod limits;
 mod node;
+mod options;
 
 pub mod flex;
+pub mod grid;
 
 pub use limits::Limits;
 pub use node::Node;
+pub use options::Options;
 
 use crate::{Point, Rectangle, Vector};
 
@@ -17,8 +17,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 use crate::{
-    layout::{Limits, Node},
-    Align, Element, Size,
+    layout::{Limits, Node, Options},
+    Align, Element, Justify, Size,
 };
 
 /// The main axis of a flex layout.
@@ -54,24 +54,32 @@ impl Axis {
     }
 }
 
-/// Computes the flex layout with the given axis and limits, applying spacing,
-/// padding and alignment to the items as needed.
+/// Computes the flex layout with the given axis and limits, applying the
+/// spacing, padding, justification and alignment of the [`Options`] to the
+/// items as needed.
 ///
 /// It returns a new layout [`Node`].
 ///
+/// [`Options`]: ../struct.Options.html
 /// [`Node`]: ../struct.Node.html
 pub fn resolve<Message, Renderer>(
     axis: Axis,
     renderer: &Renderer,
     limits: &Limits,
-    padding: f32,
-    spacing: f32,
-    align_items: Align,
+    options: &Options,
     items: &[Element<'_, Message, Renderer>],
 ) -> Node
 where
     Renderer: crate::Renderer,
 {
+    let Options {
+        padding,
+        spacing,
+        justify_content,
+        align_items,
+        ..
+    } = *options;
+
     let limits = limits.pad(padding);
 
     let mut total_non_fill =
@@ -140,6 +148,17 @@ where
         }
     }
 
+    let align_baselines = match axis {
+        Axis::Horizontal => align_items == Align::Baseline,
+        Axis::Vertical => false,
+    };
+
+    let (lowest_baseline, baseline_height) = baselines(&nodes);
+
+    if align_baselines {
+        cross = cross.max(baseline_height);
+    }
+
     let mut main = padding;
 
     for (i, node) in nodes.iter_mut().enumerate() {
@@ -155,6 +174,10 @@ where
         match axis {
             Axis::Horizontal => {
                 node.align(Align::Start, align_items, Size::new(0.0, cross));
+
+                if align_baselines {
+                    node.bounds.y += lowest_baseline - baseline(node);
+                }
             }
             Axis::Vertical => {
                 node.align(align_items, Align::Start, Size::new(cross, 0.0));
@@ -166,13 +189,347 @@ where
         main += axis.main(size);
     }
 
+    // The leading padding is not part of the content
+    let main = main - padding;
+
     let (width, height) = axis.pack(main, cross);
     let size = limits.resolve(Size::new(width, height));
 
-    let (padding_x, padding_y) = axis.pack(padding, padding * 2.0);
+    justify(
+        &axis,
+        justify_content,
+        axis.main(size) - main,
+        &mut nodes[..],
+    );
 
     Node::with_children(
-        Size::new(size.width + padding_x, size.height + padding_y),
+        Size::new(size.width + padding * 2.0, size.height + padding * 2.0),
         nodes,
     )
 }
+
+/// Computes a wrapping flex layout with the given axis and limits, applying
+/// the spacing, padding, justification and alignment of the [`Options`] to
+/// the items as needed.
+///
+/// Items flow onto a new line when they do not fit in the remaining space of
+/// the main axis. `spacing` separates the items of a line, while
+/// `line_spacing` separates the lines. Every line is justified on its own, and
+/// its items are aligned on its cross axis.
+///
+/// Items that fill the main axis take a whole line.
+///
+/// It returns a new layout [`Node`].
+///
+/// [`Options`]: ../struct.Options.html
+/// [`Node`]: ../struct.Node.html
+pub fn resolve_wrapped<Message, Renderer>(
+    axis: Axis,
+    renderer: &Renderer,
+    limits: &Limits,
+    options: &Options,
+    items: &[Element<'_, Message, Renderer>],
+) -> Node
+where
+    Renderer: crate::Renderer,
+{
+    let Options {
+        padding,
+        spacing,
+        line_spacing,
+        justify_content,
+        align_items,
+    } = *options;
+
+    let limits = limits.pad(padding);
+    let available = axis.main(limits.max());
+
+    let mut nodes: Vec<Node> = items
+        .iter()
+        .map(|child| {
+            child.layout(renderer, &Limits::new(Size::ZERO, limits.max()))
+        })
+        .collect();
+
+    // Every line is a range of items and its size on both axes
+    let mut lines: Vec<(std::ops::Range<usize>, f32, f32)> = Vec::new();
+    let mut line_start = 0;
+    let mut line_main = 0.0;
+    let mut line_cross = 0.0f32;
+
+    for (i, node) in nodes.iter().enumerate() {
+        let size = node.size();
+
+        if i > line_start && line_main + spacing + axis.main(size) > available {
+            lines.push((line_start..i, line_main, line_cross));
+
+            line_start = i;
+            line_main = 0.0;
+            line_cross = 0.0;
+        }
+
+        if i > line_start {
+            line_main += spacing;
+        }
+
+        line_main += axis.main(size);
+        line_cross = line_cross.max(axis.cross(size));
+    }
+
+    if line_start < nodes.len() {
+        lines.push((line_start..nodes.len(), line_main, line_cross));
+    }
+
+    let align_baselines = match axis {
+        Axis::Horizontal => align_items == Align::Baseline,
+        Axis::Vertical => false,
+    };
+
+    if align_baselines {
+        for (range, _, line_cross) in &mut lines {
+            *line_cross = line_cross.max(baselines(&nodes[range.clone()]).1);
+        }
+    }
+
+    let main = lines
+        .iter()
+        .map(|(_, line_main, _)| *line_main)
+        .fold(0.0, f32::max);
+
+    let cross = lines
+        .iter()
+        .map(|(_, _, line_cross)| line_cross)
+        .sum::<f32>()
+        + line_spacing * lines.len().saturating_sub(1) as f32;
+
+    let (width, height) = axis.pack(main, cross);
+    let size = limits.resolve(Size::new(width, height));
+
+    let mut line_offset = padding;
+
+    for (range, line_main, line_cross) in lines {
+        let mut item_offset = padding;
+
+        let (lowest_baseline, _) = baselines(&nodes[range.clone()]);
+
+        for (i, node) in nodes[range.clone()].iter_mut().enumerate() {
+            if i > 0 {
+                item_offset += spacing;
+            }
+
+            let (x, y) = axis.pack(item_offset, line_offset);
+
+            node.bounds.x = x;
+            node.bounds.y = y;
+
+            match axis {
+                Axis::Horizontal => {
+                    node.align(
+                        Align::Start,
+                        align_items,
+                        Size::new(0.0, line_cross),
+                    );
+
+                    if align_baselines {
+                        node.bounds.y += lowest_baseline - baseline(node);
+                    }
+                }
+                Axis::Vertical => {
+                    node.align(
+                        align_items,
+                        Align::Start,
+                        Size::new(line_cross, 0.0),
+                    );
+                }
+            }
+
+            item_offset += axis.main(node.size());
+        }
+
+        justify(
+            &axis,
+            justify_content,
+            axis.main(size) - line_main,
+            &mut nodes[range],
+        );
+
+        line_offset += line_cross + line_spacing;
+    }
+
+    Node::with_children(
+        Size::new(size.width + padding * 2.0, size.height + padding * 2.0),
+        nodes,
+    )
+}
+
+/// Distributes the free space of the main axis between the given nodes.
+fn justify(axis: &Axis, justify: Justify, free: f32, nodes: &mut [Node]) {
+    let free = free.max(0.0);
+    let count = nodes.len() as f32;
+
+    let (start, gap) = match justify {
+        Justify::Start => (0.0, 0.0),
+        Justify::Center => (free / 2.0, 0.0),
+        Justify::End => (free, 0.0),
+        Justify::SpaceBetween if nodes.len() > 1 => (0.0, free / (count - 1.0)),
+        Justify::SpaceBetween => (0.0, 0.0),
+        Justify::SpaceAround => (free / count / 2.0, free / count),
+        Justify::SpaceEvenly => (free / (count + 1.0), free / (count + 1.0)),
+    };
+
+    for (i, node) in nodes.iter_mut().enumerate() {
+        let offset = start + gap * i as f32;
+
+        match axis {
+            Axis::Horizontal => node.bounds.x += offset,
+            Axis::Vertical => node.bounds.y += offset,
+        }
+    }
+}
+
+/// Returns the first baseline of a node, as the distance from its top.
+///
+/// Nodes without a baseline are aligned by their bottom edge.
+fn baseline(node: &Node) -> f32 {
+    node.baseline().unwrap_or(node.bounds.height)
+}
+
+/// Returns the lowest first baseline of the given nodes and the height they
+/// need once their baselines are aligned.
+fn baselines(nodes: &[Node]) -> (f32, f32) {
+    let lowest = nodes.iter().map(baseline).fold(0.0, f32::max);
+
+    let height = nodes
+        .iter()
+        .map(|node| lowest - baseline(node) + node.bounds.height)
+        .fold(0.0, f32::max);
+
+    (lowest, height)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{renderer::Null, Length, Space};
+
+    fn items(sizes: &[(u16, u16)]) -> Vec<Element<'static, (), Null>> {
+        sizes
+            .iter()
+            .map(|&(width, height)| {
+                Space::new(Length::Units(width), Length::Units(height)).into()
+            })
+            .collect()
+    }
+
+    fn limits(width: f32) -> Limits {
+        Limits::new(Size::ZERO, Size::new(width, f32::INFINITY))
+            .width(Length::Fill)
+            .height(Length::Shrink)
+    }
+
+    fn options(padding: f32, justify_content: Justify) -> Options {
+        Options {
+            padding,
+            spacing: 10.0,
+            line_spacing: 4.0,
+            justify_content,
+            align_items: Align::Start,
+        }
+    }
+
+    fn bounds(node: &Node) -> Vec<(f32, f32, f32, f32)> {
+        node.children()
+            .iter()
+            .map(|child| {
+                let bounds = child.bounds();
+
+                (bounds.x, bounds.y, bounds.width, bounds.height)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn wrapped_items_break_lines() {
+        let node = resolve_wrapped(
+            Axis::Horizontal,
+            &Null,
+            &limits(110.0),
+            &options(5.0, Justify::Start),
+            &items(&[(40, 10), (40, 10), (40, 20), (40, 10)]),
+        );
+
+        assert_eq!(node.size(), Size::new(110.0, 44.0));
+
+        assert_eq!(
+            bounds(&node),
+            vec![
+                (5.0, 5.0, 40.0, 10.0),
+                (55.0, 5.0, 40.0, 10.0),
+                (5.0, 19.0, 40.0, 20.0),
+                (55.0, 19.0, 40.0, 10.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn wrapped_lines_are_justified_on_their_own() {
+        let node = resolve_wrapped(
+            Axis::Horizontal,
+            &Null,
+            &limits(110.0),
+            &options(5.0, Justify::End),
+            &items(&[(40, 10), (40, 10), (40, 10)]),
+        );
+
+        let x: Vec<f32> = bounds(&node).iter().map(|(x, ..)| *x).collect();
+
+        assert_eq!(x, vec![15.0, 65.0, 65.0]);
+    }
+
+    #[test]
+    fn justify_distributes_free_space_inside_padding() {
+        let justified = |justify_content| {
+            let node = resolve(
+                Axis::Horizontal,
+                &Null,
+                &limits(110.0),
+                &options(5.0, justify_content),
+                &items(&[(20, 10), (20, 10)]),
+            );
+
+            assert_eq!(node.size(), Size::new(110.0, 20.0));
+
+            bounds(&node).iter().map(|(x, ..)| *x).collect::<Vec<f32>>()
+        };
+
+        assert_eq!(justified(Justify::Start), vec![5.0, 35.0]);
+        assert_eq!(justified(Justify::Center), vec![30.0, 60.0]);
+        assert_eq!(justified(Justify::End), vec![55.0, 85.0]);
+        assert_eq!(justified(Justify::SpaceBetween), vec![5.0, 85.0]);
+        assert_eq!(justified(Justify::SpaceAround), vec![17.5, 72.5]);
+        assert_eq!(
+            justified(Justify::SpaceEvenly),
+            vec![5.0 + 50.0 / 3.0, 35.0 + 100.0 / 3.0]
+        );
+    }
+
+    #[test]
+    fn shrinking_layout_is_padded_on_both_sides() {
+        let node = resolve(
+            Axis::Vertical,
+            &Null,
+            &Limits::new(Size::ZERO, Size::INFINITY)
+                .width(Length::Shrink)
+                .height(Length::Shrink),
+            &options(5.0, Justify::Start),
+            &items(&[(20, 10), (30, 10)]),
+        );
+
+        assert_eq!(node.size(), Size::new(40.0, 40.0));
+
+        assert_eq!(
+            bounds(&node),
+            vec![(5.0, 5.0, 20.0, 10.0), (5.0, 25.0, 30.0, 10.0),]
+        );
+    }
+}
@@ -0,0 +1,295 @@
+//! Distribute elements in the cells of a grid.
+use crate::{
+    layout::{Limits, Node, Options},
+    Align, Element, Length, Size,
+};
+
+use std::ops::Range;
+
+/// The cells of a grid occupied by an element.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Area {
+    /// The first row of the area
+    pub row: usize,
+
+    /// The first column of the area
+    pub column: usize,
+
+    /// The amount of rows spanned by the area
+    pub row_span: usize,
+
+    /// The amount of columns spanned by the area
+    pub column_span: usize,
+
+    /// The horizontal alignment of the element inside the area
+    pub horizontal_alignment: Align,
+
+    /// The vertical alignment of the element inside the area
+    pub vertical_alignment: Align,
+}
+
+impl Area {
+    fn rows(&self) -> Range<usize> {
+        self.row..self.row + self.row_span.max(1)
+    }
+
+    fn columns(&self) -> Range<usize> {
+        self.column..self.column + self.column_span.max(1)
+    }
+}
+
+/// Computes the grid layout with the given tracks and limits, placing every
+/// item in its [`Area`].
+///
+/// A track can have a fixed size, fill a portion of the remaining space or
+/// shrink to fit the items it contains. Tracks that are not described, but
+/// needed by some [`Area`], shrink to fit their contents.
+///
+/// The `spacing` of the [`Options`] separates the columns, while its
+/// `line_spacing` separates the rows. Its justification and alignment are
+/// ignored, as every item is aligned inside its own [`Area`].
+///
+/// It returns a new layout [`Node`] with a child for every item.
+///
+/// [`Area`]: struct.Area.html
+/// [`Options`]: ../struct.Options.html
+/// [`Node`]: ../struct.Node.html
+pub fn resolve<Message, Renderer>(
+    renderer: &Renderer,
+    limits: &Limits,
+    options: &Options,
+    columns: &[Length],
+    rows: &[Length],
+    areas: &[Area],
+    items: &[Element<'_, Message, Renderer>],
+) -> Node
+where
+    Renderer: crate::Renderer,
+{
+    let Options {
+        padding,
+        spacing: column_spacing,
+        line_spacing: row_spacing,
+        ..
+    } = *options;
+
+    let limits = limits.pad(padding);
+
+    let column_count = areas
+        .iter()
+        .map(|area| area.columns().end)
+        .fold(columns.len(), usize::max);
+
+    let row_count = areas
+        .iter()
+        .map(|area| area.rows().end)
+        .fold(rows.len(), usize::max);
+
+    let max = limits.max();
+
+    // Columns first, as the height of an item may depend on its width
+    let column_widths = {
+        let fits = |length| shrinks(length, max.width);
+
+        let widths: Vec<(Range<usize>, f32)> = areas
+            .iter()
+            .zip(items)
+            .filter(|(area, _)| area.columns().any(|i| fits(track(columns, i))))
+            .map(|(area, item)| {
+                let node = item.layout(renderer, &Limits::new(Size::ZERO, max));
+
+                (area.columns(), node.size().width)
+            })
+            .collect();
+
+        tracks(columns, column_count, max.width, column_spacing, &widths)
+    };
+
+    let row_heights = {
+        let fits = |length| shrinks(length, max.height);
+
+        let heights: Vec<(Range<usize>, f32)> = areas
+            .iter()
+            .zip(items)
+            .filter(|(area, _)| area.rows().any(|i| fits(track(rows, i))))
+            .map(|(area, item)| {
+                let width =
+                    extent(&column_widths, area.columns(), column_spacing);
+
+                let node = item.layout(
+                    renderer,
+                    &Limits::new(Size::ZERO, Size::new(width, max.height)),
+                );
+
+                (area.rows(), node.size().height)
+            })
+            .collect();
+
+        tracks(rows, row_count, max.height, row_spacing, &heights)
+    };
+
+    let nodes = areas
+        .iter()
+        .zip(items)
+        .map(|(area, item)| {
+            let width = extent(&column_widths, area.columns(), column_spacing);
+            let height = extent(&row_heights, area.rows(), row_spacing);
+
+            let mut node = item.layout(
+                renderer,
+                &Limits::new(Size::ZERO, Size::new(width, height)),
+            );
+
+            node.bounds.x = padding
+                + extent(&column_widths, 0..area.column, column_spacing)
+                + if area.column > 0 { column_spacing } else { 0.0 };
+
+            node.bounds.y = padding
+                + extent(&row_heights, 0..area.row, row_spacing)
+                + if area.row > 0 { row_spacing } else { 0.0 };
+
+            node.align(
+                area.horizontal_alignment,
+                area.vertical_alignment,
+                Size::new(width, height),
+            );
+
+            node
+        })
+        .collect();
+
+    let size = limits.resolve(Size::new(
+        extent(&column_widths, 0..column_count, column_spacing),
+        extent(&row_heights, 0..row_count, row_spacing),
+    ));
+
+    Node::with_children(
+        Size::new(size.width + padding * 2.0, size.height + padding * 2.0),
+        nodes,
+    )
+}
+
+fn track(lengths: &[Length], i: usize) -> Length {
+    lengths.get(i).copied().unwrap_or(Length::Shrink)
+}
+
+// Filling tracks cannot share infinite space, so they shrink instead
+fn shrinks(length: Length, available: f32) -> bool {
+    match length {
+        Length::Shrink => true,
+        Length::Units(_) => false,
+        Length::Fill | Length::FillPortion(_) => available.is_infinite(),
+    }
+}
+
+/// Returns the size of the given range of tracks, including the spacing
+/// between them.
+fn extent(sizes: &[f32], range: Range<usize>, spacing: f32) -> f32 {
+    let count = range.len();
+
+    sizes[range].iter().sum::<f32>() + spacing * count.saturating_sub(1) as f32
+}
+
+/// Computes the sizes of some tracks, given the size of the items that need
+/// to fit in the shrinking ones.
+fn tracks(
+    lengths: &[Length],
+    count: usize,
+    available: f32,
+    spacing: f32,
+    items: &[(Range<usize>, f32)],
+) -> Vec<f32> {
+    let mut sizes: Vec<f32> = (0..count)
+        .map(|i| match track(lengths, i) {
+            Length::Units(units) => f32::from(units),
+            _ => 0.0,
+        })
+        .collect();
+
+    for (range, size) in items.iter().filter(|(range, _)| range.len() == 1) {
+        if shrinks(track(lengths, range.start), available) {
+            sizes[range.start] = sizes[range.start].max(*size);
+        }
+    }
+
+    // Items spanning many tracks grow the shrinking ones evenly, unless they
+    // span a filling track that can take the extra space
+    for (range, size) in items.iter().filter(|(range, _)| range.len() > 1) {
+        let spans_filling = range.clone().any(|i| {
+            let length = track(lengths, i);
+
+            length.fill_factor() > 0 && !shrinks(length, available)
+        });
+
+        let shrinking: Vec<usize> = range
+            .clone()
+            .filter(|i| shrinks(track(lengths, *i), available))
+            .collect();
+
+        if spans_filling || shrinking.is_empty() {
+            continue;
+        }
+
+        let current = extent(&sizes, range.clone(), spacing);
+
+        if *size > current {
+            let extra = (size - current) / shrinking.len() as f32;
+
+            for i in shrinking {
+                sizes[i] += extra;
+            }
+        }
+    }
+
+    if available.is_finite() {
+        let fill_sum: u16 =
+            (0..count).map(|i| track(lengths, i).fill_factor()).sum();
+
+        if fill_sum > 0 {
+            let used = extent(&sizes, 0..count, spacing);
+            let remaining = (available - used).max(0.0);
+
+            for (i, size) in sizes.iter_mut().enumerate() {
+                let fill_factor = track(lengths, i).fill_factor();
+
+                if fill_factor > 0 {
+                    *size = remaining * f32::from(fill_factor)
+                        / f32::from(fill_sum);
+                }
+            }
+        }
+    }
+
+    sizes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn span_across_filling_track_does_not_grow_shrinking_track() {
+        let sizes = tracks(
+            &[Length::Shrink, Length::Fill],
+            2,
+            100.0,
+            10.0,
+            &[(0..1, 20.0), (0..2, 80.0)],
+        );
+
+        assert_eq!(sizes, vec![20.0, 70.0]);
+    }
+
+    #[test]
+    fn span_grows_shrinking_tracks_when_filling_is_unbounded() {
+        let sizes = tracks(
+            &[Length::Shrink, Length::Fill],
+            2,
+            f32::INFINITY,
+            10.0,
+            &[(0..1, 20.0), (0..2, 80.0)],
+        );
+
+        assert_eq!(sizes, vec![45.0, 25.0]);
+    }
+}
@@ -4,6 +4,7 @@ use crate::{Align, Rectangle, Size};
 #[derive(Debug, Clone, Default)]
 pub struct Node {
     pub(crate) bounds: Rectangle,
+    pub(crate) baseline: Option<f32>,
     children: Vec<Node>,
 }
 
@@ -18,9 +19,16 @@ impl Node {
 
     /// Creates a new [`Node`] with the given [`Size`] and children.
     ///
+    /// The baseline of the [`Node`] is the first baseline found in its
+    /// children, so they should already be positioned.
+    ///
     /// [`Node`]: struct.Node.html
     /// [`Size`]: ../struct.Size.html
     pub fn with_children(size: Size, children: Vec<Node>) -> Self {
+        let baseline = children.iter().find_map(|child| {
+            child.baseline.map(|baseline| child.bounds.y + baseline)
+        });
+
         Node {
             bounds: Rectangle {
                 x: 0.0,
@@ -28,10 +36,19 @@ impl Node {
                 width: size.width,
                 height: size.height,
             },
+            baseline,
             children,
         }
     }
 
+    /// Sets the baseline of the [`Node`], as the distance from its top.
+    ///
+    /// [`Node`]: struct.Node.html
+    pub fn with_baseline(mut self, baseline: f32) -> Self {
+        self.baseline = Some(baseline);
+        self
+    }
+
     /// Returns the [`Size`] of the [`Node`].
     ///
     /// [`Node`]: struct.Node.html
@@ -47,6 +64,14 @@ impl Node {
         self.bounds
     }
 
+    /// Returns the first baseline of the [`Node`], as the distance from its
+    /// top, if it has any.
+    ///
+    /// [`Node`]: struct.Node.html
+    pub fn baseline(&self) -> Option<f32> {
+        self.baseline
+    }
+
     /// Returns the children of the [`Node`].
     ///
     /// [`Node`]: struct.Node.html
@@ -61,7 +86,7 @@ impl Node {
         space: Size,
     ) {
         match horizontal_alignment {
-            Align::Start => {}
+            Align::Start | Align::Baseline => {}
             Align::Center => {
                 self.bounds.x += (space.width - self.bounds.width) / 2.0;
             }
@@ -71,7 +96,7 @@ impl Node {
         }
 
         match vertical_alignment {
-            Align::Start => {}
+            Align::Start | Align::Baseline => {}
             Align::Center => {
                 self.bounds.y += (space.height - self.bounds.height) / 2.0;
             }
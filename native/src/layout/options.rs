@@ -0,0 +1,20 @@
+use crate::{Align, Justify};
+
+/// The spacing and alignment of the items of a layout.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Options {
+    /// The padding around the items
+    pub padding: f32,
+
+    /// The spacing between the items of a line
+    pub spacing: f32,
+
+    /// The spacing between the lines of items
+    pub line_spacing: f32,
+
+    /// The distribution of the free space of a line between its items
+    pub justify_content: Justify,
+
+    /// The alignment of the items of a line on its cross axis
+    pub align_items: Align,
+}
@@ -44,6 +44,7 @@ pub mod layout;
 pub mod renderer;
 pub mod widget;
 
+mod clipboard;
 mod element;
 mod event;
 mod hasher;
@@ -52,10 +53,12 @@ mod size;
 mod user_interface;
 
 pub use iced_core::{
-    Align, Background, Color, Command, Font, HorizontalAlignment, Length,
-    Point, Rectangle, Vector, VerticalAlignment,
+    command, font, Align, Background, Color, Command, ContentFit, Font,
+    HorizontalAlignment, Justify, Length, Overflow, Point, Rectangle, Vector,
+    VerticalAlignment, Weight, Wrap,
 };
 
+pub use clipboard::Clipboard;
 pub use element::Element;
 pub use event::Event;
 pub use hasher::Hasher;
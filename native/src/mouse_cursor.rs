@@ -19,6 +19,12 @@ pub enum MouseCursor {
     /// The cursor is grabbing a widget.
     Grabbing,
 
+    /// The cursor is resizing a widget horizontally.
+    ResizingHorizontally,
+
+    /// The cursor is resizing a widget vertically.
+    ResizingVertically,
+
     /// The cursor is over a text widget.
     Text,
 }
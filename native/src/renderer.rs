@@ -32,6 +32,8 @@ pub use windowed::{Target, Windowed};
 
 use crate::{layout, Element};
 
+use std::time::Instant;
+
 /// A component that can take the state of a user interface and produce an
 /// output for its users.
 pub trait Renderer: Sized {
@@ -53,4 +55,22 @@ pub trait Renderer: Sized {
     ) -> layout::Node {
         element.layout(self, &layout::Limits::NONE)
     }
+
+    /// Requests a new frame to be drawn no later than the given [`Instant`].
+    ///
+    /// Widgets can call this while drawing to keep an animation going. By
+    /// default, the request is ignored.
+    ///
+    /// [`Instant`]: https://doc.rust-lang.org/std/time/struct.Instant.html
+    fn request_redraw(&mut self, _at: Instant) {}
+
+    /// Returns whether the layout of the user interface needs to be computed
+    /// again, even if its widgets did not change, and clears the request.
+    ///
+    /// This is useful when some content finishes loading asynchronously and
+    /// its dimensions were unknown during the last layout. By default, it
+    /// returns `false`.
+    fn take_relayout_request(&mut self) -> bool {
+        false
+    }
 }
@@ -1,7 +1,8 @@
 use crate::{
-    button, checkbox, column, radio, row, scrollable, text, text_input,
-    Background, Color, Element, Font, HorizontalAlignment, Layout, Point,
-    Rectangle, Renderer, Size, VerticalAlignment,
+    button, checkbox, column, grid, modal, pane_grid, radio, rich_text, row,
+    rule, scrollable, selectable_text, space, stack, svg, table, tabs, text,
+    text_input, tree, Background, Color, Element, Font, HorizontalAlignment,
+    Layout, Point, Rectangle, Renderer, Size, VerticalAlignment,
 };
 
 /// A renderer that does nothing.
@@ -22,6 +23,32 @@ impl column::Renderer for Null {
     }
 }
 
+impl grid::Renderer for Null {
+    fn draw<Message>(
+        &mut self,
+        _content: &[Element<'_, Message, Self>],
+        _layout: Layout<'_>,
+        _cursor_position: Point,
+    ) {
+    }
+}
+
+impl modal::Renderer for Null {
+    fn draw(&mut self, _bounds: Rectangle, _base: (), _dialog: ()) {}
+}
+
+impl pane_grid::Renderer for Null {
+    fn draw<Message>(
+        &mut self,
+        _content: &[(pane_grid::Pane, Element<'_, Message, Self>)],
+        _dragging: Option<pane_grid::Pane>,
+        _resizing: Option<pane_grid::Axis>,
+        _layout: Layout<'_>,
+        _cursor_position: Point,
+    ) {
+    }
+}
+
 impl row::Renderer for Null {
     fn draw<Message>(
         &mut self,
@@ -32,6 +59,24 @@ impl row::Renderer for Null {
     }
 }
 
+impl stack::Renderer for Null {
+    fn draw<Message>(
+        &mut self,
+        _content: &[Element<'_, Message, Self>],
+        _layout: Layout<'_>,
+        _cursor_position: Point,
+    ) {
+    }
+}
+
+impl rule::Renderer for Null {
+    fn draw(&mut self, _bounds: Rectangle, _line: Rectangle) {}
+}
+
+impl space::Renderer for Null {
+    fn draw(&mut self, _layout: Layout<'_>) {}
+}
+
 impl text::Renderer for Null {
     fn default_size(&self) -> u16 {
         20
@@ -42,17 +87,39 @@ impl text::Renderer for Null {
         _content: &str,
         _size: u16,
         _font: Font,
+        _format: text::Format,
         _bounds: Size,
     ) -> (f32, f32) {
         (0.0, 20.0)
     }
 
+    fn ascent(&self, _size: u16, _font: Font, _format: text::Format) -> f32 {
+        0.0
+    }
+
+    fn measure_fragments(
+        &self,
+        _fragments: &[text::Fragment<'_>],
+        _bounds: Size,
+    ) -> (f32, f32) {
+        (0.0, 20.0)
+    }
+
+    fn hit_test(
+        &self,
+        _paragraph: &text::Paragraph<'_>,
+        _point: Point,
+    ) -> usize {
+        0
+    }
+
     fn draw(
         &mut self,
         _bounds: Rectangle,
         _content: &str,
         _size: u16,
         _font: Font,
+        _format: text::Format,
         _color: Option<Color>,
         _horizontal_alignment: HorizontalAlignment,
         _vertical_alignment: VerticalAlignment,
@@ -60,6 +127,38 @@ impl text::Renderer for Null {
     }
 }
 
+impl rich_text::Renderer for Null {
+    fn regions(
+        &self,
+        fragments: &[text::Fragment<'_>],
+        _bounds: Rectangle,
+        _horizontal_alignment: HorizontalAlignment,
+    ) -> Vec<Vec<Rectangle>> {
+        vec![Vec::new(); fragments.len()]
+    }
+
+    fn draw(
+        &mut self,
+        _bounds: Rectangle,
+        _fragments: &[text::Fragment<'_>],
+        _styles: &[rich_text::Style],
+        _horizontal_alignment: HorizontalAlignment,
+        _is_mouse_over_link: bool,
+    ) {
+    }
+}
+
+impl selectable_text::Renderer for Null {
+    fn draw(
+        &mut self,
+        _paragraph: &text::Paragraph<'_>,
+        _color: Option<Color>,
+        _selection: Option<(usize, usize)>,
+        _is_mouse_over: bool,
+    ) {
+    }
+}
+
 impl scrollable::Renderer for Null {
     fn is_mouse_over_scrollbar(
         &self,
@@ -88,6 +187,16 @@ impl text_input::Renderer for Null {
         20
     }
 
+    fn offset(
+        &self,
+        _text_bounds: Rectangle,
+        _size: u16,
+        _value: &text_input::Value,
+        _state: &text_input::State,
+    ) -> f32 {
+        0.0
+    }
+
     fn draw(
         &mut self,
         _bounds: Rectangle,
@@ -143,3 +252,39 @@ impl checkbox::Renderer for Null {
     ) {
     }
 }
+
+impl table::Renderer for Null {
+    fn draw_rows(&mut self, _rows: &[table::Row], _cells: Self::Output) {}
+
+    fn draw(
+        &mut self,
+        _bounds: Rectangle,
+        _header: &[table::Header<'_>],
+        _is_resizing: bool,
+        _body: Self::Output,
+    ) {
+    }
+}
+
+impl tree::Renderer for Null {
+    fn draw(&mut self, _rows: &[tree::Row], _labels: Self::Output) {}
+}
+
+impl tabs::Renderer for Null {
+    fn draw(
+        &mut self,
+        _bar_bounds: Rectangle,
+        _headers: &[tabs::Header<'_>],
+        _text_size: u16,
+        _content: Self::Output,
+    ) {
+    }
+}
+
+impl svg::Renderer for Null {
+    fn viewport_dimensions(&self, _path: &str) -> (u32, u32) {
+        (0, 0)
+    }
+
+    fn draw(&mut self, _path: &str, _layout: Layout<'_>) {}
+}
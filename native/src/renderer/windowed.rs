@@ -1,16 +1,20 @@
-use crate::MouseCursor;
+use crate::{font, MouseCursor};
 
 use raw_window_handle::HasRawWindowHandle;
+use std::time::Instant;
 
 /// A renderer that can target windows.
 pub trait Windowed: super::Renderer + Sized {
     /// The type of target.
     type Target: Target<Renderer = Self>;
 
-    /// Creates a new [`Windowed`] renderer.
+    /// The settings of the renderer.
+    type Settings: Default;
+
+    /// Creates a new [`Windowed`] renderer with the given settings.
     ///
     /// [`Windowed`]: trait.Windowed.html
-    fn new() -> Self;
+    fn new(settings: Self::Settings) -> Self;
 
     /// Performs the drawing operations described in the output on the given
     /// target.
@@ -23,6 +27,35 @@ pub trait Windowed: super::Renderer + Sized {
         overlay: &[T],
         target: &mut Self::Target,
     ) -> MouseCursor;
+
+    /// Returns the earliest redraw requested while producing the last output,
+    /// if any, and clears it.
+    ///
+    /// Shells should use this to schedule the next frame of an animation.
+    fn take_redraw_request(&mut self) -> Option<Instant> {
+        None
+    }
+
+    /// Loads a font at runtime, making it available as a [`Font::Loaded`]
+    /// with the given [`font::Id`] and as a [`Family::Name`] with the given
+    /// name.
+    ///
+    /// Shells call this when running a [`Command::load_font`]. By default,
+    /// it does nothing and the default font is used instead.
+    ///
+    /// [`Font::Loaded`]: ../enum.Font.html#variant.Loaded
+    /// [`font::Id`]: ../font/struct.Id.html
+    /// [`Family::Name`]: ../font/enum.Family.html#variant.Name
+    /// [`Command::load_font`]: ../struct.Command.html#method.load_font
+    fn load_font(&mut self, _id: font::Id, _name: String, _bytes: Vec<u8>) {}
+
+    /// Returns some lines describing the internal state of the renderer,
+    /// like the usage of its caches.
+    ///
+    /// Shells show them in the debug overlay. By default, there are none.
+    fn debug_info(&self) -> Vec<String> {
+        Vec::new()
+    }
 }
 
 /// A rendering target.
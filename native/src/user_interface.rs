@@ -1,4 +1,6 @@
-use crate::{input::mouse, layout, Element, Event, Layout, Point, Size};
+use crate::{
+    input::mouse, layout, Clipboard, Element, Event, Layout, Point, Size,
+};
 
 use std::hash::Hasher;
 
@@ -105,8 +107,9 @@ where
         root.hash_layout(hasher);
 
         let hash = hasher.finish();
+        let relayout = renderer.take_relayout_request();
 
-        let layout = if hash == cache.hash {
+        let layout = if hash == cache.hash && !relayout {
             cache.layout
         } else {
             renderer.layout(&root)
@@ -125,8 +128,12 @@ where
     /// It returns __messages__ that may have been produced as a result of user
     /// interactions. You should feed these to your __update logic__.
     ///
+    /// Widgets can use the provided [`Clipboard`], if any, to copy and paste
+    /// text.
+    ///
     /// [`UserInterface`]: struct.UserInterface.html
     /// [`Event`]: enum.Event.html
+    /// [`Clipboard`]: trait.Clipboard.html
     ///
     /// # Example
     /// Let's allow our [counter](index.html#usage) to change state by
@@ -185,7 +192,8 @@ where
     ///     );
     ///
     ///     // Update the user interface
-    ///     let messages = user_interface.update(&renderer, events.drain(..));
+    ///     let messages =
+    ///         user_interface.update(&renderer, None, events.drain(..));
     ///
     ///     cache = user_interface.into_cache();
     ///
@@ -198,6 +206,7 @@ where
     pub fn update(
         &mut self,
         renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
         events: impl Iterator<Item = Event>,
     ) -> Vec<Message> {
         let mut messages = Vec::new();
@@ -213,6 +222,7 @@ where
                 self.cursor_position,
                 &mut messages,
                 renderer,
+                clipboard,
             );
         }
 
@@ -282,7 +292,8 @@ where
     ///         &mut renderer,
     ///     );
     ///
-    ///     let messages = user_interface.update(&renderer, events.drain(..));
+    ///     let messages =
+    ///         user_interface.update(&renderer, None, events.drain(..));
     ///
     ///     // Draw the user interface
     ///     let mouse_cursor = user_interface.draw(&mut renderer);
@@ -24,13 +24,26 @@ pub mod button;
 pub mod checkbox;
 pub mod column;
 pub mod container;
+pub mod grid;
 pub mod image;
+pub mod modal;
+pub mod pane_grid;
 pub mod radio;
+pub mod rich_text;
 pub mod row;
+pub mod rule;
 pub mod scrollable;
+pub mod selectable_text;
 pub mod slider;
+pub mod space;
+pub mod stack;
+pub mod svg;
+pub mod table;
+pub mod tabs;
 pub mod text;
 pub mod text_input;
+pub mod tree;
+pub mod virtual_list;
 
 #[doc(no_inline)]
 pub use button::Button;
@@ -41,21 +54,47 @@ pub use column::Column;
 #[doc(no_inline)]
 pub use container::Container;
 #[doc(no_inline)]
+pub use grid::Grid;
+#[doc(no_inline)]
 pub use image::Image;
 #[doc(no_inline)]
+pub use modal::Modal;
+#[doc(no_inline)]
+pub use pane_grid::PaneGrid;
+#[doc(no_inline)]
 pub use radio::Radio;
 #[doc(no_inline)]
+pub use rich_text::RichText;
+#[doc(no_inline)]
 pub use row::Row;
 #[doc(no_inline)]
+pub use rule::Rule;
+#[doc(no_inline)]
 pub use scrollable::Scrollable;
 #[doc(no_inline)]
+pub use selectable_text::SelectableText;
+#[doc(no_inline)]
 pub use slider::Slider;
 #[doc(no_inline)]
+pub use space::Space;
+#[doc(no_inline)]
+pub use stack::Stack;
+#[doc(no_inline)]
+pub use svg::Svg;
+#[doc(no_inline)]
+pub use table::Table;
+#[doc(no_inline)]
+pub use tabs::Tabs;
+#[doc(no_inline)]
 pub use text::Text;
 #[doc(no_inline)]
 pub use text_input::TextInput;
+#[doc(no_inline)]
+pub use tree::Tree;
+#[doc(no_inline)]
+pub use virtual_list::VirtualList;
 
-use crate::{layout, Event, Hasher, Layout, Length, Point};
+use crate::{layout, Clipboard, Event, Hasher, Layout, Length, Point};
 
 /// A component that displays information and allows interaction.
 ///
@@ -126,12 +165,14 @@ where
     ///   * a mutable `Message` list, allowing the [`Widget`] to produce
     ///   new messages based on user interaction.
     ///   * the `Renderer`
+    ///   * the [`Clipboard`], if available
     ///
     /// By default, it does nothing.
     ///
     /// [`Event`]: ../enum.Event.html
     /// [`Widget`]: trait.Widget.html
     /// [`Layout`]: ../layout/struct.Layout.html
+    /// [`Clipboard`]: ../trait.Clipboard.html
     fn on_event(
         &mut self,
         _event: Event,
@@ -139,6 +180,18 @@ where
         _cursor_position: Point,
         _messages: &mut Vec<Message>,
         _renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
     ) {
     }
+
+    /// Returns whether the [`Widget`], or any of its children, is focused and
+    /// capturing keyboard input.
+    ///
+    /// Containers that react to keys use it to leave them alone while a child
+    /// is handling them. By default, it returns `false`.
+    ///
+    /// [`Widget`]: trait.Widget.html
+    fn is_focused(&self) -> bool {
+        false
+    }
 }
@@ -6,8 +6,8 @@
 //! [`State`]: struct.State.html
 use crate::{
     input::{mouse, ButtonState},
-    layout, Background, Element, Event, Hasher, Layout, Length, Point,
-    Rectangle, Widget,
+    layout, Background, Clipboard, Element, Event, Hasher, Layout, Length,
+    Point, Rectangle, Widget,
 };
 use std::hash::Hash;
 
@@ -171,6 +171,7 @@ where
         cursor_position: Point,
         messages: &mut Vec<Message>,
         _renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
     ) {
         match event {
             Event::Mouse(mouse::Event::Input {
@@ -228,6 +229,10 @@ where
         self.width.hash(state);
         self.content.hash_layout(state);
     }
+
+    fn is_focused(&self) -> bool {
+        self.content.widget.is_focused()
+    }
 }
 
 /// The renderer of a [`Button`].
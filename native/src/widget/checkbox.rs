@@ -3,7 +3,7 @@ use std::hash::Hash;
 
 use crate::{
     input::{mouse, ButtonState},
-    layout, row, text, Align, Color, Element, Event, Font, Hasher,
+    layout, row, text, Align, Clipboard, Color, Element, Event, Font, Hasher,
     HorizontalAlignment, Layout, Length, Point, Rectangle, Row, Text,
     VerticalAlignment, Widget,
 };
@@ -103,6 +103,7 @@ where
         cursor_position: Point,
         messages: &mut Vec<Message>,
         _renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
     ) {
         match event {
             Event::Mouse(mouse::Event::Input {
@@ -138,6 +139,7 @@ where
             &self.label,
             text::Renderer::default_size(renderer),
             Font::Default,
+            text::Format::default(),
             self.label_color,
             HorizontalAlignment::Left,
             VerticalAlignment::Center,
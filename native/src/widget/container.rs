@@ -2,7 +2,8 @@
 use std::hash::Hash;
 
 use crate::{
-    layout, Align, Element, Event, Hasher, Layout, Length, Point, Widget,
+    layout, Align, Clipboard, Element, Event, Hasher, Layout, Length, Point,
+    Widget,
 };
 
 use std::u32;
@@ -131,6 +132,7 @@ where
         cursor_position: Point,
         messages: &mut Vec<Message>,
         renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
     ) {
         self.content.widget.on_event(
             event,
@@ -138,6 +140,7 @@ where
             cursor_position,
             messages,
             renderer,
+            clipboard,
         )
     }
 
@@ -163,6 +166,10 @@ where
 
         self.content.hash_layout(state);
     }
+
+    fn is_focused(&self) -> bool {
+        self.content.widget.is_focused()
+    }
 }
 
 impl<'a, Message, Renderer> From<Container<'a, Message, Renderer>>
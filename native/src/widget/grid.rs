@@ -0,0 +1,341 @@
+//! Distribute content in aligned rows and columns.
+use std::hash::Hash;
+
+use crate::{
+    layout::{self, grid::Area},
+    Align, Clipboard, Element, Event, Hasher, Justify, Layout, Length, Point,
+    Widget,
+};
+
+/// A container that distributes its contents in the cells of a grid.
+///
+/// The columns and the rows of a [`Grid`] are shared by all of its cells, so
+/// they stay aligned. Every track can have a fixed size, fill a portion of the
+/// remaining space or shrink to fit its contents:
+///
+/// ```
+/// # use iced_native::{grid, renderer::Null, Length, Text};
+/// #
+/// # pub type Grid<'a, Message> = iced_native::Grid<'a, Message, Null>;
+/// let grid: Grid<'_, ()> = Grid::new(vec![Length::Shrink, Length::Fill])
+///     .column_spacing(10)
+///     .row_spacing(5)
+///     .push(grid::Cell::new(0, 0, Text::new("Name")))
+///     .push(grid::Cell::new(0, 1, Text::new("Ferris")))
+///     .push(grid::Cell::new(1, 0, Text::new("A crab")).column_span(2));
+/// ```
+///
+/// [`Grid`]: struct.Grid.html
+#[allow(missing_debug_implementations)]
+pub struct Grid<'a, Message, Renderer> {
+    columns: Vec<Length>,
+    rows: Vec<Length>,
+    column_spacing: u16,
+    row_spacing: u16,
+    padding: u16,
+    width: Length,
+    height: Length,
+    max_width: u32,
+    max_height: u32,
+    areas: Vec<Area>,
+    children: Vec<Element<'a, Message, Renderer>>,
+}
+
+impl<'a, Message, Renderer> Grid<'a, Message, Renderer> {
+    /// Creates an empty [`Grid`] with the given columns.
+    ///
+    /// Rows shrink to fit their contents by default.
+    ///
+    /// [`Grid`]: struct.Grid.html
+    pub fn new(columns: Vec<Length>) -> Self {
+        Grid {
+            columns,
+            rows: Vec::new(),
+            column_spacing: 0,
+            row_spacing: 0,
+            padding: 0,
+            width: Length::Fill,
+            height: Length::Shrink,
+            max_width: u32::MAX,
+            max_height: u32::MAX,
+            areas: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+
+    /// Sets the rows of the [`Grid`].
+    ///
+    /// Rows that are not described, but needed by some [`Cell`], shrink to
+    /// fit their contents.
+    ///
+    /// [`Grid`]: struct.Grid.html
+    /// [`Cell`]: struct.Cell.html
+    pub fn rows(mut self, rows: Vec<Length>) -> Self {
+        self.rows = rows;
+        self
+    }
+
+    /// Sets the horizontal spacing _between_ the columns of the [`Grid`].
+    ///
+    /// [`Grid`]: struct.Grid.html
+    pub fn column_spacing(mut self, units: u16) -> Self {
+        self.column_spacing = units;
+        self
+    }
+
+    /// Sets the vertical spacing _between_ the rows of the [`Grid`].
+    ///
+    /// [`Grid`]: struct.Grid.html
+    pub fn row_spacing(mut self, units: u16) -> Self {
+        self.row_spacing = units;
+        self
+    }
+
+    /// Sets the padding of the [`Grid`].
+    ///
+    /// [`Grid`]: struct.Grid.html
+    pub fn padding(mut self, units: u16) -> Self {
+        self.padding = units;
+        self
+    }
+
+    /// Sets the width of the [`Grid`].
+    ///
+    /// [`Grid`]: struct.Grid.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Grid`].
+    ///
+    /// [`Grid`]: struct.Grid.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the maximum width of the [`Grid`].
+    ///
+    /// [`Grid`]: struct.Grid.html
+    pub fn max_width(mut self, max_width: u32) -> Self {
+        self.max_width = max_width;
+        self
+    }
+
+    /// Sets the maximum height of the [`Grid`] in pixels.
+    ///
+    /// [`Grid`]: struct.Grid.html
+    pub fn max_height(mut self, max_height: u32) -> Self {
+        self.max_height = max_height;
+        self
+    }
+
+    /// Adds a [`Cell`] to the [`Grid`].
+    ///
+    /// [`Cell`]: struct.Cell.html
+    /// [`Grid`]: struct.Grid.html
+    pub fn push(mut self, cell: Cell<'a, Message, Renderer>) -> Self {
+        self.areas.push(cell.area);
+        self.children.push(cell.content);
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Grid<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits
+            .max_width(self.max_width)
+            .max_height(self.max_height)
+            .width(self.width)
+            .height(self.height);
+
+        layout::grid::resolve(
+            renderer,
+            &limits,
+            &layout::Options {
+                padding: self.padding as f32,
+                spacing: self.column_spacing as f32,
+                line_spacing: self.row_spacing as f32,
+                justify_content: Justify::Start,
+                align_items: Align::Start,
+            },
+            &self.columns,
+            &self.rows,
+            &self.areas,
+            &self.children,
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        self.children.iter_mut().zip(layout.children()).for_each(
+            |(child, layout)| {
+                child.widget.on_event(
+                    event,
+                    layout,
+                    cursor_position,
+                    messages,
+                    renderer,
+                    clipboard,
+                )
+            },
+        );
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        renderer.draw(&self.children, layout, cursor_position)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        std::any::TypeId::of::<Grid<'static, (), ()>>().hash(state);
+
+        self.columns.hash(state);
+        self.rows.hash(state);
+        self.column_spacing.hash(state);
+        self.row_spacing.hash(state);
+        self.padding.hash(state);
+        self.width.hash(state);
+        self.height.hash(state);
+        self.max_width.hash(state);
+        self.max_height.hash(state);
+        self.areas.hash(state);
+
+        for child in &self.children {
+            child.widget.hash_layout(state);
+        }
+    }
+
+    fn is_focused(&self) -> bool {
+        self.children.iter().any(|child| child.widget.is_focused())
+    }
+}
+
+/// An element placed in some cells of a [`Grid`].
+///
+/// [`Grid`]: struct.Grid.html
+#[allow(missing_debug_implementations)]
+pub struct Cell<'a, Message, Renderer> {
+    area: Area,
+    content: Element<'a, Message, Renderer>,
+}
+
+impl<'a, Message, Renderer> Cell<'a, Message, Renderer> {
+    /// Creates a new [`Cell`] with some content in the given row and column.
+    ///
+    /// [`Cell`]: struct.Cell.html
+    pub fn new<T>(row: usize, column: usize, content: T) -> Self
+    where
+        T: Into<Element<'a, Message, Renderer>>,
+    {
+        Cell {
+            area: Area {
+                row,
+                column,
+                row_span: 1,
+                column_span: 1,
+                horizontal_alignment: Align::Start,
+                vertical_alignment: Align::Start,
+            },
+            content: content.into(),
+        }
+    }
+
+    /// Sets the amount of columns spanned by the [`Cell`].
+    ///
+    /// [`Cell`]: struct.Cell.html
+    pub fn column_span(mut self, columns: usize) -> Self {
+        self.area.column_span = columns;
+        self
+    }
+
+    /// Sets the amount of rows spanned by the [`Cell`].
+    ///
+    /// [`Cell`]: struct.Cell.html
+    pub fn row_span(mut self, rows: usize) -> Self {
+        self.area.row_span = rows;
+        self
+    }
+
+    /// Sets the horizontal alignment of the contents of the [`Cell`].
+    ///
+    /// [`Cell`]: struct.Cell.html
+    pub fn align_x(mut self, align: Align) -> Self {
+        self.area.horizontal_alignment = align;
+        self
+    }
+
+    /// Sets the vertical alignment of the contents of the [`Cell`].
+    ///
+    /// [`Cell`]: struct.Cell.html
+    pub fn align_y(mut self, align: Align) -> Self {
+        self.area.vertical_alignment = align;
+        self
+    }
+}
+
+/// The renderer of a [`Grid`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`Grid`] in your user interface.
+///
+/// [`Grid`]: struct.Grid.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: crate::Renderer + Sized {
+    /// Draws a [`Grid`].
+    ///
+    /// It receives:
+    /// - the children of the [`Grid`]
+    /// - the [`Layout`] of the [`Grid`] and its children
+    /// - the cursor position
+    ///
+    /// [`Grid`]: struct.Grid.html
+    /// [`Layout`]: ../layout/struct.Layout.html
+    fn draw<Message>(
+        &mut self,
+        children: &[Element<'_, Message, Self>],
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Grid<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'static,
+{
+    fn from(
+        grid: Grid<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(grid)
+    }
+}
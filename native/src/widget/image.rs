@@ -1,10 +1,21 @@
 //! Display images in your user interface.
 
-use crate::{layout, Element, Hasher, Layout, Length, Point, Size, Widget};
+use crate::{
+    layout, Align, ContentFit, Element, Hasher, Layout, Length, Point,
+    Rectangle, Size, Widget,
+};
 
-use std::hash::Hash;
+use std::{
+    hash::{Hash, Hasher as _},
+    path::PathBuf,
+    sync::Arc,
+};
 
-/// A frame that displays an image while keeping aspect ratio.
+/// A frame that displays an image.
+///
+/// By default, the image keeps its aspect ratio and it is centered inside
+/// its bounds. You can change this with [`content_fit`], [`align_x`] and
+/// [`align_y`].
 ///
 /// # Example
 ///
@@ -15,22 +26,44 @@ use std::hash::Hash;
 /// ```
 ///
 /// <img src="https://github.com/hecrj/iced/blob/9712b319bb7a32848001b96bd84977430f14b623/examples/resources/ferris.png?raw=true" width="300">
+///
+/// Animated images, like GIF or APNG files, are played in a loop. You can
+/// control their [`Playback`] with [`playing`] and [`looping`].
+///
+/// [`content_fit`]: #method.content_fit
+/// [`align_x`]: #method.align_x
+/// [`align_y`]: #method.align_y
+/// [`Playback`]: struct.Playback.html
+/// [`playing`]: #method.playing
+/// [`looping`]: #method.looping
 #[derive(Debug)]
 pub struct Image {
-    path: String,
+    handle: Handle,
     width: Length,
     height: Length,
+    content_fit: ContentFit,
+    horizontal_alignment: Align,
+    vertical_alignment: Align,
+    playback: Playback,
 }
 
 impl Image {
-    /// Creates a new [`Image`] with the given path.
+    /// Creates a new [`Image`] with the given [`Handle`].
+    ///
+    /// A path can be used directly, as it can be converted into a
+    /// [`Handle`].
     ///
     /// [`Image`]: struct.Image.html
-    pub fn new<T: Into<String>>(path: T) -> Self {
+    /// [`Handle`]: struct.Handle.html
+    pub fn new<T: Into<Handle>>(handle: T) -> Self {
         Image {
-            path: path.into(),
+            handle: handle.into(),
             width: Length::Shrink,
             height: Length::Shrink,
+            content_fit: ContentFit::Contain,
+            horizontal_alignment: Align::Center,
+            vertical_alignment: Align::Center,
+            playback: Playback::default(),
         }
     }
 
@@ -49,6 +82,80 @@ impl Image {
         self.height = height;
         self
     }
+
+    /// Sets the [`ContentFit`] of the [`Image`].
+    ///
+    /// Defaults to [`ContentFit::Contain`].
+    ///
+    /// [`ContentFit`]: ../../enum.ContentFit.html
+    /// [`ContentFit::Contain`]: ../../enum.ContentFit.html#variant.Contain
+    /// [`Image`]: struct.Image.html
+    pub fn content_fit(mut self, content_fit: ContentFit) -> Self {
+        self.content_fit = content_fit;
+        self
+    }
+
+    /// Sets the horizontal alignment of the picture inside the [`Image`]
+    /// bounds.
+    ///
+    /// [`Image`]: struct.Image.html
+    pub fn align_x(mut self, alignment: Align) -> Self {
+        self.horizontal_alignment = alignment;
+        self
+    }
+
+    /// Sets the vertical alignment of the picture inside the [`Image`]
+    /// bounds.
+    ///
+    /// [`Image`]: struct.Image.html
+    pub fn align_y(mut self, alignment: Align) -> Self {
+        self.vertical_alignment = alignment;
+        self
+    }
+
+    /// Sets whether an animated [`Image`] is playing.
+    ///
+    /// A paused animation keeps displaying its current frame, and it resumes
+    /// from it once played again.
+    ///
+    /// [`Image`]: struct.Image.html
+    pub fn playing(mut self, playing: bool) -> Self {
+        self.playback.playing = playing;
+        self
+    }
+
+    /// Sets whether an animated [`Image`] starts over after its last frame.
+    ///
+    /// When not looping, the animation stops at its last frame.
+    ///
+    /// [`Image`]: struct.Image.html
+    pub fn looping(mut self, looping: bool) -> Self {
+        self.playback.looping = looping;
+        self
+    }
+}
+
+/// The playback settings of an animated [`Image`].
+///
+/// Images that are not animated ignore them.
+///
+/// [`Image`]: struct.Image.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Playback {
+    /// Whether the animation advances.
+    pub playing: bool,
+
+    /// Whether the animation starts over after its last frame.
+    pub looping: bool,
+}
+
+impl Default for Playback {
+    fn default() -> Playback {
+        Playback {
+            playing: true,
+            looping: true,
+        }
+    }
 }
 
 impl<Message, Renderer> Widget<Message, Renderer> for Image
@@ -68,24 +175,33 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        let (width, height) = renderer.dimensions(&self.path);
-
-        let aspect_ratio = width as f32 / height as f32;
-
-        // TODO: Deal with additional cases
-        let (width, height) = match (self.width, self.height) {
-            (Length::Units(width), _) => (
-                self.width,
-                Length::Units((width as f32 / aspect_ratio).round() as u16),
-            ),
-            (_, _) => {
-                (Length::Units(width as u16), Length::Units(height as u16))
-            }
-        };
+        let (width, height) = renderer.dimensions(&self.handle);
+        let image_size = Size::new(width as f32, height as f32);
+
+        let limits = limits.width(self.width).height(self.height);
+        let raw_size = limits.resolve(image_size);
 
-        let mut size = limits.width(width).height(height).resolve(Size::ZERO);
+        // A `Shrink` axis follows the other one when it is not `Shrink`
+        let bounds = Size::new(
+            match (self.width, self.height) {
+                (Length::Shrink, Length::Shrink) => raw_size.width,
+                (Length::Shrink, _) => limits.max().width,
+                _ => raw_size.width,
+            },
+            match (self.width, self.height) {
+                (Length::Shrink, Length::Shrink) => raw_size.height,
+                (_, Length::Shrink) => limits.max().height,
+                _ => raw_size.height,
+            },
+        );
+
+        let fitted_size = match self.content_fit {
+            ContentFit::Fill => raw_size,
+            ContentFit::Cover => fit(ContentFit::Contain, image_size, bounds),
+            content_fit => fit(content_fit, image_size, bounds),
+        };
 
-        size.height = size.width / aspect_ratio;
+        let size = limits.resolve(fitted_size);
 
         layout::Node::new(size)
     }
@@ -96,12 +212,163 @@ where
         layout: Layout<'_>,
         _cursor_position: Point,
     ) -> Renderer::Output {
-        renderer.draw(&self.path, layout)
+        let bounds = layout.bounds();
+        let (width, height) = renderer.dimensions(&self.handle);
+
+        let size = fit(
+            self.content_fit,
+            Size::new(width as f32, height as f32),
+            Size::new(bounds.width, bounds.height),
+        );
+
+        let offset = |alignment, space: f32| match alignment {
+            Align::Start | Align::Baseline => 0.0,
+            Align::Center => (space / 2.0).round(),
+            Align::End => space,
+        };
+
+        let image_bounds = Rectangle {
+            x: bounds.x
+                + offset(self.horizontal_alignment, bounds.width - size.width),
+            y: bounds.y
+                + offset(self.vertical_alignment, bounds.height - size.height),
+            width: size.width,
+            height: size.height,
+        };
+
+        renderer.draw(self.handle.clone(), image_bounds, self.playback, layout)
     }
 
     fn hash_layout(&self, state: &mut Hasher) {
+        self.handle.hash(state);
         self.width.hash(state);
         self.height.hash(state);
+        self.content_fit.hash(state);
+    }
+}
+
+/// An [`Image`] handle.
+///
+/// It can be cheaply cloned, and it has a stable identifier derived from its
+/// data. Renderers can use this identifier to cache the decoded image across
+/// frames.
+///
+/// [`Image`]: struct.Image.html
+#[derive(Debug, Clone)]
+pub struct Handle {
+    id: u64,
+    data: Arc<Data>,
+}
+
+impl Handle {
+    /// Creates an image [`Handle`] pointing to the image of the given path.
+    ///
+    /// The image is decoded lazily by the renderer.
+    ///
+    /// [`Handle`]: struct.Handle.html
+    pub fn from_path<T: Into<PathBuf>>(path: T) -> Handle {
+        Self::from_data(Data::Path(path.into()))
+    }
+
+    /// Creates an image [`Handle`] containing the encoded bytes of an image,
+    /// in any of the formats supported by the renderer (PNG, JPEG, etc).
+    ///
+    /// [`Handle`]: struct.Handle.html
+    pub fn from_memory(bytes: Vec<u8>) -> Handle {
+        Self::from_data(Data::Bytes(bytes))
+    }
+
+    /// Creates an image [`Handle`] containing the decoded pixels of an
+    /// image.
+    ///
+    /// `pixels` must be a buffer of `width * height` pixels in RGBA order,
+    /// one byte per channel.
+    ///
+    /// [`Handle`]: struct.Handle.html
+    pub fn from_pixels(width: u32, height: u32, pixels: Vec<u8>) -> Handle {
+        Self::from_data(Data::Pixels {
+            width,
+            height,
+            pixels,
+        })
+    }
+
+    fn from_data(data: Data) -> Handle {
+        let mut hasher = Hasher::default();
+        data.hash(&mut hasher);
+
+        Handle {
+            id: hasher.finish(),
+            data: Arc::new(data),
+        }
+    }
+
+    /// Returns the unique identifier of the [`Handle`].
+    ///
+    /// Two handles with the same data share the same identifier.
+    ///
+    /// [`Handle`]: struct.Handle.html
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// Returns a reference to the image [`Data`].
+    ///
+    /// [`Data`]: enum.Data.html
+    pub fn data(&self) -> &Data {
+        &self.data
+    }
+}
+
+impl From<String> for Handle {
+    fn from(path: String) -> Handle {
+        Handle::from_path(path)
+    }
+}
+
+impl From<&str> for Handle {
+    fn from(path: &str) -> Handle {
+        Handle::from_path(path)
+    }
+}
+
+impl Hash for Handle {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+/// The data of an [`Image`].
+///
+/// [`Image`]: struct.Image.html
+#[derive(Clone, Hash)]
+pub enum Data {
+    /// File data
+    Path(PathBuf),
+
+    /// In-memory data, encoded in some image format
+    Bytes(Vec<u8>),
+
+    /// Decoded pixels in RGBA order
+    Pixels {
+        /// The width of the image
+        width: u32,
+        /// The height of the image
+        height: u32,
+        /// The pixels
+        pixels: Vec<u8>,
+    },
+}
+
+impl std::fmt::Debug for Data {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Data::Path(path) => write!(f, "Path({:?})", path),
+            Data::Bytes(_) => write!(f, "Bytes(...)"),
+            Data::Pixels { width, height, .. } => {
+                write!(f, "Pixels({} * {})", width, height)
+            }
+        }
     }
 }
 
@@ -113,15 +380,59 @@ where
 /// [`Image`]: struct.Image.html
 /// [renderer]: ../../renderer/index.html
 pub trait Renderer: crate::Renderer {
-    /// Returns the dimensions of an [`Image`] located on the given path.
+    /// Returns the dimensions of an [`Image`] with the given [`Handle`].
     ///
     /// [`Image`]: struct.Image.html
-    fn dimensions(&self, path: &str) -> (u32, u32);
+    /// [`Handle`]: struct.Handle.html
+    fn dimensions(&self, handle: &Handle) -> (u32, u32);
 
     /// Draws an [`Image`].
     ///
+    /// It receives:
+    /// - the [`Handle`] of the [`Image`]
+    /// - the bounds of the picture, already fitted and aligned
+    /// - the [`Playback`] of the animation, if the image is animated
+    /// - the [`Layout`] of the [`Image`]
+    ///
+    /// The picture may overflow the bounds of the [`Layout`], in which case
+    /// it should be clipped.
+    ///
     /// [`Image`]: struct.Image.html
-    fn draw(&mut self, path: &str, layout: Layout<'_>) -> Self::Output;
+    /// [`Handle`]: struct.Handle.html
+    /// [`Playback`]: struct.Playback.html
+    /// [`Layout`]: ../layout/struct.Layout.html
+    fn draw(
+        &mut self,
+        handle: Handle,
+        bounds: Rectangle,
+        playback: Playback,
+        layout: Layout<'_>,
+    ) -> Self::Output;
+}
+
+/// Returns the size of some contents of the given size after fitting them
+/// in the given bounds using the given [`ContentFit`].
+///
+/// [`ContentFit`]: ../../enum.ContentFit.html
+fn fit(content_fit: ContentFit, content: Size, bounds: Size) -> Size {
+    // Empty contents have no aspect ratio to keep
+    if content.width == 0.0 || content.height == 0.0 {
+        return Size::ZERO;
+    }
+
+    let width_ratio = bounds.width / content.width;
+    let height_ratio = bounds.height / content.height;
+
+    let scale =
+        |ratio: f32| Size::new(content.width * ratio, content.height * ratio);
+
+    match content_fit {
+        ContentFit::Contain => scale(width_ratio.min(height_ratio)),
+        ContentFit::Cover => scale(width_ratio.max(height_ratio)),
+        ContentFit::Fill => bounds,
+        ContentFit::ScaleDown => scale(width_ratio.min(height_ratio).min(1.0)),
+        ContentFit::None => content,
+    }
 }
 
 impl<'a, Message, Renderer> From<Image> for Element<'a, Message, Renderer>
@@ -0,0 +1,207 @@
+//! Display a dialog on top of some content, blocking any interaction with
+//! it.
+use crate::{
+    input::{keyboard, mouse, ButtonState},
+    layout, Clipboard, Element, Event, Hasher, Layout, Length, Point,
+    Rectangle, Size, Widget,
+};
+
+use std::hash::Hash;
+
+/// A dialog displayed on top of some base content.
+///
+/// The base content is dimmed by a backdrop and does not receive any events
+/// while the [`Modal`] is displayed. All the events are routed to the dialog.
+///
+/// A [`Modal`] can only block the events of its own base content. Any widget
+/// outside of it keeps receiving events while the dialog is displayed.
+/// Therefore, a [`Modal`] should wrap the whole view of your application. You
+/// can display the base content alone when the dialog is closed.
+///
+/// [`Modal`]: struct.Modal.html
+#[allow(missing_debug_implementations)]
+pub struct Modal<'a, Message, Renderer> {
+    base: Element<'a, Message, Renderer>,
+    dialog: Element<'a, Message, Renderer>,
+    on_dismiss: Option<Message>,
+}
+
+impl<'a, Message, Renderer> Modal<'a, Message, Renderer> {
+    /// Creates a new [`Modal`] displaying the given dialog on top of the
+    /// base content.
+    ///
+    /// [`Modal`]: struct.Modal.html
+    pub fn new<B, D>(base: B, dialog: D) -> Self
+    where
+        B: Into<Element<'a, Message, Renderer>>,
+        D: Into<Element<'a, Message, Renderer>>,
+    {
+        Modal {
+            base: base.into(),
+            dialog: dialog.into(),
+            on_dismiss: None,
+        }
+    }
+
+    /// Sets the message that will be produced when the user dismisses the
+    /// [`Modal`], either by pressing `Escape` or by clicking the backdrop.
+    ///
+    /// [`Modal`]: struct.Modal.html
+    pub fn on_dismiss(mut self, message: Message) -> Self {
+        self.on_dismiss = Some(message);
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Modal<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+    Message: Clone,
+{
+    fn width(&self) -> Length {
+        self.base.width()
+    }
+
+    fn height(&self) -> Length {
+        self.base.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.base.width()).height(self.base.height());
+
+        let base = self.base.layout(renderer, &limits);
+        let size = limits.resolve(base.size());
+
+        let mut dialog = self
+            .dialog
+            .layout(renderer, &layout::Limits::new(Size::ZERO, size));
+
+        let dialog_size = dialog.size();
+
+        dialog.bounds.x = ((size.width - dialog_size.width) / 2.0).max(0.0);
+        dialog.bounds.y = ((size.height - dialog_size.height) / 2.0).max(0.0);
+
+        layout::Node::with_children(size, vec![base, dialog])
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        let dialog_layout = layout.children().nth(1).unwrap();
+
+        match event {
+            Event::Keyboard(keyboard::Event::Input {
+                key_code: keyboard::KeyCode::Escape,
+                state: ButtonState::Pressed,
+                ..
+            }) => {
+                if let Some(on_dismiss) = &self.on_dismiss {
+                    messages.push(on_dismiss.clone());
+                }
+            }
+            Event::Mouse(mouse::Event::Input {
+                button: mouse::Button::Left,
+                state: ButtonState::Pressed,
+            }) if layout.bounds().contains(cursor_position)
+                && !dialog_layout.bounds().contains(cursor_position) =>
+            {
+                if let Some(on_dismiss) = &self.on_dismiss {
+                    messages.push(on_dismiss.clone());
+                }
+            }
+            _ => {}
+        }
+
+        self.dialog.widget.on_event(
+            event,
+            dialog_layout,
+            cursor_position,
+            messages,
+            renderer,
+            clipboard,
+        );
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let mut children = layout.children();
+        let base_layout = children.next().unwrap();
+        let dialog_layout = children.next().unwrap();
+
+        // The base content must not react to the mouse
+        let base = self.base.draw(
+            renderer,
+            base_layout,
+            Point::new(f32::NEG_INFINITY, f32::NEG_INFINITY),
+        );
+
+        let dialog = self.dialog.draw(renderer, dialog_layout, cursor_position);
+
+        self::Renderer::draw(renderer, layout.bounds(), base, dialog)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        std::any::TypeId::of::<Modal<'static, (), ()>>().hash(state);
+
+        self.base.hash_layout(state);
+        self.dialog.hash_layout(state);
+    }
+
+    fn is_focused(&self) -> bool {
+        self.dialog.widget.is_focused()
+    }
+}
+
+/// The renderer of a [`Modal`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`Modal`] in your user interface.
+///
+/// [`Modal`]: struct.Modal.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: crate::Renderer + Sized {
+    /// Draws a [`Modal`].
+    ///
+    /// It receives:
+    /// - the bounds of the [`Modal`]
+    /// - the drawn base content
+    /// - the drawn dialog
+    ///
+    /// The dialog and its backdrop must be drawn on top of the base content.
+    ///
+    /// [`Modal`]: struct.Modal.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        base: Self::Output,
+        dialog: Self::Output,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Modal<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'static + Clone,
+{
+    fn from(
+        modal: Modal<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(modal)
+    }
+}
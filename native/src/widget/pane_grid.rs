@@ -0,0 +1,521 @@
+//! Let your users split regions of your application and organize layout
+//! dynamically.
+//!
+//! A [`PaneGrid`] has some local [`State`].
+//!
+//! [`PaneGrid`]: struct.PaneGrid.html
+//! [`State`]: struct.State.html
+mod axis;
+mod configuration;
+mod node;
+mod pane;
+mod split;
+mod state;
+
+pub use axis::Axis;
+pub use configuration::Configuration;
+pub use node::Node;
+pub use pane::Pane;
+pub use split::Split;
+pub use state::State;
+
+use crate::{
+    input::{keyboard, mouse, ButtonState},
+    layout, Clipboard, Element, Event, Hasher, Layout, Length, Point, Size,
+    Widget,
+};
+
+use std::hash::Hash;
+
+/// A collection of panes distributed using either vertical or horizontal
+/// splits to completely fill the space available.
+///
+/// The user can resize the panes by dragging the splits between them, and
+/// swap two panes by dragging one of them onto the other while holding
+/// `Ctrl`. The application owns the [`State`] and decides how to react to
+/// these interactions, which makes splitting, closing and persisting the
+/// layout straightforward.
+///
+/// [`State`]: struct.State.html
+#[allow(missing_debug_implementations)]
+pub struct PaneGrid<'a, Message, Renderer> {
+    state: &'a mut state::Internal,
+    elements: Vec<(Pane, Element<'a, Message, Renderer>)>,
+    width: Length,
+    height: Length,
+    spacing: u16,
+    on_drag: Option<Box<dyn Fn(DragEvent) -> Message>>,
+    on_resize: Option<Box<dyn Fn(ResizeEvent) -> Message>>,
+}
+
+impl<'a, Message, Renderer> PaneGrid<'a, Message, Renderer> {
+    /// Creates a [`PaneGrid`] with the given [`State`] and view function.
+    ///
+    /// The view function will be called to display each [`Pane`] present in
+    /// the [`State`].
+    ///
+    /// [`PaneGrid`]: struct.PaneGrid.html
+    /// [`State`]: struct.State.html
+    /// [`Pane`]: struct.Pane.html
+    pub fn new<T>(
+        state: &'a mut State<T>,
+        view: impl Fn(Pane, &'a mut T) -> Element<'a, Message, Renderer>,
+    ) -> Self {
+        let elements = state
+            .panes
+            .iter_mut()
+            .map(|(pane, pane_state)| (*pane, view(*pane, pane_state)))
+            .collect();
+
+        PaneGrid {
+            state: &mut state.internal,
+            elements,
+            width: Length::Fill,
+            height: Length::Fill,
+            spacing: 0,
+            on_drag: None,
+            on_resize: None,
+        }
+    }
+
+    /// Sets the width of the [`PaneGrid`].
+    ///
+    /// [`PaneGrid`]: struct.PaneGrid.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`PaneGrid`].
+    ///
+    /// [`PaneGrid`]: struct.PaneGrid.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the spacing _between_ the panes of the [`PaneGrid`].
+    ///
+    /// [`PaneGrid`]: struct.PaneGrid.html
+    pub fn spacing(mut self, units: u16) -> Self {
+        self.spacing = units;
+        self
+    }
+
+    /// Enables the drag and drop interactions of the [`PaneGrid`], which
+    /// will use the provided function to produce messages.
+    ///
+    /// Panes can be dragged while holding `Ctrl`.
+    ///
+    /// [`PaneGrid`]: struct.PaneGrid.html
+    pub fn on_drag(
+        mut self,
+        f: impl Fn(DragEvent) -> Message + 'static,
+    ) -> Self {
+        self.on_drag = Some(Box::new(f));
+        self
+    }
+
+    /// Enables the resize interactions of the [`PaneGrid`], which will
+    /// use the provided function to produce messages.
+    ///
+    /// Splits can be dragged to resize the panes around them.
+    ///
+    /// [`PaneGrid`]: struct.PaneGrid.html
+    pub fn on_resize(
+        mut self,
+        f: impl Fn(ResizeEvent) -> Message + 'static,
+    ) -> Self {
+        self.on_resize = Some(Box::new(f));
+        self
+    }
+
+    fn hovered_pane(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Option<Pane> {
+        self.elements
+            .iter()
+            .zip(layout.children())
+            .find(|(_, layout)| layout.bounds().contains(cursor_position))
+            .map(|((pane, _), _)| *pane)
+    }
+
+    fn hovered_split(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Option<(Split, Axis)> {
+        let bounds = layout.bounds();
+        let spacing = f32::from(self.spacing);
+        let handle = (spacing / 2.0).max(SPLIT_HANDLE_WIDTH / 2.0);
+
+        let splits = self
+            .state
+            .layout
+            .splits(spacing, Size::new(bounds.width, bounds.height));
+
+        let relative_cursor = Point::new(
+            cursor_position.x - bounds.x,
+            cursor_position.y - bounds.y,
+        );
+
+        splits
+            .iter()
+            .filter_map(|(split, (axis, region, ratio))| {
+                if !region.contains(relative_cursor) {
+                    return None;
+                }
+
+                let distance = match axis {
+                    Axis::Horizontal => {
+                        (relative_cursor.y - region.y - region.height * ratio)
+                            .abs()
+                    }
+                    Axis::Vertical => {
+                        (relative_cursor.x - region.x - region.width * ratio)
+                            .abs()
+                    }
+                };
+
+                if distance <= handle {
+                    Some((distance, *split, *axis))
+                } else {
+                    None
+                }
+            })
+            .min_by(|(a, _, _), (b, _, _)| {
+                a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal)
+            })
+            .map(|(_, split, axis)| (split, axis))
+    }
+}
+
+/// An event produced during a drag and drop interaction of a [`PaneGrid`].
+///
+/// [`PaneGrid`]: struct.PaneGrid.html
+#[derive(Debug, Clone, Copy)]
+pub enum DragEvent {
+    /// A [`Pane`] was picked for dragging.
+    ///
+    /// [`Pane`]: struct.Pane.html
+    Picked {
+        /// The picked [`Pane`].
+        ///
+        /// [`Pane`]: struct.Pane.html
+        pane: Pane,
+    },
+
+    /// A [`Pane`] was dropped on top of another [`Pane`].
+    ///
+    /// [`Pane`]: struct.Pane.html
+    Dropped {
+        /// The picked [`Pane`].
+        ///
+        /// [`Pane`]: struct.Pane.html
+        pane: Pane,
+
+        /// The [`Pane`] where the picked one was dropped on.
+        ///
+        /// [`Pane`]: struct.Pane.html
+        target: Pane,
+    },
+
+    /// A [`Pane`] was picked and then dropped outside of other [`Pane`]
+    /// boundaries.
+    ///
+    /// [`Pane`]: struct.Pane.html
+    Canceled {
+        /// The picked [`Pane`].
+        ///
+        /// [`Pane`]: struct.Pane.html
+        pane: Pane,
+    },
+}
+
+/// An event produced during a resize interaction of a [`PaneGrid`].
+///
+/// [`PaneGrid`]: struct.PaneGrid.html
+#[derive(Debug, Clone, Copy)]
+pub struct ResizeEvent {
+    /// The [`Split`] that is being dragged for resizing.
+    ///
+    /// [`Split`]: struct.Split.html
+    pub split: Split,
+
+    /// The new ratio of the [`Split`].
+    ///
+    /// It is a value in [0.0, 1.0].
+    ///
+    /// [`Split`]: struct.Split.html
+    pub ratio: f32,
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for PaneGrid<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+        let size = limits.resolve(Size::ZERO);
+
+        let regions = self.state.layout.regions(f32::from(self.spacing), size);
+
+        let children = self
+            .elements
+            .iter()
+            .map(|(pane, element)| {
+                let region = regions.get(pane).copied().unwrap_or_default();
+
+                let limits = layout::Limits::new(
+                    Size::ZERO,
+                    Size::new(region.width, region.height),
+                )
+                .width(Length::Fill)
+                .height(Length::Fill);
+
+                let mut node = element.layout(renderer, &limits);
+
+                node.bounds.x = region.x;
+                node.bounds.y = region.y;
+
+                node
+            })
+            .collect();
+
+        layout::Node::with_children(size, children)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        match event {
+            Event::Mouse(mouse::Event::Input {
+                button: mouse::Button::Left,
+                state: ButtonState::Pressed,
+            }) => {
+                let bounds = layout.bounds();
+
+                if bounds.contains(cursor_position) {
+                    let split = if self.on_resize.is_some() {
+                        self.hovered_split(layout, cursor_position)
+                    } else {
+                        None
+                    };
+
+                    if let Some((split, axis)) = split {
+                        self.state.action =
+                            state::Action::Resizing { split, axis };
+                    } else if let Some(on_drag) = &self.on_drag {
+                        if self.state.modifiers.control {
+                            if let Some(pane) =
+                                self.hovered_pane(layout, cursor_position)
+                            {
+                                self.state.action =
+                                    state::Action::Dragging { pane };
+
+                                messages
+                                    .push(on_drag(DragEvent::Picked { pane }));
+                            }
+                        }
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::Input {
+                button: mouse::Button::Left,
+                state: ButtonState::Released,
+            }) => {
+                if let Some(pane) = self.state.picked_pane() {
+                    if let Some(on_drag) = &self.on_drag {
+                        let event =
+                            match self.hovered_pane(layout, cursor_position) {
+                                Some(target) if target != pane => {
+                                    DragEvent::Dropped { pane, target }
+                                }
+                                _ => DragEvent::Canceled { pane },
+                            };
+
+                        messages.push(on_drag(event));
+                    }
+
+                    self.state.action = state::Action::Idle;
+                } else if self.state.picked_split().is_some() {
+                    self.state.action = state::Action::Idle;
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if let Some((split, _)) = self.state.picked_split() {
+                    if let Some(on_resize) = &self.on_resize {
+                        let bounds = layout.bounds();
+
+                        let splits = self.state.layout.splits(
+                            f32::from(self.spacing),
+                            Size::new(bounds.width, bounds.height),
+                        );
+
+                        if let Some((axis, region, _)) = splits.get(&split) {
+                            let ratio = match axis {
+                                Axis::Horizontal => {
+                                    (cursor_position.y - bounds.y - region.y)
+                                        / region.height
+                                }
+                                Axis::Vertical => {
+                                    (cursor_position.x - bounds.x - region.x)
+                                        / region.width
+                                }
+                            };
+
+                            messages.push(on_resize(ResizeEvent {
+                                split,
+                                ratio: ratio.clamp(MIN_RATIO, 1.0 - MIN_RATIO),
+                            }));
+                        }
+                    }
+                }
+            }
+            Event::Keyboard(keyboard::Event::Input {
+                key_code,
+                state,
+                modifiers,
+            }) => {
+                self.state.modifiers = modifiers;
+
+                match key_code {
+                    keyboard::KeyCode::LControl
+                    | keyboard::KeyCode::RControl => {
+                        self.state.modifiers.control =
+                            state == ButtonState::Pressed;
+                    }
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+
+        if self.state.action == state::Action::Idle {
+            self.elements.iter_mut().zip(layout.children()).for_each(
+                |((_, element), layout)| {
+                    element.widget.on_event(
+                        event,
+                        layout,
+                        cursor_position,
+                        messages,
+                        renderer,
+                        clipboard,
+                    )
+                },
+            );
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let resizing =
+            self.state.picked_split().map(|(_, axis)| axis).or_else(|| {
+                if self.on_resize.is_some() {
+                    self.hovered_split(layout, cursor_position)
+                        .map(|(_, axis)| axis)
+                } else {
+                    None
+                }
+            });
+
+        renderer.draw(
+            &self.elements,
+            self.state.picked_pane(),
+            resizing,
+            layout,
+            cursor_position,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        std::any::TypeId::of::<PaneGrid<'static, (), ()>>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+        self.spacing.hash(state);
+        self.state.layout.hash(state);
+
+        for (_, element) in &self.elements {
+            element.hash_layout(state);
+        }
+    }
+
+    fn is_focused(&self) -> bool {
+        self.elements
+            .iter()
+            .any(|(_, element)| element.widget.is_focused())
+    }
+}
+
+const SPLIT_HANDLE_WIDTH: f32 = 6.0;
+const MIN_RATIO: f32 = 0.05;
+
+/// The renderer of a [`PaneGrid`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`PaneGrid`] in your user interface.
+///
+/// [`PaneGrid`]: struct.PaneGrid.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: crate::Renderer + Sized {
+    /// Draws a [`PaneGrid`].
+    ///
+    /// It receives:
+    /// - the elements of the different panes, if any
+    /// - the dragged [`Pane`], if any
+    /// - the [`Axis`] of the split being resized or hovered, if any
+    /// - the [`Layout`] of the [`PaneGrid`] and its elements
+    /// - the cursor position
+    ///
+    /// [`PaneGrid`]: struct.PaneGrid.html
+    /// [`Pane`]: struct.Pane.html
+    /// [`Axis`]: enum.Axis.html
+    /// [`Layout`]: ../layout/struct.Layout.html
+    fn draw<Message>(
+        &mut self,
+        content: &[(Pane, Element<'_, Message, Self>)],
+        dragging: Option<Pane>,
+        resizing: Option<Axis>,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<PaneGrid<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'static,
+{
+    fn from(
+        pane_grid: PaneGrid<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(pane_grid)
+    }
+}
@@ -0,0 +1,61 @@
+use crate::Rectangle;
+
+/// A fixed reference line for the measurement of coordinates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Axis {
+    /// The horizontal axis: panes are placed one on top of the other.
+    Horizontal,
+
+    /// The vertical axis: panes are placed side by side.
+    Vertical,
+}
+
+impl Axis {
+    pub(super) fn split(
+        &self,
+        rectangle: &Rectangle,
+        ratio: f32,
+        spacing: f32,
+    ) -> (Rectangle, Rectangle) {
+        match self {
+            Axis::Horizontal => {
+                let height_top =
+                    (rectangle.height * ratio - spacing / 2.0).round().max(0.0);
+
+                let height_bottom =
+                    (rectangle.height - height_top - spacing).max(0.0);
+
+                (
+                    Rectangle {
+                        height: height_top,
+                        ..*rectangle
+                    },
+                    Rectangle {
+                        y: rectangle.y + height_top + spacing,
+                        height: height_bottom,
+                        ..*rectangle
+                    },
+                )
+            }
+            Axis::Vertical => {
+                let width_left =
+                    (rectangle.width * ratio - spacing / 2.0).round().max(0.0);
+
+                let width_right =
+                    (rectangle.width - width_left - spacing).max(0.0);
+
+                (
+                    Rectangle {
+                        width: width_left,
+                        ..*rectangle
+                    },
+                    Rectangle {
+                        x: rectangle.x + width_left + spacing,
+                        width: width_right,
+                        ..*rectangle
+                    },
+                )
+            }
+        }
+    }
+}
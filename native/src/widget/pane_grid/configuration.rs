@@ -0,0 +1,32 @@
+use crate::pane_grid::Axis;
+
+/// The arrangement of a [`PaneGrid`].
+///
+/// It can be used to restore a persisted layout when creating a new
+/// [`State`].
+///
+/// [`PaneGrid`]: struct.PaneGrid.html
+/// [`State`]: struct.State.html
+#[derive(Debug, Clone)]
+pub enum Configuration<T> {
+    /// A split of the available space.
+    Split {
+        /// The direction of the split.
+        axis: Axis,
+
+        /// The ratio of the split in [0.0, 1.0].
+        ratio: f32,
+
+        /// The left/top [`Configuration`] of the split.
+        ///
+        /// [`Configuration`]: enum.Configuration.html
+        a: Box<Configuration<T>>,
+
+        /// The right/bottom [`Configuration`] of the split.
+        ///
+        /// [`Configuration`]: enum.Configuration.html
+        b: Box<Configuration<T>>,
+    },
+    /// A pane with the given state.
+    Pane(T),
+}
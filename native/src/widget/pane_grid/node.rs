@@ -0,0 +1,272 @@
+use crate::{
+    pane_grid::{Axis, Pane, Split},
+    Rectangle, Size,
+};
+
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+
+/// A layout node of a [`PaneGrid`].
+///
+/// It describes how the space of the [`PaneGrid`] is split between its
+/// panes. You can inspect it to persist the layout of your application,
+/// including the ratio of every [`Split`].
+///
+/// [`PaneGrid`]: struct.PaneGrid.html
+/// [`Split`]: struct.Split.html
+#[derive(Debug, Clone)]
+pub enum Node {
+    /// The region of this [`Node`] is split into two.
+    ///
+    /// [`Node`]: enum.Node.html
+    Split {
+        /// The [`Split`] of this [`Node`].
+        ///
+        /// [`Split`]: struct.Split.html
+        /// [`Node`]: enum.Node.html
+        id: Split,
+
+        /// The direction of the split.
+        axis: Axis,
+
+        /// The ratio of the split in [0.0, 1.0].
+        ratio: f32,
+
+        /// The left/top [`Node`] of the split.
+        ///
+        /// [`Node`]: enum.Node.html
+        a: Box<Node>,
+
+        /// The right/bottom [`Node`] of the split.
+        ///
+        /// [`Node`]: enum.Node.html
+        b: Box<Node>,
+    },
+    /// The region of this [`Node`] is taken by a [`Pane`].
+    ///
+    /// [`Node`]: enum.Node.html
+    /// [`Pane`]: struct.Pane.html
+    Pane(Pane),
+}
+
+impl Node {
+    /// Returns the rectangular region of every [`Pane`] in this [`Node`],
+    /// given the spacing between panes and the total available space.
+    ///
+    /// [`Pane`]: struct.Pane.html
+    /// [`Node`]: enum.Node.html
+    pub fn regions(
+        &self,
+        spacing: f32,
+        size: Size,
+    ) -> HashMap<Pane, Rectangle> {
+        let mut regions = HashMap::new();
+
+        self.compute_regions(
+            spacing,
+            &Rectangle {
+                x: 0.0,
+                y: 0.0,
+                width: size.width,
+                height: size.height,
+            },
+            &mut regions,
+        );
+
+        regions
+    }
+
+    /// Returns the [`Axis`], the rectangular region and the ratio of every
+    /// [`Split`] in this [`Node`], given the spacing between panes and the
+    /// total available space.
+    ///
+    /// [`Axis`]: enum.Axis.html
+    /// [`Split`]: struct.Split.html
+    /// [`Node`]: enum.Node.html
+    pub fn splits(
+        &self,
+        spacing: f32,
+        size: Size,
+    ) -> HashMap<Split, (Axis, Rectangle, f32)> {
+        let mut splits = HashMap::new();
+
+        self.compute_splits(
+            spacing,
+            &Rectangle {
+                x: 0.0,
+                y: 0.0,
+                width: size.width,
+                height: size.height,
+            },
+            &mut splits,
+        );
+
+        splits
+    }
+
+    /// Returns the panes of this [`Node`], from left/top to right/bottom.
+    ///
+    /// [`Node`]: enum.Node.html
+    pub fn panes(&self) -> Vec<Pane> {
+        match self {
+            Node::Split { a, b, .. } => {
+                let mut panes = a.panes();
+                panes.extend(b.panes());
+
+                panes
+            }
+            Node::Pane(pane) => vec![*pane],
+        }
+    }
+
+    pub(super) fn find(&mut self, pane: &Pane) -> Option<&mut Node> {
+        match self {
+            Node::Split { a, b, .. } => {
+                a.find(pane).or_else(move || b.find(pane))
+            }
+            Node::Pane(p) => {
+                if p == pane {
+                    Some(self)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    pub(super) fn split(&mut self, id: Split, axis: Axis, new_pane: Pane) {
+        *self = Node::Split {
+            id,
+            axis,
+            ratio: 0.5,
+            a: Box::new(self.clone()),
+            b: Box::new(Node::Pane(new_pane)),
+        };
+    }
+
+    pub(super) fn update(&mut self, f: &impl Fn(&mut Node)) {
+        if let Node::Split { a, b, .. } = self {
+            a.update(f);
+            b.update(f);
+        }
+
+        f(self);
+    }
+
+    pub(super) fn resize(&mut self, split: &Split, ratio: f32) -> bool {
+        match self {
+            Node::Split {
+                id, ratio: r, a, b, ..
+            } => {
+                if id == split {
+                    *r = ratio;
+
+                    true
+                } else if a.resize(split, ratio) {
+                    true
+                } else {
+                    b.resize(split, ratio)
+                }
+            }
+            Node::Pane(_) => false,
+        }
+    }
+
+    pub(super) fn remove(&mut self, pane: &Pane) -> Option<Pane> {
+        match self {
+            Node::Split { a, b, .. } => {
+                if a.pane() == Some(*pane) {
+                    *self = *b.clone();
+                    Some(self.first_pane())
+                } else if b.pane() == Some(*pane) {
+                    *self = *a.clone();
+                    Some(self.first_pane())
+                } else {
+                    a.remove(pane).or_else(|| b.remove(pane))
+                }
+            }
+            Node::Pane(_) => None,
+        }
+    }
+
+    fn pane(&self) -> Option<Pane> {
+        match self {
+            Node::Split { .. } => None,
+            Node::Pane(pane) => Some(*pane),
+        }
+    }
+
+    fn first_pane(&self) -> Pane {
+        match self {
+            Node::Split { a, .. } => a.first_pane(),
+            Node::Pane(pane) => *pane,
+        }
+    }
+
+    fn compute_regions(
+        &self,
+        spacing: f32,
+        current: &Rectangle,
+        regions: &mut HashMap<Pane, Rectangle>,
+    ) {
+        match self {
+            Node::Split {
+                axis, ratio, a, b, ..
+            } => {
+                let (region_a, region_b) = axis.split(current, *ratio, spacing);
+
+                a.compute_regions(spacing, &region_a, regions);
+                b.compute_regions(spacing, &region_b, regions);
+            }
+            Node::Pane(pane) => {
+                let _ = regions.insert(*pane, *current);
+            }
+        }
+    }
+
+    fn compute_splits(
+        &self,
+        spacing: f32,
+        current: &Rectangle,
+        splits: &mut HashMap<Split, (Axis, Rectangle, f32)>,
+    ) {
+        if let Node::Split {
+            id,
+            axis,
+            ratio,
+            a,
+            b,
+        } = self
+        {
+            let (region_a, region_b) = axis.split(current, *ratio, spacing);
+
+            let _ = splits.insert(*id, (*axis, *current, *ratio));
+
+            a.compute_splits(spacing, &region_a, splits);
+            b.compute_splits(spacing, &region_b, splits);
+        }
+    }
+}
+
+impl Hash for Node {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Node::Split {
+                id,
+                axis,
+                ratio,
+                a,
+                b,
+            } => {
+                id.hash(state);
+                axis.hash(state);
+                ((ratio * 100_000.0) as u32).hash(state);
+                a.hash(state);
+                b.hash(state);
+            }
+            Node::Pane(pane) => {
+                pane.hash(state);
+            }
+        }
+    }
+}
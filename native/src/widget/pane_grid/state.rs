@@ -0,0 +1,284 @@
+use crate::{
+    input::keyboard,
+    pane_grid::{Axis, Configuration, Node, Pane, Split},
+};
+
+use std::collections::HashMap;
+
+/// The state of a [`PaneGrid`].
+///
+/// It keeps track of the state of each [`Pane`] and the position of each
+/// [`Split`].
+///
+/// The [`State`] needs to own any mutable contents a [`Pane`] may need. This
+/// is why this struct is generic over the type `T`. Values of this type are
+/// provided to the view function of [`PaneGrid::new`] for displaying each
+/// [`Pane`].
+///
+/// [`PaneGrid`]: struct.PaneGrid.html
+/// [`PaneGrid::new`]: struct.PaneGrid.html#method.new
+/// [`Pane`]: struct.Pane.html
+/// [`Split`]: struct.Split.html
+/// [`State`]: struct.State.html
+#[derive(Debug, Clone)]
+pub struct State<T> {
+    pub(super) panes: HashMap<Pane, T>,
+    pub(super) internal: Internal,
+}
+
+impl<T> State<T> {
+    /// Creates a new [`State`], initializing the first pane with the provided
+    /// state.
+    ///
+    /// Alongside the [`State`], it returns the first [`Pane`] identifier.
+    ///
+    /// [`State`]: struct.State.html
+    /// [`Pane`]: struct.Pane.html
+    pub fn new(first_pane_state: T) -> (Self, Pane) {
+        let first_pane = Pane(0);
+
+        let mut panes = HashMap::new();
+        let _ = panes.insert(first_pane, first_pane_state);
+
+        (
+            State {
+                panes,
+                internal: Internal {
+                    layout: Node::Pane(first_pane),
+                    last_id: 0,
+                    action: Action::Idle,
+                    modifiers: keyboard::ModifiersState::default(),
+                },
+            },
+            first_pane,
+        )
+    }
+
+    /// Creates a new [`State`] with the given [`Configuration`].
+    ///
+    /// [`State`]: struct.State.html
+    /// [`Configuration`]: enum.Configuration.html
+    pub fn with_configuration(configuration: Configuration<T>) -> Self {
+        let mut panes = HashMap::new();
+        let mut next_id = 0;
+
+        let layout = Self::distribute(configuration, &mut panes, &mut next_id);
+
+        State {
+            panes,
+            internal: Internal {
+                layout,
+                last_id: next_id - 1,
+                action: Action::Idle,
+                modifiers: keyboard::ModifiersState::default(),
+            },
+        }
+    }
+
+    /// Returns the total amount of panes in the [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn len(&self) -> usize {
+        self.panes.len()
+    }
+
+    /// Returns whether the [`State`] has no panes.
+    ///
+    /// This is never the case, as the last [`Pane`] cannot be closed.
+    ///
+    /// [`State`]: struct.State.html
+    /// [`Pane`]: struct.Pane.html
+    pub fn is_empty(&self) -> bool {
+        self.panes.is_empty()
+    }
+
+    /// Returns the internal state of the given [`Pane`], if it exists.
+    ///
+    /// [`Pane`]: struct.Pane.html
+    pub fn get(&self, pane: &Pane) -> Option<&T> {
+        self.panes.get(pane)
+    }
+
+    /// Returns the internal state of the given [`Pane`] with mutability, if
+    /// it exists.
+    ///
+    /// [`Pane`]: struct.Pane.html
+    pub fn get_mut(&mut self, pane: &Pane) -> Option<&mut T> {
+        self.panes.get_mut(pane)
+    }
+
+    /// Returns an iterator over all the panes of the [`State`], alongside
+    /// their internal state.
+    ///
+    /// [`State`]: struct.State.html
+    pub fn iter(&self) -> impl Iterator<Item = (&Pane, &T)> {
+        self.panes.iter()
+    }
+
+    /// Returns a mutable iterator over all the panes of the [`State`],
+    /// alongside their internal state.
+    ///
+    /// [`State`]: struct.State.html
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (&Pane, &mut T)> {
+        self.panes.iter_mut()
+    }
+
+    /// Returns the layout [`Node`] of the [`State`].
+    ///
+    /// You can use it to persist the arrangement of the panes and the ratio
+    /// of every [`Split`].
+    ///
+    /// [`Node`]: enum.Node.html
+    /// [`Split`]: struct.Split.html
+    /// [`State`]: struct.State.html
+    pub fn layout(&self) -> &Node {
+        &self.internal.layout
+    }
+
+    /// Splits the given [`Pane`] into two in the given [`Axis`] and
+    /// initializing the new [`Pane`] with the provided internal state.
+    ///
+    /// The new [`Pane`] takes the right/bottom half of the region. Alongside
+    /// it, the new [`Split`] is returned.
+    ///
+    /// [`Pane`]: struct.Pane.html
+    /// [`Axis`]: enum.Axis.html
+    /// [`Split`]: struct.Split.html
+    pub fn split(
+        &mut self,
+        axis: Axis,
+        pane: &Pane,
+        state: T,
+    ) -> Option<(Pane, Split)> {
+        let node = self.internal.layout.find(pane)?;
+
+        let new_pane = {
+            self.internal.last_id = self.internal.last_id.checked_add(1)?;
+
+            Pane(self.internal.last_id)
+        };
+
+        let new_split = {
+            self.internal.last_id = self.internal.last_id.checked_add(1)?;
+
+            Split(self.internal.last_id)
+        };
+
+        node.split(new_split, axis, new_pane);
+
+        let _ = self.panes.insert(new_pane, state);
+
+        Some((new_pane, new_split))
+    }
+
+    /// Swaps the position of the provided panes in the [`State`].
+    ///
+    /// If you want to swap panes on drag and drop in your [`PaneGrid`], you
+    /// will need to call this method when handling a [`DragEvent`].
+    ///
+    /// [`State`]: struct.State.html
+    /// [`PaneGrid`]: struct.PaneGrid.html
+    /// [`DragEvent`]: enum.DragEvent.html
+    pub fn swap(&mut self, a: &Pane, b: &Pane) {
+        self.internal.layout.update(&|node| {
+            if let Node::Pane(pane) = node {
+                if pane == a {
+                    *node = Node::Pane(*b);
+                } else if pane == b {
+                    *node = Node::Pane(*a);
+                }
+            }
+        });
+    }
+
+    /// Resizes two panes by setting the position of the provided [`Split`].
+    ///
+    /// The ratio is a value in [0, 1], representing the exact position of a
+    /// [`Split`] between two panes.
+    ///
+    /// If you want to enable resize interactions in your [`PaneGrid`], you
+    /// will need to call this method when handling a [`ResizeEvent`].
+    ///
+    /// [`Split`]: struct.Split.html
+    /// [`PaneGrid`]: struct.PaneGrid.html
+    /// [`ResizeEvent`]: struct.ResizeEvent.html
+    pub fn resize(&mut self, split: &Split, ratio: f32) {
+        let _ = self.internal.layout.resize(split, ratio.clamp(0.0, 1.0));
+    }
+
+    /// Closes the given [`Pane`] and returns its internal state, alongside
+    /// the [`Pane`] that takes its space.
+    ///
+    /// The last [`Pane`] of a [`State`] cannot be closed.
+    ///
+    /// [`Pane`]: struct.Pane.html
+    /// [`State`]: struct.State.html
+    pub fn close(&mut self, pane: &Pane) -> Option<(T, Pane)> {
+        let sibling = self.internal.layout.remove(pane)?;
+
+        self.panes.remove(pane).map(|state| (state, sibling))
+    }
+
+    fn distribute(
+        configuration: Configuration<T>,
+        panes: &mut HashMap<Pane, T>,
+        next_id: &mut usize,
+    ) -> Node {
+        match configuration {
+            Configuration::Split { axis, ratio, a, b } => {
+                let a = Self::distribute(*a, panes, next_id);
+                let b = Self::distribute(*b, panes, next_id);
+
+                let split = Split(*next_id);
+                *next_id += 1;
+
+                Node::Split {
+                    id: split,
+                    axis,
+                    ratio: ratio.clamp(0.0, 1.0),
+                    a: Box::new(a),
+                    b: Box::new(b),
+                }
+            }
+            Configuration::Pane(state) => {
+                let pane = Pane(*next_id);
+                *next_id += 1;
+
+                let _ = panes.insert(pane, state);
+
+                Node::Pane(pane)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct Internal {
+    pub(super) layout: Node,
+    last_id: usize,
+    pub(super) action: Action,
+    pub(super) modifiers: keyboard::ModifiersState,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(super) enum Action {
+    Idle,
+    Dragging { pane: Pane },
+    Resizing { split: Split, axis: Axis },
+}
+
+impl Internal {
+    pub(super) fn picked_pane(&self) -> Option<Pane> {
+        match self.action {
+            Action::Dragging { pane } => Some(pane),
+            _ => None,
+        }
+    }
+
+    pub(super) fn picked_split(&self) -> Option<(Split, Axis)> {
+        match self.action {
+            Action::Resizing { split, axis } => Some((split, axis)),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,493 @@
+//! Write paragraphs of text mixing different styles and links.
+use crate::{
+    input::{mouse, ButtonState},
+    layout, text, Background, Clipboard, Color, Element, Event, Font, Hasher,
+    HorizontalAlignment, Layout, Length, Point, Rectangle, Size, Weight,
+    Widget,
+};
+
+use std::hash::Hash;
+
+/// A paragraph of text made of differently styled [`Span`]s.
+///
+/// Words wrap across span boundaries, as if the paragraph was a single
+/// [`Text`].
+///
+/// # Example
+///
+/// ```
+/// # use iced_native::{rich_text::Span, RichText, Weight};
+/// #
+/// #[derive(Debug, Clone)]
+/// pub enum Message {
+///     DocumentationOpened,
+/// }
+///
+/// RichText::new()
+///     .push(Span::new("Rich text is "))
+///     .push(Span::new("bold").weight(Weight::Bold))
+///     .push(Span::new(" and can contain "))
+///     .push(
+///         Span::new("links")
+///             .color([0.0, 0.0, 1.0])
+///             .underline(true)
+///             .link(Message::DocumentationOpened),
+///     );
+/// ```
+///
+/// [`Span`]: struct.Span.html
+/// [`Text`]: ../text/struct.Text.html
+#[derive(Debug, Clone)]
+pub struct RichText<Message> {
+    spans: Vec<Span<Message>>,
+    size: Option<u16>,
+    color: Option<Color>,
+    font: Font,
+    width: Length,
+    height: Length,
+    horizontal_alignment: HorizontalAlignment,
+}
+
+impl<Message> RichText<Message> {
+    /// Creates an empty [`RichText`].
+    ///
+    /// [`RichText`]: struct.RichText.html
+    pub fn new() -> Self {
+        RichText {
+            spans: Vec::new(),
+            size: None,
+            color: None,
+            font: Font::Default,
+            width: Length::Fill,
+            height: Length::Shrink,
+            horizontal_alignment: HorizontalAlignment::Left,
+        }
+    }
+
+    /// Adds a [`Span`] at the end of the [`RichText`].
+    ///
+    /// [`Span`]: struct.Span.html
+    /// [`RichText`]: struct.RichText.html
+    pub fn push(mut self, span: Span<Message>) -> Self {
+        self.spans.push(span);
+        self
+    }
+
+    /// Sets the default size of the spans of the [`RichText`].
+    ///
+    /// [`RichText`]: struct.RichText.html
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the default [`Color`] of the spans of the [`RichText`].
+    ///
+    /// [`RichText`]: struct.RichText.html
+    /// [`Color`]: ../../struct.Color.html
+    pub fn color<C: Into<Color>>(mut self, color: C) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Sets the default [`Font`] of the spans of the [`RichText`].
+    ///
+    /// [`RichText`]: struct.RichText.html
+    /// [`Font`]: ../../struct.Font.html
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the width of the [`RichText`] boundaries.
+    ///
+    /// [`RichText`]: struct.RichText.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`RichText`] boundaries.
+    ///
+    /// [`RichText`]: struct.RichText.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the [`HorizontalAlignment`] of the [`RichText`].
+    ///
+    /// [`RichText`]: struct.RichText.html
+    /// [`HorizontalAlignment`]: ../../enum.HorizontalAlignment.html
+    pub fn horizontal_alignment(
+        mut self,
+        alignment: HorizontalAlignment,
+    ) -> Self {
+        self.horizontal_alignment = alignment;
+        self
+    }
+
+    fn fragments(&self, default_size: u16) -> Vec<text::Fragment<'_>> {
+        self.spans
+            .iter()
+            .map(|span| text::Fragment {
+                content: &span.content,
+                size: span.size.or(self.size).unwrap_or(default_size),
+                font: span.font.unwrap_or(self.font),
+                weight: span.weight,
+            })
+            .collect()
+    }
+
+    fn link_at<Renderer>(
+        &self,
+        renderer: &Renderer,
+        bounds: Rectangle,
+        cursor_position: Point,
+    ) -> Option<usize>
+    where
+        Renderer: self::Renderer,
+    {
+        if !bounds.contains(cursor_position)
+            || self.spans.iter().all(|span| span.link.is_none())
+        {
+            return None;
+        }
+
+        let fragments = self.fragments(renderer.default_size());
+        let regions =
+            renderer.regions(&fragments, bounds, self.horizontal_alignment);
+
+        self.spans
+            .iter()
+            .zip(regions.iter())
+            .position(|(span, regions)| {
+                span.link.is_some()
+                    && regions
+                        .iter()
+                        .any(|region| region.contains(cursor_position))
+            })
+    }
+}
+
+impl<Message> Default for RichText<Message> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A piece of a [`RichText`] with its own style.
+///
+/// Any property that is not set on a [`Span`] falls back to the one of its
+/// [`RichText`].
+///
+/// [`Span`]: struct.Span.html
+/// [`RichText`]: struct.RichText.html
+#[derive(Debug, Clone)]
+pub struct Span<Message> {
+    content: String,
+    size: Option<u16>,
+    color: Option<Color>,
+    font: Option<Font>,
+    weight: Weight,
+    underline: bool,
+    background: Option<Background>,
+    link: Option<Message>,
+}
+
+impl<Message> Span<Message> {
+    /// Creates a new [`Span`] with the given contents.
+    ///
+    /// [`Span`]: struct.Span.html
+    pub fn new<T: Into<String>>(content: T) -> Self {
+        Span {
+            content: content.into(),
+            size: None,
+            color: None,
+            font: None,
+            weight: Weight::Normal,
+            underline: false,
+            background: None,
+            link: None,
+        }
+    }
+
+    /// Sets the size of the [`Span`].
+    ///
+    /// [`Span`]: struct.Span.html
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the [`Color`] of the [`Span`].
+    ///
+    /// [`Span`]: struct.Span.html
+    /// [`Color`]: ../../struct.Color.html
+    pub fn color<C: Into<Color>>(mut self, color: C) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Sets the [`Font`] of the [`Span`].
+    ///
+    /// [`Span`]: struct.Span.html
+    /// [`Font`]: ../../struct.Font.html
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = Some(font);
+        self
+    }
+
+    /// Sets the [`Weight`] of the [`Span`].
+    ///
+    /// [`Span`]: struct.Span.html
+    /// [`Weight`]: ../../enum.Weight.html
+    pub fn weight(mut self, weight: Weight) -> Self {
+        self.weight = weight;
+        self
+    }
+
+    /// Sets whether the [`Span`] is underlined or not.
+    ///
+    /// [`Span`]: struct.Span.html
+    pub fn underline(mut self, underline: bool) -> Self {
+        self.underline = underline;
+        self
+    }
+
+    /// Sets the [`Background`] of the [`Span`].
+    ///
+    /// [`Span`]: struct.Span.html
+    /// [`Background`]: ../../enum.Background.html
+    pub fn background(mut self, background: Background) -> Self {
+        self.background = Some(background);
+        self
+    }
+
+    /// Turns the [`Span`] into a link that produces the given message when
+    /// clicked.
+    ///
+    /// [`Span`]: struct.Span.html
+    pub fn link(mut self, message: Message) -> Self {
+        self.link = Some(message);
+        self
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for RichText<Message>
+where
+    Renderer: self::Renderer,
+    Message: Clone,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let fragments = self.fragments(renderer.default_size());
+
+        let (width, height) =
+            renderer.measure_fragments(&fragments, limits.max());
+
+        let size = limits.resolve(Size::new(width, height));
+        let node = layout::Node::new(size);
+
+        let max = limits.max();
+        let regions = renderer.regions(
+            &fragments,
+            Rectangle {
+                x: 0.0,
+                y: 0.0,
+                width: max.width,
+                height: max.height,
+            },
+            self.horizontal_alignment,
+        );
+
+        // The spans of a line share its baseline, which fits the biggest
+        // ascent among them. Therefore, the first baseline is the highest one.
+        let baseline = fragments
+            .iter()
+            .zip(regions)
+            .filter_map(|(fragment, regions)| {
+                let region = regions.first()?;
+
+                Some(
+                    region.y
+                        + renderer.ascent(
+                            fragment.size,
+                            fragment.font,
+                            text::Format::default(),
+                        ),
+                )
+            })
+            .fold(None, |highest: Option<f32>, baseline| {
+                Some(highest.map_or(baseline, |highest| highest.min(baseline)))
+            });
+
+        match baseline {
+            Some(baseline) => node.with_baseline(baseline),
+            None => node,
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
+    ) {
+        if let Event::Mouse(mouse::Event::Input {
+            button: mouse::Button::Left,
+            state: ButtonState::Pressed,
+        }) = event
+        {
+            if let Some(index) =
+                self.link_at(renderer, layout.bounds(), cursor_position)
+            {
+                if let Some(message) = self.spans[index].link.clone() {
+                    messages.push(message);
+                }
+            }
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let bounds = layout.bounds();
+
+        let is_mouse_over_link =
+            self.link_at(renderer, bounds, cursor_position).is_some();
+
+        let fragments = self.fragments(renderer.default_size());
+
+        let styles: Vec<Style> = self
+            .spans
+            .iter()
+            .map(|span| Style {
+                color: span.color.or(self.color),
+                underline: span.underline,
+                background: span.background,
+            })
+            .collect();
+
+        self::Renderer::draw(
+            renderer,
+            bounds,
+            &fragments,
+            &styles,
+            self.horizontal_alignment,
+            is_mouse_over_link,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        for span in &self.spans {
+            span.content.hash(state);
+            span.size.hash(state);
+            span.font.hash(state);
+            span.weight.hash(state);
+        }
+
+        self.size.hash(state);
+        self.font.hash(state);
+        self.width.hash(state);
+        self.height.hash(state);
+    }
+}
+
+/// The visual properties of a [`Span`] that do not affect layout.
+///
+/// [`Span`]: struct.Span.html
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    /// The color of the text of the [`Span`], if any
+    ///
+    /// [`Span`]: struct.Span.html
+    pub color: Option<Color>,
+
+    /// Whether the [`Span`] is underlined or not
+    ///
+    /// [`Span`]: struct.Span.html
+    pub underline: bool,
+
+    /// The [`Background`] of the [`Span`], if any
+    ///
+    /// [`Span`]: struct.Span.html
+    /// [`Background`]: ../../enum.Background.html
+    pub background: Option<Background>,
+}
+
+/// The renderer of a [`RichText`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`RichText`] in your user interface.
+///
+/// [`RichText`]: struct.RichText.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: text::Renderer {
+    /// Returns the regions covered by each fragment of a paragraph laid out
+    /// in the given bounds.
+    ///
+    /// A fragment spanning multiple lines has a region per line. The
+    /// [`RichText`] uses these regions to find out which link is under the
+    /// cursor.
+    ///
+    /// [`RichText`]: struct.RichText.html
+    fn regions(
+        &self,
+        fragments: &[text::Fragment<'_>],
+        bounds: Rectangle,
+        horizontal_alignment: HorizontalAlignment,
+    ) -> Vec<Vec<Rectangle>>;
+
+    /// Draws a [`RichText`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`RichText`]
+    ///   * the fragments of the [`RichText`], one per [`Span`]
+    ///   * the [`Style`] of every fragment
+    ///   * the [`HorizontalAlignment`] of the [`RichText`]
+    ///   * whether the mouse is over a link or not
+    ///
+    /// [`RichText`]: struct.RichText.html
+    /// [`Span`]: struct.Span.html
+    /// [`Style`]: struct.Style.html
+    /// [`HorizontalAlignment`]: ../../enum.HorizontalAlignment.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        fragments: &[text::Fragment<'_>],
+        styles: &[Style],
+        horizontal_alignment: HorizontalAlignment,
+        is_mouse_over_link: bool,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<RichText<Message>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+    Message: 'static + Clone,
+{
+    fn from(rich_text: RichText<Message>) -> Element<'a, Message, Renderer> {
+        Element::new(rich_text)
+    }
+}
@@ -2,7 +2,8 @@
 use std::hash::Hash;
 
 use crate::{
-    layout, Align, Element, Event, Hasher, Layout, Length, Point, Widget,
+    layout, Align, Clipboard, Element, Event, Hasher, Justify, Layout, Length,
+    Point, Widget,
 };
 
 use std::u32;
@@ -15,11 +16,14 @@ use std::u32;
 #[allow(missing_debug_implementations)]
 pub struct Row<'a, Message, Renderer> {
     spacing: u16,
+    line_spacing: u16,
+    wrap: bool,
     padding: u16,
     width: Length,
     height: Length,
     max_width: u32,
     max_height: u32,
+    justify_content: Justify,
     align_items: Align,
     children: Vec<Element<'a, Message, Renderer>>,
 }
@@ -31,11 +35,14 @@ impl<'a, Message, Renderer> Row<'a, Message, Renderer> {
     pub fn new() -> Self {
         Row {
             spacing: 0,
+            line_spacing: 0,
+            wrap: false,
             padding: 0,
             width: Length::Fill,
             height: Length::Shrink,
             max_width: u32::MAX,
             max_height: u32::MAX,
+            justify_content: Justify::Start,
             align_items: Align::Start,
             children: Vec::new(),
         }
@@ -51,6 +58,25 @@ impl<'a, Message, Renderer> Row<'a, Message, Renderer> {
         self
     }
 
+    /// Makes the contents of the [`Row`] wrap onto a new line when they do
+    /// not fit in the available width.
+    ///
+    /// Every line aligns its contents on its own.
+    ///
+    /// [`Row`]: struct.Row.html
+    pub fn wrap(mut self) -> Self {
+        self.wrap = true;
+        self
+    }
+
+    /// Sets the vertical spacing _between_ the lines of a wrapping [`Row`].
+    ///
+    /// [`Row`]: struct.Row.html
+    pub fn line_spacing(mut self, units: u16) -> Self {
+        self.line_spacing = units;
+        self
+    }
+
     /// Sets the padding of the [`Row`].
     ///
     /// [`Row`]: struct.Row.html
@@ -91,6 +117,14 @@ impl<'a, Message, Renderer> Row<'a, Message, Renderer> {
         self
     }
 
+    /// Sets the horizontal distribution of the contents of the [`Row`].
+    ///
+    /// [`Row`]: struct.Row.html
+    pub fn justify_content(mut self, justify: Justify) -> Self {
+        self.justify_content = justify;
+        self
+    }
+
     /// Sets the vertical alignment of the contents of the [`Row`] .
     ///
     /// [`Row`]: struct.Row.html
@@ -136,15 +170,31 @@ where
             .width(self.width)
             .height(self.height);
 
-        layout::flex::resolve(
-            layout::flex::Axis::Horizontal,
-            renderer,
-            &limits,
-            self.padding as f32,
-            self.spacing as f32,
-            self.align_items,
-            &self.children,
-        )
+        let options = layout::Options {
+            padding: self.padding as f32,
+            spacing: self.spacing as f32,
+            line_spacing: self.line_spacing as f32,
+            justify_content: self.justify_content,
+            align_items: self.align_items,
+        };
+
+        if self.wrap {
+            layout::flex::resolve_wrapped(
+                layout::flex::Axis::Horizontal,
+                renderer,
+                &limits,
+                &options,
+                &self.children,
+            )
+        } else {
+            layout::flex::resolve(
+                layout::flex::Axis::Horizontal,
+                renderer,
+                &limits,
+                &options,
+                &self.children,
+            )
+        }
     }
 
     fn on_event(
@@ -154,6 +204,7 @@ where
         cursor_position: Point,
         messages: &mut Vec<Message>,
         renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
     ) {
         self.children.iter_mut().zip(layout.children()).for_each(
             |(child, layout)| {
@@ -163,6 +214,7 @@ where
                     cursor_position,
                     messages,
                     renderer,
+                    clipboard,
                 )
             },
         );
@@ -183,7 +235,10 @@ where
         self.height.hash(state);
         self.max_width.hash(state);
         self.max_height.hash(state);
+        self.justify_content.hash(state);
         self.align_items.hash(state);
+        self.wrap.hash(state);
+        self.line_spacing.hash(state);
         self.spacing.hash(state);
         self.spacing.hash(state);
 
@@ -191,6 +246,10 @@ where
             child.widget.hash_layout(state);
         }
     }
+
+    fn is_focused(&self) -> bool {
+        self.children.iter().any(|child| child.widget.is_focused())
+    }
 }
 
 /// The renderer of a [`Row`].
@@ -0,0 +1,147 @@
+//! Separate content with a divider line.
+use crate::{
+    layout, Element, Hasher, Layout, Length, Point, Rectangle, Size, Widget,
+};
+
+use std::hash::Hash;
+
+/// A horizontal or vertical line dividing some content.
+///
+/// A [`Rule`] fills the main axis of its container and takes the given
+/// spacing on the other one. The line is drawn at its center.
+///
+/// # Example
+///
+/// ```
+/// # use iced_native::Rule;
+/// #
+/// let divider = Rule::horizontal(20);
+/// ```
+///
+/// [`Rule`]: struct.Rule.html
+#[derive(Debug, Clone, Copy)]
+pub struct Rule {
+    width: Length,
+    height: Length,
+    thickness: u16,
+    is_horizontal: bool,
+}
+
+impl Rule {
+    /// Creates a horizontal [`Rule`] taking the given vertical spacing.
+    ///
+    /// [`Rule`]: struct.Rule.html
+    pub fn horizontal(spacing: u16) -> Self {
+        Rule {
+            width: Length::Fill,
+            height: Length::Units(spacing),
+            thickness: 1,
+            is_horizontal: true,
+        }
+    }
+
+    /// Creates a vertical [`Rule`] taking the given horizontal spacing.
+    ///
+    /// [`Rule`]: struct.Rule.html
+    pub fn vertical(spacing: u16) -> Self {
+        Rule {
+            width: Length::Units(spacing),
+            height: Length::Fill,
+            thickness: 1,
+            is_horizontal: false,
+        }
+    }
+
+    /// Sets the thickness of the line of the [`Rule`], in pixels.
+    ///
+    /// [`Rule`]: struct.Rule.html
+    pub fn thickness(mut self, thickness: u16) -> Self {
+        self.thickness = thickness;
+        self
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for Rule
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        layout::Node::new(limits.resolve(Size::ZERO))
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+    ) -> Renderer::Output {
+        let bounds = layout.bounds();
+        let thickness = f32::from(self.thickness);
+
+        let line = if self.is_horizontal {
+            Rectangle {
+                x: bounds.x,
+                y: (bounds.y + (bounds.height - thickness) / 2.0).round(),
+                width: bounds.width,
+                height: thickness,
+            }
+        } else {
+            Rectangle {
+                x: (bounds.x + (bounds.width - thickness) / 2.0).round(),
+                y: bounds.y,
+                width: thickness,
+                height: bounds.height,
+            }
+        };
+
+        renderer.draw(bounds, line)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        std::any::TypeId::of::<Rule>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+    }
+}
+
+/// The renderer of a [`Rule`].
+///
+/// Your [renderer] will need to implement this trait before being able to use
+/// a [`Rule`] in your user interface.
+///
+/// [`Rule`]: struct.Rule.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: crate::Renderer {
+    /// Draws a [`Rule`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`Rule`]
+    ///   * the bounds of its line
+    ///
+    /// [`Rule`]: struct.Rule.html
+    fn draw(&mut self, bounds: Rectangle, line: Rectangle) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Rule> for Element<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn from(rule: Rule) -> Element<'a, Message, Renderer> {
+        Element::new(rule)
+    }
+}
@@ -1,12 +1,17 @@
 //! Navigate an endless amount of content with a scrollbar.
 use crate::{
     column,
-    input::{mouse, ButtonState},
-    layout, Align, Column, Element, Event, Hasher, Layout, Length, Point,
-    Rectangle, Size, Widget,
+    input::{keyboard, mouse, ButtonState},
+    layout, Align, Clipboard, Column, Element, Event, Hasher, Layout, Length,
+    Point, Rectangle, Size, Widget,
 };
 
-use std::{f32, hash::Hash, u32};
+use std::{
+    f32,
+    hash::Hash,
+    time::{Duration, Instant},
+    u32,
+};
 
 /// A widget that can vertically display an infinite amount of content with a
 /// scrollbar.
@@ -15,7 +20,10 @@ pub struct Scrollable<'a, Message, Renderer> {
     state: &'a mut State,
     height: Length,
     max_height: u32,
+    line_height: u16,
+    animation_duration: Duration,
     content: Column<'a, Message, Renderer>,
+    on_scroll: Option<Box<dyn Fn(Position) -> Message>>,
 }
 
 impl<'a, Message, Renderer> Scrollable<'a, Message, Renderer> {
@@ -28,7 +36,10 @@ impl<'a, Message, Renderer> Scrollable<'a, Message, Renderer> {
             state,
             height: Length::Shrink,
             max_height: u32::MAX,
+            line_height: 60,
+            animation_duration: Duration::from_millis(150),
             content: Column::new(),
+            on_scroll: None,
         }
     }
 
@@ -90,6 +101,45 @@ impl<'a, Message, Renderer> Scrollable<'a, Message, Renderer> {
         self
     }
 
+    /// Sets the amount of pixels the [`Scrollable`] scrolls per line of a
+    /// mouse wheel or per press of an arrow key.
+    ///
+    /// By default, it is set to `60`.
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    pub fn line_height(mut self, line_height: u16) -> Self {
+        self.line_height = line_height;
+        self
+    }
+
+    /// Sets the duration of the smooth scrolling animation of the
+    /// [`Scrollable`].
+    ///
+    /// A zero duration disables smooth scrolling. By default, it is set to
+    /// `150` milliseconds.
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    pub fn animation_duration(mut self, animation_duration: Duration) -> Self {
+        self.animation_duration = animation_duration;
+        self
+    }
+
+    /// Sets the message that should be produced when the [`Scrollable`] is
+    /// scrolled.
+    ///
+    /// The function receives the [`Position`] the [`Scrollable`] is scrolling
+    /// to.
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    /// [`Position`]: struct.Position.html
+    pub fn on_scroll<F>(mut self, f: F) -> Self
+    where
+        F: 'static + Fn(Position) -> Message,
+    {
+        self.on_scroll = Some(Box::new(f));
+        self
+    }
+
     /// Adds an element to the [`Scrollable`].
     ///
     /// [`Scrollable`]: struct.Scrollable.html
@@ -143,6 +193,7 @@ where
         cursor_position: Point,
         messages: &mut Vec<Message>,
         renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
     ) {
         let bounds = layout.bounds();
         let is_mouse_over = bounds.contains(cursor_position);
@@ -156,72 +207,43 @@ where
             cursor_position,
         );
 
-        // TODO: Event capture. Nested scrollables should capture scroll events.
-        if is_mouse_over {
-            match event {
-                Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
-                    match delta {
-                        mouse::ScrollDelta::Lines { y, .. } => {
-                            // TODO: Configurable speed (?)
-                            self.state.scroll(y * 60.0, bounds, content_bounds);
-                        }
-                        mouse::ScrollDelta::Pixels { y, .. } => {
-                            self.state.scroll(y, bounds, content_bounds);
-                        }
-                    }
-                }
-                _ => {}
-            }
-        }
+        let position = self.state.destination(bounds, content_bounds);
 
-        if self.state.is_scrollbar_grabbed() || is_mouse_over_scrollbar {
-            match event {
-                Event::Mouse(mouse::Event::Input {
-                    button: mouse::Button::Left,
-                    state,
-                }) => match state {
-                    ButtonState::Pressed => {
-                        self.state.scroll_to(
-                            cursor_position.y / (bounds.y + bounds.height),
-                            bounds,
-                            content_bounds,
-                        );
+        // Focused children capture the keys used to scroll
+        let is_captured = match event {
+            Event::Keyboard(_) => self.content.is_focused(),
+            _ => false,
+        };
 
-                        self.state.scrollbar_grabbed_at = Some(cursor_position);
-                    }
-                    ButtonState::Released => {
-                        self.state.scrollbar_grabbed_at = None;
-                    }
+        if !is_captured {
+            self.state.update(
+                event,
+                Area {
+                    bounds,
+                    content_bounds,
+                    line_height: f32::from(self.line_height),
+                    animation_duration: self.animation_duration,
                 },
-                Event::Mouse(mouse::Event::CursorMoved { .. }) => {
-                    if let Some(scrollbar_grabbed_at) =
-                        self.state.scrollbar_grabbed_at
-                    {
-                        let ratio = content_bounds.height / bounds.height;
-                        let delta = scrollbar_grabbed_at.y - cursor_position.y;
+                cursor_position,
+                is_mouse_over_scrollbar,
+            );
+        }
 
-                        self.state.scroll(
-                            delta * ratio,
-                            bounds,
-                            content_bounds,
-                        );
+        if let Some(on_scroll) = &self.on_scroll {
+            let new_position = self.state.destination(bounds, content_bounds);
 
-                        self.state.scrollbar_grabbed_at = Some(cursor_position);
-                    }
-                }
-                _ => {}
+            if new_position != position {
+                messages.push(on_scroll(new_position));
             }
         }
 
+        let offset = self.state.offset(bounds, content_bounds);
+
         let cursor_position = if is_mouse_over
             && !(is_mouse_over_scrollbar
                 || self.state.scrollbar_grabbed_at.is_some())
         {
-            Point::new(
-                cursor_position.x,
-                cursor_position.y
-                    + self.state.offset(bounds, content_bounds) as f32,
-            )
+            Point::new(cursor_position.x, cursor_position.y + offset as f32)
         } else {
             // TODO: Make `cursor_position` an `Option<Point>` so we can encode
             // cursor availability.
@@ -236,6 +258,7 @@ where
             cursor_position,
             messages,
             renderer,
+            clipboard,
         )
     }
 
@@ -250,6 +273,12 @@ where
         let content_bounds = content_layout.bounds();
         let offset = self.state.offset(bounds, content_bounds);
 
+        let now = Instant::now();
+
+        if self.state.is_animating(now) {
+            renderer.request_redraw(now + FRAME_INTERVAL);
+        }
+
         let is_mouse_over = bounds.contains(cursor_position);
         let is_mouse_over_scrollbar = renderer.is_mouse_over_scrollbar(
             bounds,
@@ -287,6 +316,10 @@ where
 
         self.content.hash_layout(state)
     }
+
+    fn is_focused(&self) -> bool {
+        self.state.is_focused || self.content.is_focused()
+    }
 }
 
 /// The local state of a [`Scrollable`].
@@ -295,7 +328,10 @@ where
 #[derive(Debug, Clone, Copy, Default)]
 pub struct State {
     scrollbar_grabbed_at: Option<Point>,
-    offset: f32,
+    offset: Offset,
+    animation: Option<Animation>,
+    is_pinned_to_bottom: bool,
+    is_focused: bool,
 }
 
 impl State {
@@ -306,9 +342,27 @@ impl State {
         State::default()
     }
 
+    /// Creates a new [`State`] that keeps the scrollbar pinned to the bottom
+    /// while the contents of the [`Scrollable`] grow.
+    ///
+    /// This is useful for chats and logs. Scrolling up releases the pin, and
+    /// scrolling back to the bottom restores it.
+    ///
+    /// [`State`]: struct.State.html
+    /// [`Scrollable`]: struct.Scrollable.html
+    pub fn pinned_to_bottom() -> Self {
+        State {
+            offset: Offset::Relative(1.0),
+            is_pinned_to_bottom: true,
+            ..State::default()
+        }
+    }
+
     /// Apply a scrolling offset to the current [`State`], given the bounds of
     /// the [`Scrollable`] and its contents.
     ///
+    /// The offset is applied immediately, cancelling any ongoing animation.
+    ///
     /// [`Scrollable`]: struct.Scrollable.html
     /// [`State`]: struct.State.html
     pub fn scroll(
@@ -321,27 +375,33 @@ impl State {
             return;
         }
 
-        self.offset = (self.offset - delta_y)
-            .max(0.0)
-            .min((content_bounds.height - bounds.height) as f32);
+        let current = self.current(bounds, content_bounds, Instant::now());
+
+        self.animation = None;
+        self.move_to(current - delta_y, bounds, content_bounds);
     }
 
-    /// Moves the scroll position to a relative amount, given the bounds of
-    /// the [`Scrollable`] and its contents.
+    /// Scrolls the [`Scrollable`] to the given absolute offset, in pixels.
+    ///
+    /// The offset will be clamped to the size of the contents once the
+    /// [`Scrollable`] is drawn.
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    pub fn scroll_to(&mut self, offset: f32) {
+        self.animation = None;
+        self.offset = Offset::Absolute(offset.max(0.0));
+    }
+
+    /// Snaps the scroll position to a relative amount.
     ///
     /// `0` represents scrollbar at the top, while `1` represents scrollbar at
-    /// the bottom.
+    /// the bottom. The relative position is kept when the contents of the
+    /// [`Scrollable`] change size.
     ///
     /// [`Scrollable`]: struct.Scrollable.html
-    /// [`State`]: struct.State.html
-    pub fn scroll_to(
-        &mut self,
-        percentage: f32,
-        bounds: Rectangle,
-        content_bounds: Rectangle,
-    ) {
-        self.offset =
-            ((content_bounds.height - bounds.height) * percentage).max(0.0);
+    pub fn snap_to(&mut self, percentage: f32) {
+        self.animation = None;
+        self.offset = Offset::Relative(percentage.clamp(0.0, 1.0));
     }
 
     /// Returns the current scrolling offset of the [`State`], given the bounds
@@ -350,16 +410,486 @@ impl State {
     /// [`Scrollable`]: struct.Scrollable.html
     /// [`State`]: struct.State.html
     pub fn offset(&self, bounds: Rectangle, content_bounds: Rectangle) -> u32 {
-        let hidden_content =
-            (content_bounds.height - bounds.height).max(0.0).round() as u32;
+        self.current(bounds, content_bounds, Instant::now()).round() as u32
+    }
 
-        self.offset.min(hidden_content as f32) as u32
+    /// Returns the current [`Position`] of the [`State`], given the bounds of
+    /// the [`Scrollable`] and its contents.
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    /// [`State`]: struct.State.html
+    /// [`Position`]: struct.Position.html
+    pub fn position(
+        &self,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) -> Position {
+        Position::new(
+            self.current(bounds, content_bounds, Instant::now()),
+            bounds,
+            content_bounds,
+        )
     }
 
     /// Returns whether the scrollbar is currently grabbed or not.
     pub fn is_scrollbar_grabbed(&self) -> bool {
         self.scrollbar_grabbed_at.is_some()
     }
+
+    /// Returns whether the [`State`] is focused, reacting to keys.
+    ///
+    /// [`State`]: struct.State.html
+    pub fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    /// Returns whether the [`State`] is animating a scroll at the given
+    /// instant.
+    ///
+    /// [`State`]: struct.State.html
+    pub fn is_animating(&self, now: Instant) -> bool {
+        match self.animation {
+            None => false,
+            Some(Animation::Smooth {
+                started_at,
+                duration,
+                ..
+            }) => now < started_at + duration,
+            Some(Animation::Kinetic {
+                velocity,
+                updated_at,
+            }) => {
+                let elapsed = kinetic_elapsed(updated_at, now);
+
+                (velocity * (-FRICTION * elapsed).exp()).abs() > MIN_VELOCITY
+            }
+        }
+    }
+
+    /// Processes an [`Event`] targeting a scrollable area.
+    ///
+    /// [`Event`]: ../../enum.Event.html
+    pub(crate) fn update(
+        &mut self,
+        event: Event,
+        area: Area,
+        cursor_position: Point,
+        is_mouse_over_scrollbar: bool,
+    ) {
+        let Area {
+            bounds,
+            content_bounds,
+            line_height,
+            animation_duration,
+        } = area;
+
+        let is_mouse_over = bounds.contains(cursor_position);
+
+        self.settle(bounds, content_bounds, Instant::now());
+
+        if let Event::Mouse(mouse::Event::Input {
+            button: mouse::Button::Left,
+            state: ButtonState::Pressed,
+        }) = event
+        {
+            self.is_focused = is_mouse_over;
+        }
+
+        // TODO: Event capture. Nested scrollables should capture scroll events.
+        if is_mouse_over {
+            if let Event::Mouse(mouse::Event::WheelScrolled { delta }) = event {
+                match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => {
+                        self.scroll_smoothly(
+                            y * line_height,
+                            bounds,
+                            content_bounds,
+                            animation_duration,
+                        );
+                    }
+                    mouse::ScrollDelta::Pixels { y, .. } => {
+                        self.scroll_kinetically(y, bounds, content_bounds);
+                    }
+                }
+            }
+        }
+
+        if is_mouse_over || self.is_focused {
+            if let Event::Keyboard(keyboard::Event::Input {
+                key_code,
+                state: ButtonState::Pressed,
+                ..
+            }) = event
+            {
+                let delta_y = match key_code {
+                    keyboard::KeyCode::Up => Some(line_height),
+                    keyboard::KeyCode::Down => Some(-line_height),
+                    keyboard::KeyCode::PageUp => Some(bounds.height),
+                    keyboard::KeyCode::PageDown => Some(-bounds.height),
+                    keyboard::KeyCode::Home => Some(f32::INFINITY),
+                    keyboard::KeyCode::End => Some(f32::NEG_INFINITY),
+                    _ => None,
+                };
+
+                if let Some(delta_y) = delta_y {
+                    self.scroll_smoothly(
+                        delta_y,
+                        bounds,
+                        content_bounds,
+                        animation_duration,
+                    );
+                }
+            }
+        }
+
+        if self.is_scrollbar_grabbed() || is_mouse_over_scrollbar {
+            match event {
+                Event::Mouse(mouse::Event::Input {
+                    button: mouse::Button::Left,
+                    state,
+                }) => match state {
+                    ButtonState::Pressed => {
+                        self.animation = None;
+                        self.move_to(
+                            Offset::Relative(
+                                cursor_position.y / (bounds.y + bounds.height),
+                            )
+                            .absolute(bounds, content_bounds),
+                            bounds,
+                            content_bounds,
+                        );
+
+                        self.scrollbar_grabbed_at = Some(cursor_position);
+                    }
+                    ButtonState::Released => {
+                        self.scrollbar_grabbed_at = None;
+                    }
+                },
+                Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                    if let Some(scrollbar_grabbed_at) =
+                        self.scrollbar_grabbed_at
+                    {
+                        let ratio = content_bounds.height / bounds.height;
+                        let delta = scrollbar_grabbed_at.y - cursor_position.y;
+
+                        self.scroll(delta * ratio, bounds, content_bounds);
+
+                        self.scrollbar_grabbed_at = Some(cursor_position);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Returns the [`Position`] the [`State`] is scrolling to.
+    ///
+    /// [`Position`]: struct.Position.html
+    /// [`State`]: struct.State.html
+    pub(crate) fn destination(
+        &self,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) -> Position {
+        let target = self.offset.absolute(bounds, content_bounds);
+
+        let destination = match self.animation {
+            Some(Animation::Kinetic { velocity, .. }) => {
+                let hidden_content =
+                    (content_bounds.height - bounds.height).max(0.0);
+
+                (target + velocity / FRICTION).max(0.0).min(hidden_content)
+            }
+            _ => target,
+        };
+
+        Position::new(destination.round(), bounds, content_bounds)
+    }
+
+    fn scroll_smoothly(
+        &mut self,
+        delta_y: f32,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+        duration: Duration,
+    ) {
+        if bounds.height >= content_bounds.height {
+            return;
+        }
+
+        let now = Instant::now();
+        let current = self.current(bounds, content_bounds, now);
+
+        // Consecutive steps accumulate on top of the ongoing animation, so
+        // fast wheel spins do not lose any distance.
+        let target = match self.animation {
+            Some(Animation::Smooth { .. }) if self.is_animating(now) => {
+                self.offset.absolute(bounds, content_bounds)
+            }
+            _ => current,
+        };
+
+        self.animation = if duration > Duration::from_millis(0) {
+            Some(Animation::Smooth {
+                from: current,
+                started_at: now,
+                duration,
+            })
+        } else {
+            None
+        };
+
+        self.move_to(target - delta_y, bounds, content_bounds);
+    }
+
+    fn scroll_kinetically(
+        &mut self,
+        delta_y: f32,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) {
+        if bounds.height >= content_bounds.height {
+            return;
+        }
+
+        let now = Instant::now();
+        let current = self.current(bounds, content_bounds, now);
+
+        // While the deltas keep coming, we follow them and estimate the
+        // velocity of the gesture. Once they stop, the content keeps moving
+        // with that velocity and slows down progressively.
+        let velocity = match self.animation {
+            Some(Animation::Kinetic {
+                velocity,
+                updated_at,
+            }) if now.duration_since(updated_at) < KINETIC_DELAY => {
+                let elapsed = now
+                    .duration_since(updated_at)
+                    .as_secs_f32()
+                    .max(MIN_SAMPLE_INTERVAL);
+
+                velocity * 0.2 - delta_y / elapsed * 0.8
+            }
+            _ => 0.0,
+        };
+
+        self.animation = Some(Animation::Kinetic {
+            velocity,
+            updated_at: now,
+        });
+
+        self.move_to(current - delta_y, bounds, content_bounds);
+    }
+
+    /// Commits the distance travelled by a kinetic animation to the offset,
+    /// so it is kept when the animation is interrupted.
+    fn settle(
+        &mut self,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+        now: Instant,
+    ) {
+        if let Some(Animation::Kinetic {
+            velocity,
+            updated_at,
+        }) = self.animation
+        {
+            let elapsed = kinetic_elapsed(updated_at, now);
+
+            if elapsed == 0.0 {
+                return;
+            }
+
+            let is_animating = self.is_animating(now);
+            let current = self.current(bounds, content_bounds, now);
+
+            self.move_to(current, bounds, content_bounds);
+
+            // The animation continues from the committed offset with the
+            // velocity it had decayed to
+            self.animation = if is_animating {
+                Some(Animation::Kinetic {
+                    velocity: velocity * (-FRICTION * elapsed).exp(),
+                    updated_at: now - KINETIC_DELAY,
+                })
+            } else {
+                None
+            };
+        }
+    }
+
+    fn move_to(
+        &mut self,
+        offset: f32,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) {
+        let hidden_content = (content_bounds.height - bounds.height).max(0.0);
+        let offset = offset.max(0.0).min(hidden_content);
+
+        self.offset = if self.is_pinned_to_bottom && offset >= hidden_content {
+            Offset::Relative(1.0)
+        } else {
+            Offset::Absolute(offset)
+        };
+    }
+
+    fn current(
+        &self,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+        now: Instant,
+    ) -> f32 {
+        let hidden_content = (content_bounds.height - bounds.height).max(0.0);
+        let target = self.offset.absolute(bounds, content_bounds);
+
+        match self.animation {
+            None => target,
+            Some(Animation::Smooth {
+                from,
+                started_at,
+                duration,
+            }) => {
+                let from = from.min(hidden_content);
+                let progress = if now < started_at + duration {
+                    now.duration_since(started_at).as_secs_f32()
+                        / duration.as_secs_f32()
+                } else {
+                    1.0
+                };
+
+                // Cubic ease-out
+                let progress = 1.0 - (1.0 - progress).powi(3);
+
+                from + (target - from) * progress
+            }
+            Some(Animation::Kinetic {
+                velocity,
+                updated_at,
+            }) => {
+                let elapsed = kinetic_elapsed(updated_at, now);
+                let distance =
+                    velocity * (1.0 - (-FRICTION * elapsed).exp()) / FRICTION;
+
+                (target + distance).max(0.0).min(hidden_content)
+            }
+        }
+    }
+}
+
+/// The scrolling position of a [`Scrollable`].
+///
+/// [`Scrollable`]: struct.Scrollable.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Position {
+    /// The amount of content hidden above the [`Scrollable`], in pixels.
+    ///
+    /// [`Scrollable`]: struct.Scrollable.html
+    pub absolute: f32,
+
+    /// The relative position of the scrollbar.
+    ///
+    /// `0` represents scrollbar at the top, while `1` represents scrollbar at
+    /// the bottom.
+    pub relative: f32,
+}
+
+impl Position {
+    fn new(
+        absolute: f32,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) -> Self {
+        let hidden_content = (content_bounds.height - bounds.height).max(0.0);
+
+        Position {
+            absolute,
+            relative: if hidden_content > 0.0 {
+                absolute / hidden_content
+            } else {
+                0.0
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Offset {
+    Absolute(f32),
+    Relative(f32),
+}
+
+impl Offset {
+    fn absolute(self, bounds: Rectangle, content_bounds: Rectangle) -> f32 {
+        let hidden_content = (content_bounds.height - bounds.height).max(0.0);
+
+        match self {
+            Offset::Absolute(offset) => offset.min(hidden_content),
+            Offset::Relative(percentage) => {
+                (hidden_content * percentage).round()
+            }
+        }
+    }
+}
+
+impl Default for Offset {
+    fn default() -> Self {
+        Offset::Absolute(0.0)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Animation {
+    Smooth {
+        from: f32,
+        started_at: Instant,
+        duration: Duration,
+    },
+    Kinetic {
+        velocity: f32,
+        updated_at: Instant,
+    },
+}
+
+/// A scrollable area and the way it scrolls.
+///
+/// Widgets that scroll their contents with a [`State`] describe themselves
+/// with it when processing events.
+///
+/// [`State`]: struct.State.html
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Area {
+    /// The bounds of the visible part of the area
+    pub bounds: Rectangle,
+
+    /// The bounds of the whole contents of the area
+    pub content_bounds: Rectangle,
+
+    /// The distance scrolled by a wheel line or an arrow key
+    pub line_height: f32,
+
+    /// The duration of smooth scrolling animations
+    pub animation_duration: Duration,
+}
+
+/// The time between the frames of a scrolling animation.
+pub(crate) const FRAME_INTERVAL: Duration = Duration::from_millis(16);
+
+/// The deceleration rate of kinetic scrolling, per second.
+const FRICTION: f32 = 4.0;
+
+/// The velocity, in pixels per second, under which kinetic scrolling stops.
+const MIN_VELOCITY: f32 = 10.0;
+
+/// The time without new deltas after which kinetic scrolling kicks in.
+const KINETIC_DELAY: Duration = Duration::from_millis(50);
+
+/// The minimum interval between deltas used to estimate velocity, in seconds.
+const MIN_SAMPLE_INTERVAL: f32 = 1.0 / 120.0;
+
+fn kinetic_elapsed(updated_at: Instant, now: Instant) -> f32 {
+    (now.duration_since(updated_at).as_secs_f32() - KINETIC_DELAY.as_secs_f32())
+        .max(0.0)
 }
 
 /// The renderer of a [`Scrollable`].
@@ -0,0 +1,479 @@
+//! Display text that your users can select and copy.
+//!
+//! A [`SelectableText`] has some local [`State`].
+//!
+//! [`SelectableText`]: struct.SelectableText.html
+//! [`State`]: struct.State.html
+use crate::{
+    input::{keyboard, mouse, ButtonState},
+    layout, text, Clipboard, Color, Element, Event, Font, Hasher,
+    HorizontalAlignment, Layout, Length, Overflow, Point, Rectangle, Size,
+    VerticalAlignment, Widget, Wrap,
+};
+
+use std::{
+    hash::Hash,
+    time::{Duration, Instant},
+};
+
+/// The maximum time between two clicks for them to be considered a double
+/// click.
+const DOUBLE_CLICK_INTERVAL: Duration = Duration::from_millis(500);
+
+/// A paragraph of text that can be selected with the mouse and copied to the
+/// [`Clipboard`].
+///
+/// Dragging the mouse selects text and double clicking selects a word.
+/// `Ctrl+C` copies the selection, while `Ctrl+A` selects all of the text when
+/// the cursor is over it.
+///
+/// # Example
+///
+/// ```
+/// # use iced_native::{selectable_text, SelectableText};
+/// #
+/// let mut state = selectable_text::State::new();
+///
+/// SelectableText::new(&mut state, "Error 0x80070005: access denied")
+///     .size(16);
+/// ```
+///
+/// [`Clipboard`]: ../../trait.Clipboard.html
+#[allow(missing_debug_implementations)]
+pub struct SelectableText<'a> {
+    state: &'a mut State,
+    content: String,
+    size: Option<u16>,
+    color: Option<Color>,
+    font: Font,
+    format: text::Format,
+    width: Length,
+    height: Length,
+    horizontal_alignment: HorizontalAlignment,
+    vertical_alignment: VerticalAlignment,
+}
+
+impl<'a> SelectableText<'a> {
+    /// Creates a new [`SelectableText`] with the given [`State`] and
+    /// contents.
+    ///
+    /// [`SelectableText`]: struct.SelectableText.html
+    /// [`State`]: struct.State.html
+    pub fn new<T: Into<String>>(state: &'a mut State, content: T) -> Self {
+        SelectableText {
+            state,
+            content: content.into(),
+            size: None,
+            color: None,
+            font: Font::Default,
+            format: text::Format::default(),
+            width: Length::Fill,
+            height: Length::Shrink,
+            horizontal_alignment: HorizontalAlignment::Left,
+            vertical_alignment: VerticalAlignment::Top,
+        }
+    }
+
+    /// Sets the size of the [`SelectableText`].
+    ///
+    /// [`SelectableText`]: struct.SelectableText.html
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the [`Color`] of the [`SelectableText`].
+    ///
+    /// [`SelectableText`]: struct.SelectableText.html
+    /// [`Color`]: ../../struct.Color.html
+    pub fn color<C: Into<Color>>(mut self, color: C) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Sets the [`Font`] of the [`SelectableText`].
+    ///
+    /// [`SelectableText`]: struct.SelectableText.html
+    /// [`Font`]: ../../struct.Font.html
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the [`Wrap`] strategy of the [`SelectableText`].
+    ///
+    /// [`SelectableText`]: struct.SelectableText.html
+    /// [`Wrap`]: ../../enum.Wrap.html
+    pub fn wrap(mut self, wrap: Wrap) -> Self {
+        self.format.wrap = wrap;
+        self
+    }
+
+    /// Sets the [`Overflow`] strategy of the [`SelectableText`].
+    ///
+    /// [`SelectableText`]: struct.SelectableText.html
+    /// [`Overflow`]: ../../enum.Overflow.html
+    pub fn overflow(mut self, overflow: Overflow) -> Self {
+        self.format.overflow = overflow;
+        self
+    }
+
+    /// Sets the line height of the [`SelectableText`], relative to its size.
+    ///
+    /// [`SelectableText`]: struct.SelectableText.html
+    pub fn line_height(mut self, line_height: f32) -> Self {
+        self.format.line_height = Some(line_height);
+        self
+    }
+
+    /// Sets the extra space between the characters of the
+    /// [`SelectableText`], in pixels.
+    ///
+    /// [`SelectableText`]: struct.SelectableText.html
+    pub fn letter_spacing(mut self, letter_spacing: f32) -> Self {
+        self.format.letter_spacing = letter_spacing;
+        self
+    }
+
+    /// Sets the width of the [`SelectableText`] boundaries.
+    ///
+    /// [`SelectableText`]: struct.SelectableText.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`SelectableText`] boundaries.
+    ///
+    /// [`SelectableText`]: struct.SelectableText.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the [`HorizontalAlignment`] of the [`SelectableText`].
+    ///
+    /// [`SelectableText`]: struct.SelectableText.html
+    /// [`HorizontalAlignment`]: ../../enum.HorizontalAlignment.html
+    pub fn horizontal_alignment(
+        mut self,
+        alignment: HorizontalAlignment,
+    ) -> Self {
+        self.horizontal_alignment = alignment;
+        self
+    }
+
+    /// Sets the [`VerticalAlignment`] of the [`SelectableText`].
+    ///
+    /// [`SelectableText`]: struct.SelectableText.html
+    /// [`VerticalAlignment`]: ../../enum.VerticalAlignment.html
+    pub fn vertical_alignment(mut self, alignment: VerticalAlignment) -> Self {
+        self.vertical_alignment = alignment;
+        self
+    }
+
+    fn paragraph(
+        &self,
+        bounds: Rectangle,
+        default_size: u16,
+    ) -> text::Paragraph<'_> {
+        text::Paragraph {
+            content: &self.content,
+            bounds,
+            size: self.size.unwrap_or(default_size),
+            font: self.font,
+            format: self.format,
+            horizontal_alignment: self.horizontal_alignment,
+            vertical_alignment: self.vertical_alignment,
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for SelectableText<'a>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+        let size = self.size.unwrap_or(renderer.default_size());
+
+        let (width, height) = renderer.measure(
+            &self.content,
+            size,
+            self.font,
+            self.format,
+            limits.max(),
+        );
+
+        let ascent = renderer.ascent(size, self.font, self.format);
+
+        let size = limits.resolve(Size::new(width, height));
+
+        // The text is aligned inside of its bounds when drawn
+        let offset = match self.vertical_alignment {
+            VerticalAlignment::Top => 0.0,
+            VerticalAlignment::Center => (size.height - height) / 2.0,
+            VerticalAlignment::Bottom => size.height - height,
+        };
+
+        layout::Node::new(size).with_baseline(offset + ascent)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::Input {
+                button: mouse::Button::Left,
+                state: ButtonState::Pressed,
+            }) => {
+                if !bounds.contains(cursor_position) {
+                    self.state.selection = None;
+                    self.state.last_click = None;
+                    return;
+                }
+
+                let index = renderer.hit_test(
+                    &self.paragraph(bounds, renderer.default_size()),
+                    cursor_position,
+                );
+
+                let now = Instant::now();
+
+                let is_double_click = match self.state.last_click {
+                    Some((time, position)) => {
+                        now.duration_since(time) < DOUBLE_CLICK_INTERVAL
+                            && (position.x - cursor_position.x).abs() < 4.0
+                            && (position.y - cursor_position.y).abs() < 4.0
+                    }
+                    None => false,
+                };
+
+                if is_double_click {
+                    self.state.selection = Some(word_at(&self.content, index));
+                    self.state.is_dragging = false;
+                    self.state.last_click = None;
+                } else {
+                    self.state.selection = Some((index, index));
+                    self.state.is_dragging = true;
+                    self.state.last_click = Some((now, cursor_position));
+                }
+            }
+            Event::Mouse(mouse::Event::Input {
+                button: mouse::Button::Left,
+                state: ButtonState::Released,
+            }) => {
+                self.state.is_dragging = false;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+                if self.state.is_dragging =>
+            {
+                let index = renderer.hit_test(
+                    &self.paragraph(bounds, renderer.default_size()),
+                    cursor_position,
+                );
+
+                if let Some((anchor, _)) = self.state.selection {
+                    self.state.selection = Some((anchor, index));
+                }
+            }
+            Event::Keyboard(keyboard::Event::Input {
+                key_code,
+                state: ButtonState::Pressed,
+                modifiers,
+            }) if modifiers.control || modifiers.logo => match key_code {
+                keyboard::KeyCode::C if self.state.selection.is_some() => {
+                    if let Some(clipboard) = clipboard {
+                        let selected = self.state.selected(&self.content);
+
+                        if !selected.is_empty() {
+                            clipboard.write(selected);
+                        }
+                    }
+                }
+                keyboard::KeyCode::A if bounds.contains(cursor_position) => {
+                    self.state.selection =
+                        Some((0, self.content.chars().count()));
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let bounds = layout.bounds();
+        let paragraph = self.paragraph(bounds, renderer.default_size());
+
+        self::Renderer::draw(
+            renderer,
+            &paragraph,
+            self.color,
+            self.state.range(),
+            bounds.contains(cursor_position),
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        self.content.hash(state);
+        self.size.hash(state);
+        self.format.hash(state);
+        self.width.hash(state);
+        self.height.hash(state);
+    }
+}
+
+/// The local state of a [`SelectableText`].
+///
+/// [`SelectableText`]: struct.SelectableText.html
+#[derive(Debug, Clone, Copy, Default)]
+pub struct State {
+    // The anchor and the moving end of the selection, as character indices
+    selection: Option<(usize, usize)>,
+    is_dragging: bool,
+    last_click: Option<(Instant, Point)>,
+}
+
+impl State {
+    /// Creates a new [`State`] with nothing selected.
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> Self {
+        State::default()
+    }
+
+    /// Returns the range of characters currently selected, if any.
+    ///
+    /// The start of the range is always before its end.
+    pub fn range(&self) -> Option<(usize, usize)> {
+        self.selection
+            .map(|(a, b)| (a.min(b), a.max(b)))
+            .filter(|(start, end)| start < end)
+    }
+
+    /// Returns the selected part of the given contents.
+    pub fn selected(&self, content: &str) -> String {
+        match self.range() {
+            Some((start, end)) => {
+                content.chars().skip(start).take(end - start).collect()
+            }
+            None => String::new(),
+        }
+    }
+
+    /// Clears the selection.
+    pub fn clear(&mut self) {
+        self.selection = None;
+        self.is_dragging = false;
+    }
+}
+
+/// Returns the range of the word containing the character at the given
+/// index.
+///
+/// Runs of whitespace and single punctuation characters are considered words
+/// of their own.
+fn word_at(content: &str, index: usize) -> (usize, usize) {
+    let characters: Vec<char> = content.chars().collect();
+
+    if characters.is_empty() {
+        return (0, 0);
+    }
+
+    // Clicking past the end of a line selects its last word
+    let index = index.min(characters.len() - 1);
+
+    let class = |c: char| {
+        if c.is_alphanumeric() || c == '_' {
+            0
+        } else if c.is_whitespace() {
+            1
+        } else {
+            2
+        }
+    };
+
+    let current = class(characters[index]);
+
+    if current == 2 {
+        return (index, index + 1);
+    }
+
+    let start = characters[..index]
+        .iter()
+        .rposition(|&c| class(c) != current)
+        .map(|i| i + 1)
+        .unwrap_or(0);
+
+    let end = characters[index..]
+        .iter()
+        .position(|&c| class(c) != current)
+        .map(|i| index + i)
+        .unwrap_or(characters.len());
+
+    (start, end)
+}
+
+/// The renderer of a [`SelectableText`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`SelectableText`] in your user interface.
+///
+/// [`SelectableText`]: struct.SelectableText.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: text::Renderer {
+    /// Draws a [`SelectableText`].
+    ///
+    /// It receives:
+    ///   * the [`Paragraph`] of the [`SelectableText`]
+    ///   * the color of the text, if any
+    ///   * the range of characters selected, if any
+    ///   * whether the mouse is over the [`SelectableText`] or not
+    ///
+    /// [`SelectableText`]: struct.SelectableText.html
+    /// [`Paragraph`]: ../text/struct.Paragraph.html
+    fn draw(
+        &mut self,
+        paragraph: &text::Paragraph<'_>,
+        color: Option<Color>,
+        selection: Option<(usize, usize)>,
+        is_mouse_over: bool,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<SelectableText<'a>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn from(
+        selectable_text: SelectableText<'a>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(selectable_text)
+    }
+}
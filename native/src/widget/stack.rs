@@ -0,0 +1,267 @@
+//! Display content on top of other content.
+use std::hash::Hash;
+
+use crate::{
+    layout, Align, Clipboard, Element, Event, Hasher, Layout, Length, Point,
+    Size, Widget,
+};
+
+/// A container that displays its contents on top of each other.
+///
+/// All the children of a [`Stack`] share the same bounds, which fit the
+/// biggest of them. Every child is aligned on its own inside of them. Later
+/// children are displayed on top of earlier ones, and they hide the cursor
+/// from the children below them.
+///
+/// [`Stack`]: struct.Stack.html
+#[allow(missing_debug_implementations)]
+pub struct Stack<'a, Message, Renderer> {
+    width: Length,
+    height: Length,
+    max_width: u32,
+    max_height: u32,
+    alignments: Vec<(Align, Align)>,
+    children: Vec<Element<'a, Message, Renderer>>,
+}
+
+impl<'a, Message, Renderer> Stack<'a, Message, Renderer> {
+    /// Creates an empty [`Stack`].
+    ///
+    /// [`Stack`]: struct.Stack.html
+    pub fn new() -> Self {
+        Stack {
+            width: Length::Shrink,
+            height: Length::Shrink,
+            max_width: u32::MAX,
+            max_height: u32::MAX,
+            alignments: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+
+    /// Sets the width of the [`Stack`].
+    ///
+    /// [`Stack`]: struct.Stack.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Stack`].
+    ///
+    /// [`Stack`]: struct.Stack.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the maximum width of the [`Stack`].
+    ///
+    /// [`Stack`]: struct.Stack.html
+    pub fn max_width(mut self, max_width: u32) -> Self {
+        self.max_width = max_width;
+        self
+    }
+
+    /// Sets the maximum height of the [`Stack`] in pixels.
+    ///
+    /// [`Stack`]: struct.Stack.html
+    pub fn max_height(mut self, max_height: u32) -> Self {
+        self.max_height = max_height;
+        self
+    }
+
+    /// Adds an element on top of the contents of the [`Stack`], aligned to
+    /// its top left corner.
+    ///
+    /// [`Stack`]: struct.Stack.html
+    pub fn push<E>(self, child: E) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        self.push_aligned(child, Align::Start, Align::Start)
+    }
+
+    /// Adds an element on top of the contents of the [`Stack`], with the
+    /// given horizontal and vertical alignment.
+    ///
+    /// [`Stack`]: struct.Stack.html
+    pub fn push_aligned<E>(
+        mut self,
+        child: E,
+        horizontal_alignment: Align,
+        vertical_alignment: Align,
+    ) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        self.alignments
+            .push((horizontal_alignment, vertical_alignment));
+        self.children.push(child.into());
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Default for Stack<'a, Message, Renderer> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Stack<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits
+            .max_width(self.max_width)
+            .max_height(self.max_height)
+            .width(self.width)
+            .height(self.height);
+
+        let content = self.children.iter().fold(Size::ZERO, |size, child| {
+            let child_size = child.layout(renderer, &limits).size();
+
+            Size::new(
+                size.width.max(child_size.width),
+                size.height.max(child_size.height),
+            )
+        });
+
+        let size = limits.resolve(content);
+
+        // Children are laid out again, so the ones filling the stack fit the
+        // bounds of the biggest one
+        let children = self
+            .children
+            .iter()
+            .zip(&self.alignments)
+            .map(|(child, (horizontal_alignment, vertical_alignment))| {
+                let mut node = child
+                    .layout(renderer, &layout::Limits::new(Size::ZERO, size));
+
+                node.align(*horizontal_alignment, *vertical_alignment, size);
+
+                node
+            })
+            .collect();
+
+        layout::Node::with_children(size, children)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        let layouts: Vec<Layout<'_>> = layout.children().collect();
+        let mut is_covered = false;
+
+        // The topmost child gets the events first, and the children below the
+        // one under the cursor cannot see it
+        for (child, layout) in self.children.iter_mut().zip(layouts).rev() {
+            let child_cursor_position = if is_covered {
+                Point::new(f32::NEG_INFINITY, f32::NEG_INFINITY)
+            } else {
+                cursor_position
+            };
+
+            child.widget.on_event(
+                event,
+                layout,
+                child_cursor_position,
+                messages,
+                renderer,
+                clipboard,
+            );
+
+            is_covered =
+                is_covered || layout.bounds().contains(cursor_position);
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        renderer.draw(&self.children, layout, cursor_position)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        std::any::TypeId::of::<Stack<'static, (), ()>>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+        self.max_width.hash(state);
+        self.max_height.hash(state);
+        self.alignments.hash(state);
+
+        for child in &self.children {
+            child.widget.hash_layout(state);
+        }
+    }
+
+    fn is_focused(&self) -> bool {
+        self.children.iter().any(|child| child.widget.is_focused())
+    }
+}
+
+/// The renderer of a [`Stack`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`Stack`] in your user interface.
+///
+/// [`Stack`]: struct.Stack.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: crate::Renderer + Sized {
+    /// Draws a [`Stack`].
+    ///
+    /// It receives:
+    /// - the children of the [`Stack`]
+    /// - the [`Layout`] of the [`Stack`] and its children
+    /// - the cursor position
+    ///
+    /// Every child must be drawn on top of the previous ones. Only the
+    /// topmost child under the cursor should be able to see it.
+    ///
+    /// [`Stack`]: struct.Stack.html
+    /// [`Layout`]: ../layout/struct.Layout.html
+    fn draw<Message>(
+        &mut self,
+        children: &[Element<'_, Message, Self>],
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Stack<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer,
+    Message: 'static,
+{
+    fn from(
+        stack: Stack<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(stack)
+    }
+}
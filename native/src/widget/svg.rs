@@ -0,0 +1,146 @@
+//! Display vector graphics in your user interface.
+use crate::{layout, Element, Hasher, Layout, Length, Point, Size, Widget};
+
+use std::hash::Hash;
+
+/// A vector graphics image.
+///
+/// An [`Svg`] image resizes smoothly without losing any quality.
+///
+/// [`Svg`] images can have a considerable rendering cost when resized,
+/// specially when they are complex.
+///
+/// # Example
+///
+/// ```
+/// # use iced_native::Svg;
+/// #
+/// let svg = Svg::new("resources/tiger.svg");
+/// ```
+///
+/// [`Svg`]: struct.Svg.html
+#[derive(Debug)]
+pub struct Svg {
+    path: String,
+    width: Length,
+    height: Length,
+}
+
+impl Svg {
+    /// Creates a new [`Svg`] from the given path.
+    ///
+    /// [`Svg`]: struct.Svg.html
+    pub fn new<T: Into<String>>(path: T) -> Self {
+        Svg {
+            path: path.into(),
+            width: Length::Fill,
+            height: Length::Shrink,
+        }
+    }
+
+    /// Sets the width of the [`Svg`].
+    ///
+    /// [`Svg`]: struct.Svg.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Svg`].
+    ///
+    /// [`Svg`]: struct.Svg.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for Svg
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let (width, height) = renderer.viewport_dimensions(&self.path);
+
+        // An empty document has no aspect ratio to keep
+        if width == 0 || height == 0 {
+            return layout::Node::new(Size::ZERO);
+        }
+
+        let aspect_ratio = width as f32 / height as f32;
+
+        let mut size = limits
+            .width(self.width)
+            .height(self.height)
+            .resolve(Size::new(width as f32, height as f32));
+
+        // Keep the intrinsic aspect ratio of the document
+        let viewport_aspect_ratio = size.width / size.height;
+
+        if viewport_aspect_ratio > aspect_ratio {
+            size.width = width as f32 * size.height / height as f32;
+        } else {
+            size.height = height as f32 * size.width / width as f32;
+        }
+
+        layout::Node::new(size)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+    ) -> Renderer::Output {
+        renderer.draw(&self.path, layout)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        std::any::TypeId::of::<Svg>().hash(state);
+
+        self.path.hash(state);
+        self.width.hash(state);
+        self.height.hash(state);
+    }
+}
+
+/// The renderer of an [`Svg`].
+///
+/// Your [renderer] will need to implement this trait before being able to use
+/// an [`Svg`] in your user interface.
+///
+/// [`Svg`]: struct.Svg.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: crate::Renderer {
+    /// Returns the default dimensions of an [`Svg`] located on the given
+    /// path.
+    ///
+    /// [`Svg`]: struct.Svg.html
+    fn viewport_dimensions(&self, path: &str) -> (u32, u32);
+
+    /// Draws an [`Svg`].
+    ///
+    /// [`Svg`]: struct.Svg.html
+    fn draw(&mut self, path: &str, layout: Layout<'_>) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Svg> for Element<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn from(svg: Svg) -> Element<'a, Message, Renderer> {
+        Element::new(svg)
+    }
+}
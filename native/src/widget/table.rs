@@ -0,0 +1,791 @@
+//! Display tabular data with sortable and resizable columns.
+use crate::{
+    column,
+    input::{keyboard, mouse, ButtonState},
+    layout, scrollable, Clipboard, Element, Event, Hasher, Layout, Length,
+    Point, Rectangle, Size, Widget,
+};
+
+use std::{f32, hash::Hash, time::Duration};
+
+/// A widget displaying rows of data under a header of titled columns.
+///
+/// Only the rows inside the viewport are built, laid out and drawn. The
+/// header stays visible while the rows are scrolled.
+///
+/// The application owns the width, the sorting and the selection of the
+/// [`Table`]. It receives messages when the user wants to change them.
+///
+/// [`Table`]: struct.Table.html
+#[allow(missing_debug_implementations)]
+pub struct Table<'a, Message, Renderer> {
+    state: &'a mut State,
+    columns: Vec<Column>,
+    rows: usize,
+    cell: Box<dyn Fn(usize, usize) -> Element<'a, Message, Renderer> + 'a>,
+    selected: Option<usize>,
+    width: Length,
+    height: Length,
+    max_height: u32,
+    row_height: u16,
+    animation_duration: Duration,
+    on_sort: Option<Box<dyn Fn(usize) -> Message>>,
+    on_resize: Option<Box<dyn Fn(usize, u16) -> Message>>,
+    on_select: Option<Box<dyn Fn(usize) -> Message>>,
+}
+
+impl<'a, Message, Renderer> Table<'a, Message, Renderer> {
+    /// Creates a new [`Table`] with the given [`State`], columns, amount of
+    /// rows and a closure producing the cell at a given row and column.
+    ///
+    /// [`Table`]: struct.Table.html
+    /// [`State`]: struct.State.html
+    pub fn new<F>(
+        state: &'a mut State,
+        columns: Vec<Column>,
+        rows: usize,
+        cell: F,
+    ) -> Self
+    where
+        F: 'a + Fn(usize, usize) -> Element<'a, Message, Renderer>,
+    {
+        Table {
+            state,
+            columns,
+            rows,
+            cell: Box::new(cell),
+            selected: None,
+            width: Length::Fill,
+            height: Length::Fill,
+            max_height: u32::MAX,
+            row_height: 30,
+            animation_duration: Duration::from_millis(150),
+            on_sort: None,
+            on_resize: None,
+            on_select: None,
+        }
+    }
+
+    /// Sets the width of the [`Table`].
+    ///
+    /// [`Table`]: struct.Table.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Table`].
+    ///
+    /// [`Table`]: struct.Table.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the maximum height of the [`Table`] in pixels.
+    ///
+    /// [`Table`]: struct.Table.html
+    pub fn max_height(mut self, max_height: u32) -> Self {
+        self.max_height = max_height;
+        self
+    }
+
+    /// Sets the height of the header and the rows of the [`Table`].
+    ///
+    /// [`Table`]: struct.Table.html
+    pub fn row_height(mut self, row_height: u16) -> Self {
+        self.row_height = row_height;
+        self
+    }
+
+    /// Sets the duration of the smooth scrolling animation of the [`Table`].
+    ///
+    /// [`Table`]: struct.Table.html
+    pub fn animation_duration(mut self, animation_duration: Duration) -> Self {
+        self.animation_duration = animation_duration;
+        self
+    }
+
+    /// Sets the selected row of the [`Table`].
+    ///
+    /// [`Table`]: struct.Table.html
+    pub fn selected(mut self, selected: Option<usize>) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    /// Sets the message that should be produced when the title of a column
+    /// is clicked.
+    ///
+    /// The function receives the index of the column.
+    pub fn on_sort<F>(mut self, f: F) -> Self
+    where
+        F: 'static + Fn(usize) -> Message,
+    {
+        self.on_sort = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the message that should be produced when a column is resized by
+    /// dragging the right edge of its title.
+    ///
+    /// The function receives the index of the column and its new width.
+    pub fn on_resize<F>(mut self, f: F) -> Self
+    where
+        F: 'static + Fn(usize, u16) -> Message,
+    {
+        self.on_resize = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the message that should be produced when a row is selected,
+    /// either by clicking it or by using the arrow keys.
+    ///
+    /// The function receives the index of the row.
+    pub fn on_select<F>(mut self, f: F) -> Self
+    where
+        F: 'static + Fn(usize) -> Message,
+    {
+        self.on_select = Some(Box::new(f));
+        self
+    }
+
+    fn header(
+        &self,
+        bounds: Rectangle,
+        cursor_position: Point,
+    ) -> Vec<Header<'_>> {
+        let mut x = bounds.x;
+
+        self.columns
+            .iter()
+            .map(|column| {
+                let bounds = Rectangle {
+                    x,
+                    y: bounds.y,
+                    width: f32::from(column.width),
+                    height: f32::from(self.row_height),
+                };
+
+                x += bounds.width;
+
+                Header {
+                    title: &column.title,
+                    bounds,
+                    sort: column.sort,
+                    is_hovered: bounds.contains(cursor_position),
+                }
+            })
+            .collect()
+    }
+
+    fn resize_handle(
+        &self,
+        bounds: Rectangle,
+        cursor_position: Point,
+    ) -> Option<usize> {
+        if self.on_resize.is_none()
+            || cursor_position.y < bounds.y
+            || cursor_position.y > bounds.y + f32::from(self.row_height)
+        {
+            return None;
+        }
+
+        let mut right = bounds.x;
+
+        self.columns.iter().position(|column| {
+            right += f32::from(column.width);
+
+            (cursor_position.x - right).abs() <= RESIZE_HANDLE_WIDTH
+        })
+    }
+
+    fn visible_rows(
+        &self,
+        renderer: &Renderer,
+        body: Rectangle,
+        offset: f32,
+        cursor_position: Point,
+    ) -> (Vec<Element<'a, Message, Renderer>>, layout::Node, Vec<Row>)
+    where
+        Renderer: crate::Renderer,
+    {
+        let row_height = f32::from(self.row_height.max(1));
+        let content_size = self.content_size(body);
+
+        let first = (offset / row_height).floor() as usize;
+        let last = (((offset + body.height) / row_height).ceil() as usize)
+            .min(self.rows);
+
+        let mut cells = Vec::new();
+        let mut nodes = Vec::new();
+        let mut rows = Vec::new();
+
+        for index in first..last {
+            let y = index as f32 * row_height;
+            let mut x = 0.0;
+
+            for (i, column) in self.columns.iter().enumerate() {
+                let size = Size::new(f32::from(column.width), row_height);
+                let cell = (self.cell)(index, i);
+
+                let mut node =
+                    cell.layout(renderer, &layout::Limits::new(size, size));
+
+                node.bounds.x = x;
+                node.bounds.y = y;
+                x += size.width;
+
+                cells.push(cell);
+                nodes.push(node);
+            }
+
+            let bounds = Rectangle {
+                x: body.x,
+                y: body.y + y,
+                width: content_size.width,
+                height: row_height,
+            };
+
+            rows.push(Row {
+                index,
+                bounds,
+                is_selected: self.selected == Some(index),
+                is_hovered: bounds.contains(cursor_position),
+            });
+        }
+
+        let mut content = layout::Node::with_children(content_size, nodes);
+        content.bounds.x = body.x;
+        content.bounds.y = body.y;
+
+        (cells, content, rows)
+    }
+
+    fn content_size(&self, body: Rectangle) -> Size {
+        let width = self
+            .columns
+            .iter()
+            .map(|column| f32::from(column.width))
+            .sum::<f32>();
+
+        Size::new(
+            width.max(body.width),
+            self.rows as f32 * f32::from(self.row_height.max(1)),
+        )
+    }
+
+    fn body(&self, bounds: Rectangle) -> Rectangle {
+        let header_height = f32::from(self.row_height).min(bounds.height);
+
+        Rectangle {
+            y: bounds.y + header_height,
+            height: bounds.height - header_height,
+            ..bounds
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Table<'a, Message, Renderer>
+where
+    Renderer: self::Renderer + scrollable::Renderer + column::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits
+            .max_height(self.max_height)
+            .width(self.width)
+            .height(self.height);
+
+        let columns_width = self
+            .columns
+            .iter()
+            .map(|column| f32::from(column.width))
+            .sum::<f32>();
+
+        let content_height =
+            self.rows as f32 * f32::from(self.row_height.max(1));
+
+        let size = limits.resolve(Size::new(
+            columns_width,
+            f32::from(self.row_height) + content_height,
+        ));
+
+        let mut content = layout::Node::new(Size::new(
+            columns_width.max(size.width),
+            content_height,
+        ));
+
+        content.bounds.y = f32::from(self.row_height).min(size.height);
+
+        layout::Node::with_children(size, vec![content])
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        let bounds = layout.bounds();
+        let body = self.body(bounds);
+        let content_bounds = layout.children().next().unwrap().bounds();
+
+        if let Some(resizing) = self.state.resizing {
+            match event {
+                Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                    let width = (resizing.width + cursor_position.x
+                        - resizing.grabbed_at)
+                        .max(MIN_COLUMN_WIDTH)
+                        .round() as u16;
+
+                    // The column may be removed while it is being resized
+                    let column = match self.columns.get(resizing.column) {
+                        Some(column) => column,
+                        None => {
+                            self.state.resizing = None;
+                            return;
+                        }
+                    };
+
+                    if let Some(on_resize) = &self.on_resize {
+                        if width != column.width {
+                            messages.push(on_resize(resizing.column, width));
+                        }
+                    }
+                }
+                Event::Mouse(mouse::Event::Input {
+                    button: mouse::Button::Left,
+                    state: ButtonState::Released,
+                }) => {
+                    self.state.resizing = None;
+                }
+                _ => {}
+            }
+
+            return;
+        }
+
+        let is_mouse_over_body = body.contains(cursor_position);
+        let is_mouse_over_scrollbar = renderer.is_mouse_over_scrollbar(
+            body,
+            content_bounds,
+            cursor_position,
+        );
+
+        match event {
+            Event::Mouse(mouse::Event::Input {
+                button: mouse::Button::Left,
+                state: ButtonState::Pressed,
+            }) => {
+                self.state.is_focused = bounds.contains(cursor_position);
+
+                if let Some(column) =
+                    self.resize_handle(bounds, cursor_position)
+                {
+                    self.state.resizing = Some(Resizing {
+                        column,
+                        grabbed_at: cursor_position.x,
+                        width: f32::from(self.columns[column].width),
+                    });
+
+                    return;
+                }
+
+                let header = self.header(bounds, cursor_position);
+
+                if let Some(column) =
+                    header.iter().position(|header| header.is_hovered)
+                {
+                    if let Some(on_sort) = &self.on_sort {
+                        messages.push(on_sort(column));
+                    }
+
+                    return;
+                }
+
+                if is_mouse_over_body && !is_mouse_over_scrollbar {
+                    let offset =
+                        self.state.scrollable.offset(body, content_bounds);
+
+                    let index = ((cursor_position.y - body.y + offset as f32)
+                        / f32::from(self.row_height.max(1)))
+                    .floor() as usize;
+
+                    if index < self.rows {
+                        if let Some(on_select) = &self.on_select {
+                            messages.push(on_select(index));
+                        }
+                    }
+                }
+            }
+            Event::Keyboard(keyboard::Event::Input {
+                key_code: key_code @ keyboard::KeyCode::Up,
+                state: ButtonState::Pressed,
+                ..
+            })
+            | Event::Keyboard(keyboard::Event::Input {
+                key_code: key_code @ keyboard::KeyCode::Down,
+                state: ButtonState::Pressed,
+                ..
+            }) if self.state.is_focused
+                && self.on_select.is_some()
+                && self.rows > 0 =>
+            {
+                let index = match (self.selected, key_code) {
+                    (Some(selected), keyboard::KeyCode::Up) => {
+                        selected.saturating_sub(1)
+                    }
+                    (Some(selected), _) => (selected + 1).min(self.rows - 1),
+                    (None, _) => 0,
+                };
+
+                // Keep the selected row inside the viewport
+                let row_height = f32::from(self.row_height.max(1));
+                let top = index as f32 * row_height;
+                let offset =
+                    self.state.scrollable.offset(body, content_bounds) as f32;
+
+                if top < offset {
+                    self.state.scrollable.scroll_to(top);
+                } else if top + row_height > offset + body.height {
+                    self.state
+                        .scrollable
+                        .scroll_to(top + row_height - body.height);
+                }
+
+                if let Some(on_select) = &self.on_select {
+                    messages.push(on_select(index));
+                }
+
+                return;
+            }
+            _ => {}
+        }
+
+        self.state.scrollable.update(
+            event,
+            scrollable::Area {
+                bounds: body,
+                content_bounds,
+                line_height: f32::from(self.row_height),
+                animation_duration: self.animation_duration,
+            },
+            cursor_position,
+            is_mouse_over_scrollbar,
+        );
+
+        let offset = self.state.scrollable.offset(body, content_bounds);
+
+        let cursor_position = if is_mouse_over_body
+            && !(is_mouse_over_scrollbar
+                || self.state.scrollable.is_scrollbar_grabbed())
+        {
+            Point::new(cursor_position.x, cursor_position.y + offset as f32)
+        } else {
+            Point::new(cursor_position.x, -1.0)
+        };
+
+        let (mut cells, content, _) =
+            self.visible_rows(renderer, body, offset as f32, cursor_position);
+
+        for (cell, layout) in
+            cells.iter_mut().zip(Layout::new(&content).children())
+        {
+            cell.widget.on_event(
+                event,
+                layout,
+                cursor_position,
+                messages,
+                renderer,
+                clipboard,
+            );
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let bounds = layout.bounds();
+        let body = self.body(bounds);
+        let content_bounds = layout.children().next().unwrap().bounds();
+        let offset = self.state.scrollable.offset(body, content_bounds);
+
+        let now = std::time::Instant::now();
+
+        if self.state.scrollable.is_animating(now) {
+            renderer.request_redraw(now + scrollable::FRAME_INTERVAL);
+        }
+
+        let is_mouse_over_body = body.contains(cursor_position);
+        let is_mouse_over_scrollbar = renderer.is_mouse_over_scrollbar(
+            body,
+            content_bounds,
+            cursor_position,
+        );
+
+        let body_cursor_position = if is_mouse_over_body
+            && !is_mouse_over_scrollbar
+            && self.state.resizing.is_none()
+        {
+            Point::new(cursor_position.x, cursor_position.y + offset as f32)
+        } else {
+            Point::new(cursor_position.x, -1.0)
+        };
+
+        let (cells, content, rows) = self.visible_rows(
+            renderer,
+            body,
+            offset as f32,
+            body_cursor_position,
+        );
+
+        let cells = column::Renderer::draw(
+            renderer,
+            &cells,
+            Layout::new(&content),
+            body_cursor_position,
+        );
+
+        let rows = self::Renderer::draw_rows(renderer, &rows, cells);
+
+        let body = scrollable::Renderer::draw(
+            renderer,
+            &self.state.scrollable,
+            body,
+            content_bounds,
+            is_mouse_over_body,
+            is_mouse_over_scrollbar,
+            offset,
+            rows,
+        );
+
+        let is_resizing = self.state.resizing.is_some()
+            || self.resize_handle(bounds, cursor_position).is_some();
+
+        let header = if self.state.resizing.is_some() {
+            self.header(bounds, Point::new(cursor_position.x, -1.0))
+        } else {
+            self.header(bounds, cursor_position)
+        };
+
+        self::Renderer::draw(renderer, bounds, &header, is_resizing, body)
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        std::any::TypeId::of::<Table<'static, (), ()>>().hash(state);
+
+        self.rows.hash(state);
+        self.row_height.hash(state);
+        self.width.hash(state);
+        self.height.hash(state);
+        self.max_height.hash(state);
+
+        for column in &self.columns {
+            column.width.hash(state);
+        }
+    }
+
+    fn is_focused(&self) -> bool {
+        self.state.is_focused
+    }
+}
+
+/// A column of a [`Table`].
+///
+/// [`Table`]: struct.Table.html
+#[derive(Debug, Clone)]
+pub struct Column {
+    title: String,
+    width: u16,
+    sort: Option<Sort>,
+}
+
+impl Column {
+    /// Creates a new [`Column`] with the given title and width.
+    ///
+    /// [`Column`]: struct.Column.html
+    pub fn new(title: impl Into<String>, width: u16) -> Self {
+        Column {
+            title: title.into(),
+            width,
+            sort: None,
+        }
+    }
+
+    /// Sets the [`Sort`] indicator of the [`Column`].
+    ///
+    /// [`Sort`]: enum.Sort.html
+    /// [`Column`]: struct.Column.html
+    pub fn sort(mut self, sort: Sort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+}
+
+/// The sorting order of a [`Column`].
+///
+/// [`Column`]: struct.Column.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Sort {
+    /// Smallest values first.
+    Ascending,
+
+    /// Largest values first.
+    Descending,
+}
+
+/// The title of a [`Column`], ready to be drawn.
+///
+/// [`Column`]: struct.Column.html
+#[derive(Debug, Clone, Copy)]
+pub struct Header<'a> {
+    /// The title of the [`Column`].
+    ///
+    /// [`Column`]: struct.Column.html
+    pub title: &'a str,
+
+    /// The bounds of the title.
+    pub bounds: Rectangle,
+
+    /// The [`Sort`] indicator of the [`Column`], if any.
+    ///
+    /// [`Sort`]: enum.Sort.html
+    /// [`Column`]: struct.Column.html
+    pub sort: Option<Sort>,
+
+    /// Whether the mouse is over the title or not.
+    pub is_hovered: bool,
+}
+
+/// A visible row of a [`Table`], ready to be drawn.
+///
+/// [`Table`]: struct.Table.html
+#[derive(Debug, Clone, Copy)]
+pub struct Row {
+    /// The index of the row.
+    pub index: usize,
+
+    /// The bounds of the row.
+    pub bounds: Rectangle,
+
+    /// Whether the row is selected or not.
+    pub is_selected: bool,
+
+    /// Whether the mouse is over the row or not.
+    pub is_hovered: bool,
+}
+
+/// The local state of a [`Table`].
+///
+/// [`Table`]: struct.Table.html
+#[derive(Debug, Clone, Copy, Default)]
+pub struct State {
+    scrollable: scrollable::State,
+    resizing: Option<Resizing>,
+    is_focused: bool,
+}
+
+impl State {
+    /// Creates a new [`State`] with the scrollbar located at the top.
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> Self {
+        State::default()
+    }
+
+    /// Returns a mutable reference to the [`scrollable::State`] of the
+    /// rows of the [`Table`].
+    ///
+    /// You can use it to scroll the [`Table`] programmatically.
+    ///
+    /// [`scrollable::State`]: ../scrollable/struct.State.html
+    /// [`Table`]: struct.Table.html
+    pub fn scrollable_mut(&mut self) -> &mut scrollable::State {
+        &mut self.scrollable
+    }
+
+    /// Returns whether a column is currently being resized or not.
+    pub fn is_resizing(&self) -> bool {
+        self.resizing.is_some()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Resizing {
+    column: usize,
+    grabbed_at: f32,
+    width: f32,
+}
+
+const RESIZE_HANDLE_WIDTH: f32 = 4.0;
+const MIN_COLUMN_WIDTH: f32 = 20.0;
+
+/// The renderer of a [`Table`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`Table`] in your user interface.
+///
+/// [`Table`]: struct.Table.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: crate::Renderer + Sized {
+    /// Draws the visible rows of a [`Table`].
+    ///
+    /// It receives:
+    /// - the visible [`Row`]s
+    /// - the drawn cells of the visible rows
+    ///
+    /// [`Table`]: struct.Table.html
+    /// [`Row`]: struct.Row.html
+    fn draw_rows(&mut self, rows: &[Row], cells: Self::Output) -> Self::Output;
+
+    /// Draws a [`Table`].
+    ///
+    /// It receives:
+    /// - the bounds of the [`Table`]
+    /// - the [`Header`] of every column
+    /// - whether the mouse is over a resize handle or a column is being
+    ///   resized
+    /// - the drawn rows, already scrolled
+    ///
+    /// [`Table`]: struct.Table.html
+    /// [`Header`]: struct.Header.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        header: &[Header<'_>],
+        is_resizing: bool,
+        body: Self::Output,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Table<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer + scrollable::Renderer + column::Renderer,
+    Message: 'static,
+{
+    fn from(
+        table: Table<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(table)
+    }
+}
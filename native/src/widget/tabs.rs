@@ -0,0 +1,495 @@
+//! Switch between different views with a bar of tabs.
+use crate::{
+    input::{keyboard, mouse, ButtonState},
+    layout, text, Clipboard, Element, Event, Font, Hasher, Layout, Length,
+    Point, Rectangle, Size, Widget,
+};
+
+use std::hash::Hash;
+
+/// A bar of [`Tab`]s on top of the contents of the active one.
+///
+/// Only the contents of the active [`Tab`] are built. The application owns
+/// the active [`Tab`] and receives a message when the user selects another
+/// one, either by clicking it or by pressing `Ctrl+Tab` and `Ctrl+Shift+Tab`
+/// while the [`Tabs`] are focused.
+///
+/// [`Tab`]: struct.Tab.html
+/// [`Tabs`]: struct.Tabs.html
+#[allow(missing_debug_implementations)]
+pub struct Tabs<'a, Message, Renderer> {
+    state: &'a mut State,
+    tabs: Vec<Tab>,
+    active: usize,
+    content: Element<'a, Message, Renderer>,
+    on_select: Box<dyn Fn(usize) -> Message>,
+    on_close: Option<Box<dyn Fn(usize) -> Message>>,
+    width: Length,
+    height: Length,
+    text_size: Option<u16>,
+    padding: u16,
+}
+
+impl<'a, Message, Renderer> Tabs<'a, Message, Renderer> {
+    /// Creates a new [`Tabs`] widget with the given [`State`] and [`Tab`]s,
+    /// the index of the active one, its contents and a function producing a
+    /// message when a [`Tab`] is selected.
+    ///
+    /// [`Tabs`]: struct.Tabs.html
+    /// [`State`]: struct.State.html
+    /// [`Tab`]: struct.Tab.html
+    pub fn new<E, F>(
+        state: &'a mut State,
+        tabs: Vec<Tab>,
+        active: usize,
+        content: E,
+        f: F,
+    ) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+        F: 'static + Fn(usize) -> Message,
+    {
+        Tabs {
+            state,
+            tabs,
+            active,
+            content: content.into(),
+            on_select: Box::new(f),
+            on_close: None,
+            width: Length::Fill,
+            height: Length::Shrink,
+            text_size: None,
+            padding: 10,
+        }
+    }
+
+    /// Sets the width of the [`Tabs`].
+    ///
+    /// [`Tabs`]: struct.Tabs.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Tabs`].
+    ///
+    /// [`Tabs`]: struct.Tabs.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the text size of the [`Tab`]s.
+    ///
+    /// [`Tab`]: struct.Tab.html
+    pub fn text_size(mut self, size: u16) -> Self {
+        self.text_size = Some(size);
+        self
+    }
+
+    /// Sets the padding of every [`Tab`].
+    ///
+    /// [`Tab`]: struct.Tab.html
+    pub fn padding(mut self, units: u16) -> Self {
+        self.padding = units;
+        self
+    }
+
+    /// Shows a close button on every [`Tab`], producing the given message
+    /// when pressed.
+    ///
+    /// The function receives the index of the [`Tab`] to close.
+    ///
+    /// [`Tab`]: struct.Tab.html
+    pub fn on_close<F>(mut self, f: F) -> Self
+    where
+        F: 'static + Fn(usize) -> Message,
+    {
+        self.on_close = Some(Box::new(f));
+        self
+    }
+
+    fn headers(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Vec<Header<'_>> {
+        let bar = layout.children().next().unwrap();
+
+        self.tabs
+            .iter()
+            .zip(bar.children())
+            .enumerate()
+            .map(|(i, (tab, layout))| {
+                let bounds = layout.bounds();
+
+                let close_bounds = if self.on_close.is_some() {
+                    let size = bounds.height - f32::from(2 * self.padding);
+
+                    Some(Rectangle {
+                        x: bounds.x + bounds.width
+                            - f32::from(self.padding)
+                            - size,
+                        y: bounds.y + f32::from(self.padding),
+                        width: size,
+                        height: size,
+                    })
+                } else {
+                    None
+                };
+
+                Header {
+                    text: tab.text.as_deref(),
+                    icon: tab.icon,
+                    bounds,
+                    close_bounds,
+                    is_active: i == self.active,
+                    is_hovered: bounds.contains(cursor_position),
+                    is_close_hovered: close_bounds
+                        .map(|bounds| bounds.contains(cursor_position))
+                        .unwrap_or(false),
+                }
+            })
+            .collect()
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Tabs<'a, Message, Renderer>
+where
+    Renderer: self::Renderer + text::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+
+        let text_size = self.text_size.unwrap_or(renderer.default_size());
+        let padding = f32::from(self.padding);
+        let spacing = padding / 2.0;
+        let icon_size = f32::from(text_size);
+        let bar_height = icon_size + 2.0 * padding;
+
+        let mut x = 0.0;
+
+        let tabs = self
+            .tabs
+            .iter()
+            .map(|tab| {
+                let mut parts = Vec::new();
+
+                if tab.icon.is_some() {
+                    parts.push(icon_size);
+                }
+
+                if let Some(text) = &tab.text {
+                    let (width, _) = renderer.measure(
+                        text,
+                        text_size,
+                        Font::Default,
+                        text::Format::default(),
+                        Size::INFINITY,
+                    );
+
+                    parts.push(width);
+                }
+
+                if self.on_close.is_some() {
+                    parts.push(icon_size);
+                }
+
+                let width = parts.iter().sum::<f32>()
+                    + spacing * (parts.len().max(1) - 1) as f32
+                    + 2.0 * padding;
+
+                let mut node = layout::Node::new(Size::new(width, bar_height));
+                node.bounds.x = x;
+                x += width;
+
+                node
+            })
+            .collect();
+
+        let content_limits = limits.shrink(Size::new(0.0, bar_height));
+        let mut content = self.content.layout(renderer, &content_limits);
+        content.bounds.y = bar_height;
+
+        let content_size = content.size();
+
+        let size = limits.resolve(Size::new(
+            content_size.width.max(x),
+            content_size.height + bar_height,
+        ));
+
+        let bar = layout::Node::with_children(
+            Size::new(size.width, bar_height),
+            tabs,
+        );
+
+        layout::Node::with_children(size, vec![bar, content])
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        let messages_before = messages.len();
+
+        self.content.widget.on_event(
+            event,
+            layout.children().nth(1).unwrap(),
+            cursor_position,
+            messages,
+            renderer,
+            clipboard,
+        );
+
+        match event {
+            Event::Mouse(mouse::Event::Input {
+                button: mouse::Button::Left,
+                state: ButtonState::Pressed,
+            }) => {
+                self.state.is_focused =
+                    layout.bounds().contains(cursor_position);
+
+                let headers = self.headers(layout, cursor_position);
+
+                if let Some(index) =
+                    headers.iter().position(|header| header.is_hovered)
+                {
+                    match &self.on_close {
+                        Some(on_close) if headers[index].is_close_hovered => {
+                            messages.push(on_close(index));
+                        }
+                        _ => {
+                            if index != self.active {
+                                messages.push((self.on_select)(index));
+                            }
+                        }
+                    }
+                }
+            }
+            Event::Keyboard(keyboard::Event::Input {
+                key_code: keyboard::KeyCode::Tab,
+                state: ButtonState::Pressed,
+                modifiers,
+            }) if modifiers.control
+                && self.state.is_focused
+                && self.tabs.len() > 1
+                // Nested tabs, which are focused too, switch first
+                && messages.len() == messages_before =>
+            {
+                let index = if modifiers.shift {
+                    (self.active + self.tabs.len() - 1) % self.tabs.len()
+                } else {
+                    (self.active + 1) % self.tabs.len()
+                };
+
+                messages.push((self.on_select)(index));
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let bar = layout.children().next().unwrap();
+        let content_layout = layout.children().nth(1).unwrap();
+
+        let content =
+            self.content.draw(renderer, content_layout, cursor_position);
+
+        self::Renderer::draw(
+            renderer,
+            bar.bounds(),
+            &self.headers(layout, cursor_position),
+            self.text_size
+                .unwrap_or(text::Renderer::default_size(renderer)),
+            content,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        std::any::TypeId::of::<Tabs<'static, (), ()>>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+        self.text_size.hash(state);
+        self.padding.hash(state);
+        self.on_close.is_some().hash(state);
+
+        for tab in &self.tabs {
+            tab.text.hash(state);
+            tab.icon.is_some().hash(state);
+        }
+
+        self.content.hash_layout(state);
+    }
+
+    fn is_focused(&self) -> bool {
+        self.content.widget.is_focused()
+    }
+}
+
+/// The local state of some [`Tabs`].
+///
+/// [`Tabs`]: struct.Tabs.html
+#[derive(Debug, Clone, Copy, Default)]
+pub struct State {
+    is_focused: bool,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> Self {
+        State::default()
+    }
+
+    /// Returns whether the [`Tabs`] are focused or not.
+    ///
+    /// Focused [`Tabs`] can be switched with `Ctrl+Tab` and
+    /// `Ctrl+Shift+Tab`.
+    ///
+    /// [`Tabs`]: struct.Tabs.html
+    pub fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+}
+
+/// A tab of some [`Tabs`], with a text and/or an icon.
+///
+/// [`Tabs`]: struct.Tabs.html
+#[derive(Debug, Clone, Default)]
+pub struct Tab {
+    text: Option<String>,
+    icon: Option<(char, Font)>,
+}
+
+impl Tab {
+    /// Creates a new empty [`Tab`].
+    ///
+    /// [`Tab`]: struct.Tab.html
+    pub fn new() -> Self {
+        Tab::default()
+    }
+
+    /// Sets the text of the [`Tab`].
+    ///
+    /// [`Tab`]: struct.Tab.html
+    pub fn text(mut self, text: impl Into<String>) -> Self {
+        self.text = Some(text.into());
+        self
+    }
+
+    /// Sets the icon of the [`Tab`], a character of the given [`Font`].
+    ///
+    /// The icon is displayed before the text.
+    ///
+    /// [`Tab`]: struct.Tab.html
+    /// [`Font`]: ../../enum.Font.html
+    pub fn icon(mut self, icon: char, font: Font) -> Self {
+        self.icon = Some((icon, font));
+        self
+    }
+}
+
+/// A [`Tab`], ready to be drawn.
+///
+/// [`Tab`]: struct.Tab.html
+#[derive(Debug, Clone, Copy)]
+pub struct Header<'a> {
+    /// The text of the [`Tab`], if any.
+    ///
+    /// [`Tab`]: struct.Tab.html
+    pub text: Option<&'a str>,
+
+    /// The icon of the [`Tab`] and its [`Font`], if any.
+    ///
+    /// [`Tab`]: struct.Tab.html
+    /// [`Font`]: ../../enum.Font.html
+    pub icon: Option<(char, Font)>,
+
+    /// The bounds of the [`Tab`].
+    ///
+    /// [`Tab`]: struct.Tab.html
+    pub bounds: Rectangle,
+
+    /// The bounds of the close button of the [`Tab`], if any.
+    ///
+    /// [`Tab`]: struct.Tab.html
+    pub close_bounds: Option<Rectangle>,
+
+    /// Whether the [`Tab`] is the active one or not.
+    ///
+    /// [`Tab`]: struct.Tab.html
+    pub is_active: bool,
+
+    /// Whether the mouse is over the [`Tab`] or not.
+    ///
+    /// [`Tab`]: struct.Tab.html
+    pub is_hovered: bool,
+
+    /// Whether the mouse is over the close button or not.
+    pub is_close_hovered: bool,
+}
+
+/// The renderer of some [`Tabs`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use [`Tabs`] in your user interface.
+///
+/// [`Tabs`]: struct.Tabs.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: crate::Renderer + Sized {
+    /// Draws some [`Tabs`].
+    ///
+    /// It receives:
+    /// - the bounds of the bar of tabs
+    /// - the [`Header`] of every tab
+    /// - the text size of the tabs
+    /// - the drawn contents of the active tab
+    ///
+    /// [`Tabs`]: struct.Tabs.html
+    /// [`Header`]: struct.Header.html
+    fn draw(
+        &mut self,
+        bar_bounds: Rectangle,
+        headers: &[Header<'_>],
+        text_size: u16,
+        content: Self::Output,
+    ) -> Self::Output;
+}
+
+impl<'a, Message, Renderer> From<Tabs<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer + text::Renderer,
+    Message: 'static,
+{
+    fn from(
+        tabs: Tabs<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(tabs)
+    }
+}
@@ -1,7 +1,7 @@
 //! Write some text for your users to read.
 use crate::{
     layout, Color, Element, Font, Hasher, HorizontalAlignment, Layout, Length,
-    Point, Rectangle, Size, VerticalAlignment, Widget,
+    Overflow, Point, Rectangle, Size, VerticalAlignment, Weight, Widget, Wrap,
 };
 
 use std::hash::Hash;
@@ -25,6 +25,7 @@ pub struct Text {
     size: Option<u16>,
     color: Option<Color>,
     font: Font,
+    format: Format,
     width: Length,
     height: Length,
     horizontal_alignment: HorizontalAlignment,
@@ -41,6 +42,7 @@ impl Text {
             size: None,
             color: None,
             font: Font::Default,
+            format: Format::default(),
             width: Length::Fill,
             height: Length::Shrink,
             horizontal_alignment: HorizontalAlignment::Left,
@@ -74,6 +76,47 @@ impl Text {
         self
     }
 
+    /// Sets the [`Wrap`] strategy of the [`Text`].
+    ///
+    /// By default, lines are broken between words.
+    ///
+    /// [`Text`]: struct.Text.html
+    /// [`Wrap`]: ../../enum.Wrap.html
+    pub fn wrap(mut self, wrap: Wrap) -> Self {
+        self.format.wrap = wrap;
+        self
+    }
+
+    /// Sets the [`Overflow`] strategy of the [`Text`].
+    ///
+    /// By default, text that does not fit is drawn outside of the bounds.
+    ///
+    /// [`Text`]: struct.Text.html
+    /// [`Overflow`]: ../../enum.Overflow.html
+    pub fn overflow(mut self, overflow: Overflow) -> Self {
+        self.format.overflow = overflow;
+        self
+    }
+
+    /// Sets the line height of the [`Text`], relative to its size.
+    ///
+    /// By default, the line height is given by the metrics of the font.
+    ///
+    /// [`Text`]: struct.Text.html
+    pub fn line_height(mut self, line_height: f32) -> Self {
+        self.format.line_height = Some(line_height);
+        self
+    }
+
+    /// Sets the extra space between the characters of the [`Text`], in
+    /// pixels.
+    ///
+    /// [`Text`]: struct.Text.html
+    pub fn letter_spacing(mut self, letter_spacing: f32) -> Self {
+        self.format.letter_spacing = letter_spacing;
+        self
+    }
+
     /// Sets the width of the [`Text`] boundaries.
     ///
     /// [`Text`]: struct.Text.html
@@ -135,12 +178,26 @@ where
 
         let bounds = limits.max();
 
-        let (width, height) =
-            renderer.measure(&self.content, size, self.font, bounds);
+        let (width, height) = renderer.measure(
+            &self.content,
+            size,
+            self.font,
+            self.format,
+            bounds,
+        );
+
+        let ascent = renderer.ascent(size, self.font, self.format);
 
         let size = limits.resolve(Size::new(width, height));
 
-        layout::Node::new(size)
+        // The text is aligned inside of its bounds when drawn
+        let offset = match self.vertical_alignment {
+            VerticalAlignment::Top => 0.0,
+            VerticalAlignment::Center => (size.height - height) / 2.0,
+            VerticalAlignment::Bottom => size.height - height,
+        };
+
+        layout::Node::new(size).with_baseline(offset + ascent)
     }
 
     fn draw(
@@ -154,6 +211,7 @@ where
             &self.content,
             self.size.unwrap_or(renderer.default_size()),
             self.font,
+            self.format,
             self.color,
             self.horizontal_alignment,
             self.vertical_alignment,
@@ -163,6 +221,7 @@ where
     fn hash_layout(&self, state: &mut Hasher) {
         self.content.hash(state);
         self.size.hash(state);
+        self.format.hash(state);
         self.width.hash(state);
         self.height.hash(state);
     }
@@ -185,26 +244,67 @@ pub trait Renderer: crate::Renderer {
     /// Measures the [`Text`] in the given bounds and returns the minimum
     /// boundaries that can fit the contents.
     ///
+    /// The contents must be wrapped and truncated following the given
+    /// [`Format`], exactly like they are when drawn.
+    ///
     /// [`Text`]: struct.Text.html
+    /// [`Format`]: struct.Format.html
     fn measure(
         &self,
         content: &str,
         size: u16,
         font: Font,
+        format: Format,
+        bounds: Size,
+    ) -> (f32, f32);
+
+    /// Returns the distance from the top of the [`Text`] to the baseline of
+    /// its first line.
+    ///
+    /// It must match the result of [`measure`] with the same size, font and
+    /// [`Format`], including any leading added by its line height.
+    ///
+    /// [`Text`]: struct.Text.html
+    /// [`measure`]: #tymethod.measure
+    /// [`Format`]: struct.Format.html
+    fn ascent(&self, size: u16, font: Font, format: Format) -> f32;
+
+    /// Measures a paragraph made of multiple [`Fragment`]s in the given bounds
+    /// and returns the minimum boundaries that can fit the contents.
+    ///
+    /// The fragments must be laid out as a single paragraph, wrapping words
+    /// across fragment boundaries.
+    ///
+    /// [`Fragment`]: struct.Fragment.html
+    fn measure_fragments(
+        &self,
+        fragments: &[Fragment<'_>],
         bounds: Size,
     ) -> (f32, f32);
 
+    /// Returns the index of the character boundary of a [`Paragraph`] that
+    /// is closest to the given point.
+    ///
+    /// Widgets use it to place a caret or start a selection wherever the
+    /// user clicks.
+    ///
+    /// [`Paragraph`]: struct.Paragraph.html
+    fn hit_test(&self, paragraph: &Paragraph<'_>, point: Point) -> usize;
+
     /// Draws a [`Text`] fragment.
     ///
     /// It receives:
     ///   * the bounds of the [`Text`]
     ///   * the contents of the [`Text`]
     ///   * the size of the [`Text`]
+    ///   * the font of the [`Text`]
+    ///   * the [`Format`] of the [`Text`]
     ///   * the color of the [`Text`]
     ///   * the [`HorizontalAlignment`] of the [`Text`]
     ///   * the [`VerticalAlignment`] of the [`Text`]
     ///
     /// [`Text`]: struct.Text.html
+    /// [`Format`]: struct.Format.html
     /// [`HorizontalAlignment`]: enum.HorizontalAlignment.html
     /// [`VerticalAlignment`]: enum.VerticalAlignment.html
     fn draw(
@@ -213,12 +313,106 @@ pub trait Renderer: crate::Renderer {
         content: &str,
         size: u16,
         font: Font,
+        format: Format,
         color: Option<Color>,
         horizontal_alignment: HorizontalAlignment,
         vertical_alignment: VerticalAlignment,
     ) -> Self::Output;
 }
 
+/// The way the lines of a [`Text`] are broken, spaced and truncated.
+///
+/// [`Text`]: struct.Text.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Format {
+    /// The [`Wrap`] strategy of the text
+    ///
+    /// [`Wrap`]: ../../enum.Wrap.html
+    pub wrap: Wrap,
+
+    /// The [`Overflow`] strategy of the text
+    ///
+    /// [`Overflow`]: ../../enum.Overflow.html
+    pub overflow: Overflow,
+
+    /// The line height of the text, relative to its size, if any
+    pub line_height: Option<f32>,
+
+    /// The extra space between characters, in pixels
+    pub letter_spacing: f32,
+}
+
+impl Default for Format {
+    fn default() -> Format {
+        Format {
+            wrap: Wrap::Word,
+            overflow: Overflow::Visible,
+            line_height: None,
+            letter_spacing: 0.0,
+        }
+    }
+}
+
+impl Hash for Format {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.wrap.hash(state);
+        self.overflow.hash(state);
+        self.line_height.map(f32::to_bits).hash(state);
+        self.letter_spacing.to_bits().hash(state);
+    }
+}
+
+/// A run of text sharing the same size, font and weight.
+///
+/// Multiple fragments can be measured together as a single paragraph using
+/// [`Renderer::measure_fragments`].
+///
+/// [`Renderer::measure_fragments`]: trait.Renderer.html#tymethod.measure_fragments
+#[derive(Debug, Clone, Copy)]
+pub struct Fragment<'a> {
+    /// The contents of the fragment
+    pub content: &'a str,
+
+    /// The size of the fragment
+    pub size: u16,
+
+    /// The font of the fragment
+    pub font: Font,
+
+    /// The weight of the fragment
+    pub weight: Weight,
+}
+
+/// Some text laid out in specific bounds, exactly like it is drawn by a
+/// [`Renderer`].
+///
+/// [`Renderer`]: trait.Renderer.html
+#[derive(Debug, Clone, Copy)]
+pub struct Paragraph<'a> {
+    /// The contents of the paragraph
+    pub content: &'a str,
+
+    /// The bounds of the paragraph
+    pub bounds: Rectangle,
+
+    /// The size of the paragraph
+    pub size: u16,
+
+    /// The font of the paragraph
+    pub font: Font,
+
+    /// The [`Format`] of the paragraph
+    ///
+    /// [`Format`]: struct.Format.html
+    pub format: Format,
+
+    /// The horizontal alignment of the paragraph
+    pub horizontal_alignment: HorizontalAlignment,
+
+    /// The vertical alignment of the paragraph
+    pub vertical_alignment: VerticalAlignment,
+}
+
 impl<'a, Message, Renderer> From<Text> for Element<'a, Message, Renderer>
 where
     Renderer: self::Renderer,
@@ -6,8 +6,8 @@
 //! [`State`]: struct.State.html
 use crate::{
     input::{keyboard, mouse, ButtonState},
-    layout, Element, Event, Hasher, Layout, Length, Point, Rectangle, Size,
-    Widget,
+    layout, text, Clipboard, Element, Event, Font, Hasher, HorizontalAlignment,
+    Layout, Length, Point, Rectangle, Size, VerticalAlignment, Widget, Wrap,
 };
 
 /// A field that can be filled with text.
@@ -140,7 +140,8 @@ where
         limits: &layout::Limits,
     ) -> layout::Node {
         let padding = self.padding as f32;
-        let text_size = self.size.unwrap_or(renderer.default_size());
+        let text_size =
+            self.size.unwrap_or(self::Renderer::default_size(renderer));
 
         let limits = limits
             .pad(padding)
@@ -160,19 +161,58 @@ where
         layout: Layout<'_>,
         cursor_position: Point,
         messages: &mut Vec<Message>,
-        _renderer: &Renderer,
+        renderer: &Renderer,
+        _clipboard: Option<&dyn Clipboard>,
     ) {
         match event {
             Event::Mouse(mouse::Event::Input {
                 button: mouse::Button::Left,
                 state: ButtonState::Pressed,
             }) => {
-                self.state.is_focused =
-                    layout.bounds().contains(cursor_position);
-
-                if self.state.cursor_position(&self.value) == 0 {
-                    self.state.move_cursor_to_end(&self.value);
+                let is_clicked = layout.bounds().contains(cursor_position);
+
+                if is_clicked {
+                    let text_bounds =
+                        layout.children().next().unwrap().bounds();
+                    let size = self
+                        .size
+                        .unwrap_or(self::Renderer::default_size(renderer));
+
+                    // The text may be scrolled to keep the cursor visible
+                    let offset = if self.state.is_focused {
+                        renderer.offset(
+                            text_bounds,
+                            size,
+                            &self.value,
+                            self.state,
+                        )
+                    } else {
+                        0.0
+                    };
+
+                    let content = self.value.to_string();
+
+                    self.state.cursor_position = renderer.hit_test(
+                        &text::Paragraph {
+                            content: &content,
+                            bounds: text_bounds,
+                            size,
+                            font: Font::Default,
+                            format: text::Format {
+                                wrap: Wrap::None,
+                                ..text::Format::default()
+                            },
+                            horizontal_alignment: HorizontalAlignment::Left,
+                            vertical_alignment: VerticalAlignment::Center,
+                        },
+                        Point::new(
+                            cursor_position.x + offset,
+                            cursor_position.y,
+                        ),
+                    );
                 }
+
+                self.state.is_focused = is_clicked;
             }
             Event::Keyboard(keyboard::Event::CharacterReceived(c))
                 if self.state.is_focused && !c.is_control() =>
@@ -188,6 +228,7 @@ where
             Event::Keyboard(keyboard::Event::Input {
                 key_code,
                 state: ButtonState::Pressed,
+                ..
             }) if self.state.is_focused => match key_code {
                 keyboard::KeyCode::Enter => {
                     if let Some(on_submit) = self.on_submit.clone() {
@@ -239,11 +280,14 @@ where
         let bounds = layout.bounds();
         let text_bounds = layout.children().next().unwrap().bounds();
 
-        renderer.draw(
+        let size = self.size.unwrap_or(self::Renderer::default_size(renderer));
+
+        self::Renderer::draw(
+            renderer,
             bounds,
             text_bounds,
             cursor_position,
-            self.size.unwrap_or(renderer.default_size()),
+            size,
             &self.placeholder,
             &self.value,
             &self.state,
@@ -260,6 +304,10 @@ where
         self.padding.hash(state);
         self.size.hash(state);
     }
+
+    fn is_focused(&self) -> bool {
+        self.state.is_focused
+    }
 }
 
 /// The renderer of a [`TextInput`].
@@ -269,12 +317,27 @@ where
 ///
 /// [`TextInput`]: struct.TextInput.html
 /// [renderer]: ../../renderer/index.html
-pub trait Renderer: crate::Renderer + Sized {
+pub trait Renderer: text::Renderer + Sized {
     /// Returns the default size of the text of the [`TextInput`].
     ///
     /// [`TextInput`]: struct.TextInput.html
     fn default_size(&self) -> u16;
 
+    /// Returns the horizontal offset of the text of a [`TextInput`].
+    ///
+    /// The text is scrolled when it does not fit, so the cursor is always
+    /// visible. The [`TextInput`] needs this offset to place the cursor
+    /// where the user clicks.
+    ///
+    /// [`TextInput`]: struct.TextInput.html
+    fn offset(
+        &self,
+        text_bounds: Rectangle,
+        size: u16,
+        value: &Value,
+        state: &State,
+    ) -> f32;
+
     /// Draws a [`TextInput`].
     ///
     /// It receives:
@@ -377,13 +440,6 @@ impl State {
             self.cursor_position = current - 1;
         }
     }
-
-    /// Moves the cursor of a [`TextInput`] to the end.
-    ///
-    /// [`TextInput`]: struct.TextInput.html
-    pub(crate) fn move_cursor_to_end(&mut self, value: &Value) {
-        self.cursor_position = value.len();
-    }
 }
 
 /// The value of a [`TextInput`].
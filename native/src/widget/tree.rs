@@ -0,0 +1,548 @@
+//! Display hierarchical data that can be expanded and collapsed.
+use crate::{
+    column,
+    input::{keyboard, mouse, ButtonState},
+    layout, Clipboard, Element, Event, Hasher, Layout, Length, Point,
+    Rectangle, Size, Widget,
+};
+
+use std::{f32, hash::Hash};
+
+/// A widget displaying a hierarchy of [`Node`]s, with disclosure arrows to
+/// expand and collapse them.
+///
+/// The application owns the expansion and the selection of the [`Tree`]. It
+/// receives messages when the user wants to change them.
+///
+/// [`Node`]: struct.Node.html
+/// [`Tree`]: struct.Tree.html
+#[allow(missing_debug_implementations)]
+pub struct Tree<'a, Key, Message, Renderer> {
+    state: &'a mut State,
+    entries: Vec<Entry<Key>>,
+    labels: Vec<Element<'a, Message, Renderer>>,
+    selected: Option<Key>,
+    width: Length,
+    indent: u16,
+    on_expand: Option<Box<dyn Fn(Key) -> Message>>,
+    on_collapse: Option<Box<dyn Fn(Key) -> Message>>,
+    on_select: Option<Box<dyn Fn(Key) -> Message>>,
+}
+
+impl<'a, Key, Message, Renderer> Tree<'a, Key, Message, Renderer>
+where
+    Key: Clone + PartialEq,
+{
+    /// Creates a new [`Tree`] with the given [`State`] and root [`Node`]s.
+    ///
+    /// [`Tree`]: struct.Tree.html
+    /// [`State`]: struct.State.html
+    /// [`Node`]: struct.Node.html
+    pub fn new(
+        state: &'a mut State,
+        roots: Vec<Node<'a, Key, Message, Renderer>>,
+    ) -> Self {
+        let mut entries = Vec::new();
+        let mut labels = Vec::new();
+
+        for root in roots {
+            root.flatten(0, None, &mut entries, &mut labels);
+        }
+
+        Tree {
+            state,
+            entries,
+            labels,
+            selected: None,
+            width: Length::Fill,
+            indent: 20,
+            on_expand: None,
+            on_collapse: None,
+            on_select: None,
+        }
+    }
+
+    /// Sets the width of the [`Tree`].
+    ///
+    /// [`Tree`]: struct.Tree.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the indentation of every level of the [`Tree`], in pixels.
+    ///
+    /// The disclosure arrows take this space too.
+    ///
+    /// [`Tree`]: struct.Tree.html
+    pub fn indent(mut self, indent: u16) -> Self {
+        self.indent = indent;
+        self
+    }
+
+    /// Sets the key of the selected [`Node`] of the [`Tree`].
+    ///
+    /// [`Node`]: struct.Node.html
+    /// [`Tree`]: struct.Tree.html
+    pub fn selected(mut self, selected: Option<Key>) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    /// Sets the message that should be produced when a collapsed [`Node`] is
+    /// expanded.
+    ///
+    /// [`Node`]: struct.Node.html
+    pub fn on_expand<F>(mut self, f: F) -> Self
+    where
+        F: 'static + Fn(Key) -> Message,
+    {
+        self.on_expand = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the message that should be produced when an expanded [`Node`] is
+    /// collapsed.
+    ///
+    /// [`Node`]: struct.Node.html
+    pub fn on_collapse<F>(mut self, f: F) -> Self
+    where
+        F: 'static + Fn(Key) -> Message,
+    {
+        self.on_collapse = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the message that should be produced when a [`Node`] is selected.
+    ///
+    /// [`Node`]: struct.Node.html
+    pub fn on_select<F>(mut self, f: F) -> Self
+    where
+        F: 'static + Fn(Key) -> Message,
+    {
+        self.on_select = Some(Box::new(f));
+        self
+    }
+
+    fn selected_index(&self) -> Option<usize> {
+        let selected = self.selected.as_ref()?;
+
+        self.entries.iter().position(|entry| &entry.key == selected)
+    }
+
+    fn rows(&self, layout: Layout<'_>, cursor_position: Point) -> Vec<Row> {
+        let bounds = layout.bounds();
+        let selected = self.selected_index();
+
+        self.entries
+            .iter()
+            .zip(layout.children())
+            .enumerate()
+            .map(|(i, (entry, label))| {
+                let label_bounds = label.bounds();
+
+                let row_bounds = Rectangle {
+                    x: bounds.x,
+                    y: label_bounds.y,
+                    width: bounds.width,
+                    height: label_bounds.height,
+                };
+
+                Row {
+                    bounds: row_bounds,
+                    toggle_bounds: Rectangle {
+                        x: label_bounds.x - f32::from(self.indent),
+                        width: f32::from(self.indent),
+                        ..row_bounds
+                    },
+                    depth: entry.depth,
+                    is_expandable: entry.is_expandable,
+                    is_expanded: entry.is_expanded,
+                    is_selected: selected == Some(i),
+                    is_hovered: row_bounds.contains(cursor_position),
+                }
+            })
+            .collect()
+    }
+
+    fn toggle(&self, index: usize, messages: &mut Vec<Message>) {
+        let entry = &self.entries[index];
+
+        if !entry.is_expandable {
+            return;
+        }
+
+        let on_toggle = if entry.is_expanded {
+            &self.on_collapse
+        } else {
+            &self.on_expand
+        };
+
+        if let Some(on_toggle) = on_toggle {
+            messages.push(on_toggle(entry.key.clone()));
+        }
+    }
+
+    fn select(&self, index: usize, messages: &mut Vec<Message>) {
+        if let Some(on_select) = &self.on_select {
+            messages.push(on_select(self.entries[index].key.clone()));
+        }
+    }
+}
+
+impl<'a, Key, Message, Renderer> Widget<Message, Renderer>
+    for Tree<'a, Key, Message, Renderer>
+where
+    Key: Clone + PartialEq,
+    Renderer: self::Renderer + column::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(Length::Shrink);
+        let max_width = limits.max().width;
+        let indent = f32::from(self.indent);
+
+        let mut y = 0.0;
+        let mut width: f32 = 0.0;
+
+        let children = self
+            .entries
+            .iter()
+            .zip(self.labels.iter())
+            .map(|(entry, label)| {
+                let x = indent * (entry.depth + 1) as f32;
+
+                let label_limits = layout::Limits::new(
+                    Size::new(0.0, indent),
+                    Size::new((max_width - x).max(0.0), f32::INFINITY),
+                );
+
+                let mut node = label.layout(renderer, &label_limits);
+
+                node.bounds.x = x;
+                node.bounds.y = y;
+                y += node.bounds.height;
+                width = width.max(x + node.bounds.width);
+
+                node
+            })
+            .collect();
+
+        let size = limits.resolve(Size::new(width, y));
+
+        layout::Node::with_children(size, children)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        match event {
+            Event::Mouse(mouse::Event::Input {
+                button: mouse::Button::Left,
+                state: ButtonState::Pressed,
+            }) => {
+                self.state.is_focused =
+                    layout.bounds().contains(cursor_position);
+
+                let rows = self.rows(layout, cursor_position);
+
+                if let Some(index) = rows.iter().position(|row| row.is_hovered)
+                {
+                    if rows[index].toggle_bounds.contains(cursor_position) {
+                        self.toggle(index, messages);
+                    } else {
+                        self.select(index, messages);
+                    }
+                }
+            }
+            Event::Keyboard(keyboard::Event::Input {
+                key_code,
+                state: ButtonState::Pressed,
+                ..
+            }) if self.state.is_focused && !self.entries.is_empty() => {
+                let selected = self.selected_index();
+
+                match (key_code, selected) {
+                    (keyboard::KeyCode::Up, Some(index)) => {
+                        self.select(index.saturating_sub(1), messages);
+                    }
+                    (keyboard::KeyCode::Down, Some(index)) => {
+                        self.select(
+                            (index + 1).min(self.entries.len() - 1),
+                            messages,
+                        );
+                    }
+                    (keyboard::KeyCode::Up, None)
+                    | (keyboard::KeyCode::Down, None) => {
+                        self.select(0, messages);
+                    }
+                    (keyboard::KeyCode::Left, Some(index)) => {
+                        let entry = &self.entries[index];
+
+                        if entry.is_expandable && entry.is_expanded {
+                            self.toggle(index, messages);
+                        } else if let Some(parent) = entry.parent {
+                            self.select(parent, messages);
+                        }
+                    }
+                    (keyboard::KeyCode::Right, Some(index)) => {
+                        let entry = &self.entries[index];
+
+                        if entry.is_expandable && !entry.is_expanded {
+                            self.toggle(index, messages);
+                        } else if entry.is_expandable
+                            && self
+                                .entries
+                                .get(index + 1)
+                                .map(|child| child.parent == Some(index))
+                                == Some(true)
+                        {
+                            self.select(index + 1, messages);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+
+        self.labels.iter_mut().zip(layout.children()).for_each(
+            |(label, layout)| {
+                label.widget.on_event(
+                    event,
+                    layout,
+                    cursor_position,
+                    messages,
+                    renderer,
+                    clipboard,
+                )
+            },
+        );
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let labels = column::Renderer::draw(
+            renderer,
+            &self.labels,
+            layout,
+            cursor_position,
+        );
+
+        self::Renderer::draw(
+            renderer,
+            &self.rows(layout, cursor_position),
+            labels,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        std::any::TypeId::of::<Tree<'static, (), (), ()>>().hash(state);
+
+        self.width.hash(state);
+        self.indent.hash(state);
+
+        for (entry, label) in self.entries.iter().zip(self.labels.iter()) {
+            entry.depth.hash(state);
+            label.hash_layout(state);
+        }
+    }
+
+    fn is_focused(&self) -> bool {
+        self.state.is_focused
+            || self.labels.iter().any(|label| label.widget.is_focused())
+    }
+}
+
+/// A node of a [`Tree`], with a label and some children.
+///
+/// [`Tree`]: struct.Tree.html
+#[allow(missing_debug_implementations)]
+pub struct Node<'a, Key, Message, Renderer> {
+    key: Key,
+    label: Element<'a, Message, Renderer>,
+    is_expanded: bool,
+    children: Vec<Node<'a, Key, Message, Renderer>>,
+}
+
+impl<'a, Key, Message, Renderer> Node<'a, Key, Message, Renderer> {
+    /// Creates a new collapsed [`Node`] with the given key and label.
+    ///
+    /// The key identifies the [`Node`] in the messages produced by the
+    /// [`Tree`].
+    ///
+    /// [`Node`]: struct.Node.html
+    /// [`Tree`]: struct.Tree.html
+    pub fn new<E>(key: Key, label: E) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        Node {
+            key,
+            label: label.into(),
+            is_expanded: false,
+            children: Vec::new(),
+        }
+    }
+
+    /// Sets whether the [`Node`] is expanded or not.
+    ///
+    /// The children of a collapsed [`Node`] are not displayed.
+    ///
+    /// [`Node`]: struct.Node.html
+    pub fn expanded(mut self, is_expanded: bool) -> Self {
+        self.is_expanded = is_expanded;
+        self
+    }
+
+    /// Adds a child to the [`Node`].
+    ///
+    /// [`Node`]: struct.Node.html
+    pub fn push(mut self, child: Node<'a, Key, Message, Renderer>) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    fn flatten(
+        self,
+        depth: usize,
+        parent: Option<usize>,
+        entries: &mut Vec<Entry<Key>>,
+        labels: &mut Vec<Element<'a, Message, Renderer>>,
+    ) {
+        let index = entries.len();
+
+        entries.push(Entry {
+            key: self.key,
+            depth,
+            parent,
+            is_expandable: !self.children.is_empty(),
+            is_expanded: self.is_expanded,
+        });
+
+        labels.push(self.label);
+
+        if self.is_expanded {
+            for child in self.children {
+                child.flatten(depth + 1, Some(index), entries, labels);
+            }
+        }
+    }
+}
+
+/// The local state of a [`Tree`].
+///
+/// [`Tree`]: struct.Tree.html
+#[derive(Debug, Clone, Copy, Default)]
+pub struct State {
+    is_focused: bool,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> Self {
+        State::default()
+    }
+
+    /// Returns whether the [`Tree`] is focused or not.
+    ///
+    /// A focused [`Tree`] can be navigated with the arrow keys.
+    ///
+    /// [`Tree`]: struct.Tree.html
+    pub fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+}
+
+/// A visible row of a [`Tree`], ready to be drawn.
+///
+/// [`Tree`]: struct.Tree.html
+#[derive(Debug, Clone, Copy)]
+pub struct Row {
+    /// The bounds of the row.
+    pub bounds: Rectangle,
+
+    /// The bounds of the disclosure arrow of the row.
+    pub toggle_bounds: Rectangle,
+
+    /// The depth of the row in the [`Tree`].
+    ///
+    /// [`Tree`]: struct.Tree.html
+    pub depth: usize,
+
+    /// Whether the row has children or not.
+    pub is_expandable: bool,
+
+    /// Whether the row is expanded or not.
+    pub is_expanded: bool,
+
+    /// Whether the row is selected or not.
+    pub is_selected: bool,
+
+    /// Whether the mouse is over the row or not.
+    pub is_hovered: bool,
+}
+
+struct Entry<Key> {
+    key: Key,
+    depth: usize,
+    parent: Option<usize>,
+    is_expandable: bool,
+    is_expanded: bool,
+}
+
+/// The renderer of a [`Tree`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`Tree`] in your user interface.
+///
+/// [`Tree`]: struct.Tree.html
+/// [renderer]: ../../renderer/index.html
+pub trait Renderer: crate::Renderer + Sized {
+    /// Draws a [`Tree`].
+    ///
+    /// It receives:
+    /// - the visible [`Row`]s of the [`Tree`]
+    /// - the drawn labels of the rows
+    ///
+    /// [`Tree`]: struct.Tree.html
+    /// [`Row`]: struct.Row.html
+    fn draw(&mut self, rows: &[Row], labels: Self::Output) -> Self::Output;
+}
+
+impl<'a, Key, Message, Renderer> From<Tree<'a, Key, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Key: 'a + Clone + PartialEq,
+    Renderer: 'a + self::Renderer + column::Renderer,
+    Message: 'static,
+{
+    fn from(
+        tree: Tree<'a, Key, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(tree)
+    }
+}
@@ -0,0 +1,439 @@
+//! Display a huge amount of rows by only building the visible ones.
+use crate::{
+    column, layout, scrollable, Clipboard, Element, Event, Hasher, Layout,
+    Length, Point, Rectangle, Size, Widget,
+};
+
+use std::{f32, hash::Hash, time::Duration};
+
+/// A scrollable list that only builds, lays out and draws the rows inside
+/// its viewport.
+///
+/// Rows are produced on demand by a closure given their index. Therefore,
+/// the cost of a frame does not depend on the total amount of rows.
+///
+/// Rows are rebuilt every frame. If you need rows with interactive widgets,
+/// keep their state in your application and produce messages instead.
+#[allow(missing_debug_implementations)]
+pub struct VirtualList<'a, Message, Renderer> {
+    state: &'a mut State,
+    count: usize,
+    row_height: RowHeight,
+    width: Length,
+    height: Length,
+    max_height: u32,
+    line_height: u16,
+    animation_duration: Duration,
+    view: Box<dyn Fn(usize) -> Element<'a, Message, Renderer> + 'a>,
+}
+
+impl<'a, Message, Renderer> VirtualList<'a, Message, Renderer> {
+    /// Creates a new [`VirtualList`] with the given [`State`], amount of
+    /// rows, row height and a closure producing the row at a given index.
+    ///
+    /// Every row is laid out with the given height. If the rows of your
+    /// list have different heights, use [`estimated_row_height`] instead.
+    ///
+    /// [`VirtualList`]: struct.VirtualList.html
+    /// [`State`]: struct.State.html
+    /// [`estimated_row_height`]: #method.estimated_row_height
+    pub fn new<F>(
+        state: &'a mut State,
+        count: usize,
+        row_height: u16,
+        view: F,
+    ) -> Self
+    where
+        F: 'a + Fn(usize) -> Element<'a, Message, Renderer>,
+    {
+        VirtualList {
+            state,
+            count,
+            row_height: RowHeight::Fixed(row_height),
+            width: Length::Fill,
+            height: Length::Fill,
+            max_height: u32::MAX,
+            line_height: 60,
+            animation_duration: Duration::from_millis(150),
+            view: Box::new(view),
+        }
+    }
+
+    /// Lets the rows of the [`VirtualList`] choose their own height, using
+    /// the given estimation to compute the size of the scrollbar.
+    ///
+    /// [`VirtualList`]: struct.VirtualList.html
+    pub fn estimated_row_height(mut self, row_height: u16) -> Self {
+        self.row_height = RowHeight::Estimated(row_height);
+        self
+    }
+
+    /// Sets the width of the [`VirtualList`].
+    ///
+    /// [`VirtualList`]: struct.VirtualList.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`VirtualList`].
+    ///
+    /// [`VirtualList`]: struct.VirtualList.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the maximum height of the [`VirtualList`] in pixels.
+    ///
+    /// [`VirtualList`]: struct.VirtualList.html
+    pub fn max_height(mut self, max_height: u32) -> Self {
+        self.max_height = max_height;
+        self
+    }
+
+    /// Sets the amount of pixels the [`VirtualList`] scrolls per line of a
+    /// mouse wheel or per press of an arrow key.
+    ///
+    /// [`VirtualList`]: struct.VirtualList.html
+    pub fn line_height(mut self, line_height: u16) -> Self {
+        self.line_height = line_height;
+        self
+    }
+
+    /// Sets the duration of the smooth scrolling animation of the
+    /// [`VirtualList`].
+    ///
+    /// [`VirtualList`]: struct.VirtualList.html
+    pub fn animation_duration(mut self, animation_duration: Duration) -> Self {
+        self.animation_duration = animation_duration;
+        self
+    }
+
+    fn visible_rows(
+        &self,
+        renderer: &Renderer,
+        bounds: Rectangle,
+        offset: f32,
+    ) -> (Vec<Element<'a, Message, Renderer>>, layout::Node)
+    where
+        Renderer: crate::Renderer,
+    {
+        let content_size = Size::new(
+            bounds.width,
+            self.count as f32 * self.row_height.estimation(),
+        );
+
+        let hidden_content = (content_size.height - bounds.height).max(0.0);
+
+        let layout_row = |index| {
+            let row = (self.view)(index);
+            let node = row.layout(renderer, &self.row_height.limits(bounds));
+
+            (row, node)
+        };
+
+        let mut rows = Vec::new();
+        let mut nodes = Vec::new();
+
+        match self.row_height {
+            RowHeight::Estimated(_)
+                if hidden_content > 0.0 && offset >= hidden_content =>
+            {
+                // When we reach the end, we lay out the rows bottom-up, so
+                // the last ones are always reachable regardless of the
+                // estimation.
+                let mut y = content_size.height;
+                let mut index = self.count;
+
+                while index > 0 && y > offset {
+                    index -= 1;
+
+                    let (row, mut node) = layout_row(index);
+
+                    y -= node.bounds.height;
+                    node.bounds.y = y;
+
+                    rows.push(row);
+                    nodes.push(node);
+                }
+
+                rows.reverse();
+                nodes.reverse();
+            }
+            _ => {
+                let estimation = self.row_height.estimation();
+                let mut index = (offset / estimation).floor() as usize;
+                let mut y = index as f32 * estimation;
+
+                while index < self.count && y < offset + bounds.height {
+                    let (row, mut node) = layout_row(index);
+
+                    node.bounds.y = y;
+                    y += node.bounds.height;
+
+                    rows.push(row);
+                    nodes.push(node);
+
+                    index += 1;
+                }
+            }
+        }
+
+        let mut content = layout::Node::with_children(content_size, nodes);
+        content.bounds.x = bounds.x;
+        content.bounds.y = bounds.y;
+
+        (rows, content)
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for VirtualList<'a, Message, Renderer>
+where
+    Renderer: scrollable::Renderer + column::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits
+            .max_height(self.max_height)
+            .width(self.width)
+            .height(self.height);
+
+        let content_height = self.count as f32 * self.row_height.estimation();
+        let size = limits.resolve(Size::new(0.0, content_height));
+
+        layout::Node::with_children(
+            size,
+            vec![layout::Node::new(Size::new(size.width, content_height))],
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+        renderer: &Renderer,
+        clipboard: Option<&dyn Clipboard>,
+    ) {
+        let bounds = layout.bounds();
+        let is_mouse_over = bounds.contains(cursor_position);
+        let content_bounds = layout.children().next().unwrap().bounds();
+
+        let is_mouse_over_scrollbar = renderer.is_mouse_over_scrollbar(
+            bounds,
+            content_bounds,
+            cursor_position,
+        );
+
+        self.state.scrollable.update(
+            event,
+            scrollable::Area {
+                bounds,
+                content_bounds,
+                line_height: f32::from(self.line_height),
+                animation_duration: self.animation_duration,
+            },
+            cursor_position,
+            is_mouse_over_scrollbar,
+        );
+
+        let offset = self.state.scrollable.offset(bounds, content_bounds);
+
+        let cursor_position = if is_mouse_over
+            && !(is_mouse_over_scrollbar
+                || self.state.scrollable.is_scrollbar_grabbed())
+        {
+            Point::new(cursor_position.x, cursor_position.y + offset as f32)
+        } else {
+            Point::new(cursor_position.x, -1.0)
+        };
+
+        let (mut rows, content) =
+            self.visible_rows(renderer, bounds, offset as f32);
+
+        for (row, layout) in
+            rows.iter_mut().zip(Layout::new(&content).children())
+        {
+            row.widget.on_event(
+                event,
+                layout,
+                cursor_position,
+                messages,
+                renderer,
+                clipboard,
+            );
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Renderer::Output {
+        let bounds = layout.bounds();
+        let content_bounds = layout.children().next().unwrap().bounds();
+        let offset = self.state.scrollable.offset(bounds, content_bounds);
+
+        let now = std::time::Instant::now();
+
+        if self.state.scrollable.is_animating(now) {
+            renderer.request_redraw(now + scrollable::FRAME_INTERVAL);
+        }
+
+        let is_mouse_over = bounds.contains(cursor_position);
+        let is_mouse_over_scrollbar = renderer.is_mouse_over_scrollbar(
+            bounds,
+            content_bounds,
+            cursor_position,
+        );
+
+        let (rows, content) =
+            self.visible_rows(renderer, bounds, offset as f32);
+
+        let content = {
+            let cursor_position = if is_mouse_over && !is_mouse_over_scrollbar {
+                Point::new(cursor_position.x, cursor_position.y + offset as f32)
+            } else {
+                Point::new(cursor_position.x, -1.0)
+            };
+
+            column::Renderer::draw(
+                renderer,
+                &rows,
+                Layout::new(&content),
+                cursor_position,
+            )
+        };
+
+        scrollable::Renderer::draw(
+            renderer,
+            &self.state.scrollable,
+            bounds,
+            content_bounds,
+            is_mouse_over,
+            is_mouse_over_scrollbar,
+            offset,
+            content,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        std::any::TypeId::of::<VirtualList<'static, (), ()>>().hash(state);
+
+        self.count.hash(state);
+        self.row_height.hash(state);
+        self.width.hash(state);
+        self.height.hash(state);
+        self.max_height.hash(state);
+    }
+
+    fn is_focused(&self) -> bool {
+        self.state.scrollable.is_focused()
+    }
+}
+
+/// The local state of a [`VirtualList`].
+///
+/// [`VirtualList`]: struct.VirtualList.html
+#[derive(Debug, Clone, Copy, Default)]
+pub struct State {
+    scrollable: scrollable::State,
+}
+
+impl State {
+    /// Creates a new [`State`] with the scrollbar located at the top.
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> Self {
+        State::default()
+    }
+
+    /// Creates a new [`State`] that keeps the scrollbar pinned to the bottom
+    /// while rows are added to the [`VirtualList`].
+    ///
+    /// [`State`]: struct.State.html
+    /// [`VirtualList`]: struct.VirtualList.html
+    pub fn pinned_to_bottom() -> Self {
+        State {
+            scrollable: scrollable::State::pinned_to_bottom(),
+        }
+    }
+
+    /// Returns a reference to the [`scrollable::State`] of the
+    /// [`VirtualList`].
+    ///
+    /// [`scrollable::State`]: ../scrollable/struct.State.html
+    /// [`VirtualList`]: struct.VirtualList.html
+    pub fn scrollable(&self) -> &scrollable::State {
+        &self.scrollable
+    }
+
+    /// Returns a mutable reference to the [`scrollable::State`] of the
+    /// [`VirtualList`].
+    ///
+    /// You can use it to scroll the [`VirtualList`] programmatically.
+    ///
+    /// [`scrollable::State`]: ../scrollable/struct.State.html
+    /// [`VirtualList`]: struct.VirtualList.html
+    pub fn scrollable_mut(&mut self) -> &mut scrollable::State {
+        &mut self.scrollable
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum RowHeight {
+    Fixed(u16),
+    Estimated(u16),
+}
+
+impl RowHeight {
+    fn estimation(self) -> f32 {
+        match self {
+            RowHeight::Fixed(height) | RowHeight::Estimated(height) => {
+                f32::from(height.max(1))
+            }
+        }
+    }
+
+    fn limits(self, bounds: Rectangle) -> layout::Limits {
+        match self {
+            RowHeight::Fixed(height) => {
+                let size = Size::new(bounds.width, f32::from(height));
+
+                layout::Limits::new(size, size)
+            }
+            RowHeight::Estimated(_) => layout::Limits::new(
+                Size::new(bounds.width, 0.0),
+                Size::new(bounds.width, f32::INFINITY),
+            ),
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<VirtualList<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + scrollable::Renderer + column::Renderer,
+    Message: 'static,
+{
+    fn from(
+        virtual_list: VirtualList<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(virtual_list)
+    }
+}
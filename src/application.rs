@@ -137,7 +137,10 @@ pub trait Application: Sized {
         Self: 'static,
     {
         #[cfg(not(target_arch = "wasm32"))]
-        <Instance<Self> as iced_winit::Application>::run(settings.into());
+        <Instance<Self> as iced_winit::Application>::run(
+            settings.into(),
+            settings.into(),
+        );
 
         #[cfg(target_arch = "wasm32")]
         <Instance<Self> as iced_web::Application>::run();
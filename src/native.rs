@@ -1,6 +1,7 @@
 pub use iced_winit::{
-    Align, Background, Color, Command, Font, HorizontalAlignment, Length,
-    VerticalAlignment,
+    font, Align, Background, Color, Command, ContentFit, Font,
+    HorizontalAlignment, Justify, Length, Overflow, VerticalAlignment, Weight,
+    Wrap,
 };
 
 pub mod widget {
@@ -40,6 +41,59 @@ pub mod widget {
         pub use iced_winit::button::State;
     }
 
+    pub mod grid {
+        //! Distribute content in aligned rows and columns.
+
+        /// A container that distributes its contents in the cells of a grid.
+        ///
+        /// This is an alias of an `iced_native` grid with a default
+        /// `Renderer`.
+        pub type Grid<'a, Message> =
+            iced_winit::Grid<'a, Message, iced_wgpu::Renderer>;
+
+        /// An element placed in some cells of a [`Grid`].
+        ///
+        /// This is an alias of an `iced_native` grid cell with a default
+        /// `Renderer`.
+        ///
+        /// [`Grid`]: type.Grid.html
+        pub type Cell<'a, Message> =
+            iced_winit::grid::Cell<'a, Message, iced_wgpu::Renderer>;
+    }
+
+    pub mod image {
+        //! Display images in your user interface.
+        pub use iced_winit::image::{Data, Handle, Image, Playback};
+    }
+
+    pub mod pane_grid {
+        //! Let your users split regions of your application and organize
+        //! layout dynamically.
+        //!
+        //! A [`PaneGrid`] has some local [`State`].
+        //!
+        //! [`PaneGrid`]: type.PaneGrid.html
+        //! [`State`]: struct.State.html
+
+        /// A collection of panes distributed using either vertical or
+        /// horizontal splits to completely fill the space available.
+        ///
+        /// This is an alias of an `iced_native` pane grid with a default
+        /// `Renderer`.
+        pub type PaneGrid<'a, Message> =
+            iced_winit::PaneGrid<'a, Message, iced_wgpu::Renderer>;
+
+        pub use iced_winit::pane_grid::{
+            Axis, Configuration, DragEvent, Node, Pane, ResizeEvent, Split,
+            State,
+        };
+    }
+
+    pub mod rich_text {
+        //! Write paragraphs of text mixing different styles and links.
+        pub use iced_winit::rich_text::{RichText, Span};
+    }
+
     pub mod scrollable {
         //! Navigate an endless amount of content with a scrollbar.
 
@@ -51,7 +105,17 @@ pub mod widget {
         pub type Scrollable<'a, Message> =
             iced_winit::Scrollable<'a, Message, iced_wgpu::Renderer>;
 
-        pub use iced_winit::scrollable::State;
+        pub use iced_winit::scrollable::{Position, State};
+    }
+
+    pub mod selectable_text {
+        //! Display text that your users can select and copy.
+        //!
+        //! A [`SelectableText`] has some local [`State`].
+        //!
+        //! [`SelectableText`]: struct.SelectableText.html
+        //! [`State`]: struct.State.html
+        pub use iced_winit::selectable_text::{SelectableText, State};
     }
 
     pub mod text_input {
@@ -75,12 +139,91 @@ pub mod widget {
         pub use iced_winit::slider::{Slider, State};
     }
 
-    pub use iced_winit::{Checkbox, Image, Radio, Text};
+    pub mod table {
+        //! Display tabular data with sortable and resizable columns.
+        //!
+        //! A [`Table`] has some local [`State`].
+        //!
+        //! [`Table`]: type.Table.html
+        //! [`State`]: struct.State.html
+
+        /// A widget displaying rows of data under a header of titled columns.
+        ///
+        /// This is an alias of an `iced_native` table with a default
+        /// `Renderer`.
+        pub type Table<'a, Message> =
+            iced_winit::Table<'a, Message, iced_wgpu::Renderer>;
+
+        pub use iced_winit::table::{Column, Sort, State};
+    }
+
+    pub mod tabs {
+        //! Switch between different views with a bar of tabs.
+        //!
+        //! [`Tabs`] have some local [`State`].
+        //!
+        //! [`Tabs`]: type.Tabs.html
+        //! [`State`]: struct.State.html
+
+        /// A bar of tabs on top of the contents of the active one.
+        ///
+        /// This is an alias of an `iced_native` tabs widget with a default
+        /// `Renderer`.
+        pub type Tabs<'a, Message> =
+            iced_winit::Tabs<'a, Message, iced_wgpu::Renderer>;
+
+        pub use iced_winit::tabs::{State, Tab};
+    }
+
+    pub mod tree {
+        //! Display hierarchical data that can be expanded and collapsed.
+        //!
+        //! A [`Tree`] has some local [`State`].
+        //!
+        //! [`Tree`]: type.Tree.html
+        //! [`State`]: struct.State.html
+
+        /// A widget displaying a hierarchy of nodes.
+        ///
+        /// This is an alias of an `iced_native` tree with a default
+        /// `Renderer`.
+        pub type Tree<'a, Key, Message> =
+            iced_winit::Tree<'a, Key, Message, iced_wgpu::Renderer>;
+
+        /// A node of a [`Tree`].
+        ///
+        /// This is an alias of an `iced_native` tree node with a default
+        /// `Renderer`.
+        ///
+        /// [`Tree`]: type.Tree.html
+        pub type Node<'a, Key, Message> =
+            iced_winit::tree::Node<'a, Key, Message, iced_wgpu::Renderer>;
+
+        pub use iced_winit::tree::State;
+    }
+
+    pub mod virtual_list {
+        //! Display a huge amount of rows by only building the visible ones.
+
+        /// A scrollable list that only builds, lays out and draws the rows
+        /// inside its viewport.
+        ///
+        /// This is an alias of an `iced_native` virtual list with a default
+        /// `Renderer`.
+        pub type VirtualList<'a, Message> =
+            iced_winit::VirtualList<'a, Message, iced_wgpu::Renderer>;
+
+        pub use iced_winit::virtual_list::State;
+    }
+
+    pub use iced_winit::{Checkbox, Image, Radio, Rule, Space, Svg, Text};
 
     #[doc(no_inline)]
     pub use {
-        button::Button, scrollable::Scrollable, slider::Slider,
-        text_input::TextInput,
+        button::Button, grid::Grid, pane_grid::PaneGrid, rich_text::RichText,
+        scrollable::Scrollable, selectable_text::SelectableText,
+        slider::Slider, table::Table, tabs::Tabs, text_input::TextInput,
+        tree::Tree, virtual_list::VirtualList,
     };
 
     /// A container that distributes its contents vertically.
@@ -101,6 +244,18 @@ pub mod widget {
     /// `Renderer`.
     pub type Container<'a, Message> =
         iced_winit::Container<'a, Message, iced_wgpu::Renderer>;
+
+    /// A dialog displayed on top of some base content.
+    ///
+    /// This is an alias of an `iced_native` modal with a default `Renderer`.
+    pub type Modal<'a, Message> =
+        iced_winit::Modal<'a, Message, iced_wgpu::Renderer>;
+
+    /// A container that displays its contents on top of each other.
+    ///
+    /// This is an alias of an `iced_native` stack with a default `Renderer`.
+    pub type Stack<'a, Message> =
+        iced_winit::Stack<'a, Message, iced_wgpu::Renderer>;
 }
 
 #[doc(no_inline)]
@@ -9,6 +9,15 @@ pub struct Settings {
     ///
     /// [`Window`]: struct.Window.html
     pub window: Window,
+
+    /// The maximum amount of memory, in bytes, that can be used to keep
+    /// images in the GPU.
+    ///
+    /// When the budget is exceeded, the least recently drawn images are
+    /// evicted. If `None`, the default budget of the renderer is used.
+    ///
+    /// It will be ignored on the Web.
+    pub image_cache_budget: Option<usize>,
 }
 
 /// The window settings of an application.
@@ -41,3 +50,16 @@ impl From<Settings> for iced_winit::Settings {
         }
     }
 }
+
+#[cfg(not(target_arch = "wasm32"))]
+impl From<Settings> for iced_wgpu::Settings {
+    fn from(settings: Settings) -> iced_wgpu::Settings {
+        let default = iced_wgpu::Settings::default();
+
+        iced_wgpu::Settings {
+            image_cache_budget: settings
+                .image_cache_budget
+                .unwrap_or(default.image_cache_budget),
+        }
+    }
+}
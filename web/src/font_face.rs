@@ -0,0 +1,59 @@
+use crate::{base64, font};
+
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+};
+
+thread_local! {
+    static INJECTED: RefCell<HashSet<String>> = RefCell::new(HashSet::new());
+    static LOADED: RefCell<HashMap<font::Id, String>> =
+        RefCell::new(HashMap::new());
+}
+
+/// Injects an `@font-face` rule for a font loaded at runtime, remembering
+/// its name for the given [`font::Id`].
+///
+/// [`font::Id`]: ../font/struct.Id.html
+pub fn load_as(id: font::Id, name: &str, bytes: &[u8]) {
+    load(name, bytes);
+
+    LOADED.with(|loaded| {
+        let _ = loaded.borrow_mut().insert(id, name.to_owned());
+    });
+}
+
+/// Returns the name of the font loaded at runtime with the given
+/// [`font::Id`], if any.
+///
+/// [`font::Id`]: ../font/struct.Id.html
+pub fn loaded(id: font::Id) -> Option<String> {
+    LOADED.with(|loaded| loaded.borrow().get(&id).cloned())
+}
+
+/// Injects an `@font-face` rule in the document, making the given font bytes
+/// available under the given name.
+///
+/// Fonts are only injected once per name.
+pub fn load(name: &str, bytes: &[u8]) {
+    let is_new =
+        INJECTED.with(|injected| injected.borrow_mut().insert(name.to_owned()));
+
+    if !is_new {
+        return;
+    }
+
+    let window = web_sys::window().unwrap();
+    let document = window.document().unwrap();
+    let head = document.head().unwrap();
+
+    let style = document.create_element("style").unwrap();
+
+    style.set_text_content(Some(&format!(
+        "@font-face {{ font-family: \"{}\"; src: url(data:font/ttf;base64,{}); }}",
+        name,
+        base64::encode(bytes)
+    )));
+
+    let _ = head.append_child(&style).unwrap();
+}
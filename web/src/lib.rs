@@ -59,8 +59,10 @@
 use dodrio::bumpalo;
 use std::{cell::RefCell, rc::Rc};
 
+mod base64;
 mod bus;
 mod element;
+mod font_face;
 
 pub mod style;
 pub mod widget;
@@ -69,8 +71,9 @@ pub use bus::Bus;
 pub use dodrio;
 pub use element::Element;
 pub use iced_core::{
-    Align, Background, Color, Command, Font, HorizontalAlignment, Length,
-    VerticalAlignment,
+    command, font, Align, Background, Color, Command, ContentFit, Font,
+    HorizontalAlignment, Justify, Length, Overflow, VerticalAlignment, Weight,
+    Wrap,
 };
 pub use style::Style;
 pub use widget::*;
@@ -190,11 +193,30 @@ where
     fn spawn(&mut self, command: Command<Message>) {
         use futures::FutureExt;
 
-        for future in command.futures() {
-            let mut instance = self.clone();
-            let future = future.map(move |message| instance.update(message));
-
-            wasm_bindgen_futures::spawn_local(future);
+        for action in command.actions() {
+            match action {
+                command::Action::Future(future) => {
+                    let mut instance = self.clone();
+                    let future =
+                        future.map(move |message| instance.update(message));
+
+                    wasm_bindgen_futures::spawn_local(future);
+                }
+                command::Action::LoadFont {
+                    id,
+                    name,
+                    bytes,
+                    message,
+                } => {
+                    font_face::load_as(id, &name, &bytes);
+
+                    let mut instance = self.clone();
+                    let future = futures::future::ready(message)
+                        .map(move |message| instance.update(message));
+
+                    wasm_bindgen_futures::spawn_local(future);
+                }
+            }
         }
     }
 }
@@ -1,7 +1,11 @@
 //! Style your widgets.
-use crate::{bumpalo, Align, Color, Length};
+use crate::{
+    bumpalo,
+    font::{self, Family},
+    font_face, Align, Color, Font, Justify, Length, Weight,
+};
 
-use std::collections::BTreeMap;
+use std::{borrow::Cow, collections::BTreeMap};
 
 /// The style of a VDOM node.
 #[derive(Debug)]
@@ -139,7 +143,19 @@ pub fn length(length: Length) -> String {
     match length {
         Length::Shrink => String::from("auto"),
         Length::Units(px) => format!("{}px", px),
-        Length::Fill => String::from("100%"),
+        Length::Fill | Length::FillPortion(_) => String::from("100%"),
+    }
+}
+
+/// Returns the size of a grid track for the given [`Length`].
+///
+/// [`Length`]: ../enum.Length.html
+pub fn track(length: Length) -> String {
+    match length {
+        Length::Shrink => String::from("max-content"),
+        Length::Units(px) => format!("{}px", px),
+        Length::Fill => String::from("1fr"),
+        Length::FillPortion(factor) => format!("{}fr", factor),
     }
 }
 
@@ -158,5 +174,86 @@ pub fn align(align: Align) -> &'static str {
         Align::Start => "flex-start",
         Align::Center => "center",
         Align::End => "flex-end",
+        Align::Baseline => "baseline",
+    }
+}
+
+/// Returns the style value for the given [`Justify`].
+///
+/// [`Justify`]: ../enum.Justify.html
+pub fn justify(justify: Justify) -> &'static str {
+    match justify {
+        Justify::Start => "flex-start",
+        Justify::Center => "center",
+        Justify::End => "flex-end",
+        Justify::SpaceBetween => "space-between",
+        Justify::SpaceAround => "space-around",
+        Justify::SpaceEvenly => "space-evenly",
+    }
+}
+
+/// Returns the style declaration of the family, weight, style and stretch of
+/// the given [`Font`].
+///
+/// The first time an external [`Font`] is used, its bytes are made
+/// available to the document with an `@font-face` rule.
+///
+/// [`Font`]: ../enum.Font.html
+pub fn font(font: Font) -> String {
+    let (weight, style, stretch) = match font {
+        Font::Descriptor(descriptor) => {
+            (descriptor.weight, descriptor.style, descriptor.stretch)
+        }
+        _ => (Weight::Normal, font::Style::Normal, font::Stretch::Normal),
+    };
+
+    format!(
+        "font-family: {}; font-weight: {}; font-style: {}; font-stretch: {}",
+        font_family(font),
+        weight.value(),
+        match style {
+            font::Style::Normal => "normal",
+            font::Style::Italic => "italic",
+            font::Style::Oblique => "oblique",
+        },
+        match stretch {
+            font::Stretch::UltraCondensed => "ultra-condensed",
+            font::Stretch::ExtraCondensed => "extra-condensed",
+            font::Stretch::Condensed => "condensed",
+            font::Stretch::SemiCondensed => "semi-condensed",
+            font::Stretch::Normal => "normal",
+            font::Stretch::SemiExpanded => "semi-expanded",
+            font::Stretch::Expanded => "expanded",
+            font::Stretch::ExtraExpanded => "extra-expanded",
+            font::Stretch::UltraExpanded => "ultra-expanded",
+        },
+    )
+}
+
+/// Returns the style value of the family of the given [`Font`].
+///
+/// [`Font`]: ../enum.Font.html
+pub fn font_family(font: Font) -> Cow<'static, str> {
+    match font {
+        Font::Default => Cow::Borrowed("inherit"),
+        Font::External { name, bytes } => {
+            font_face::load(name, bytes);
+
+            Cow::Borrowed(name)
+        }
+        Font::Loaded(id) => match font_face::loaded(id) {
+            Some(name) => Cow::Owned(name),
+            None => Cow::Borrowed("inherit"),
+        },
+        Font::Descriptor(descriptor) => {
+            Cow::Borrowed(match descriptor.family {
+                Family::Name(name) => name,
+                Family::Serif => "serif",
+                Family::SansSerif => "sans-serif",
+                Family::Monospace => "monospace",
+                Family::Cursive => "cursive",
+                Family::Fantasy => "fantasy",
+            })
+        }
     }
 }
@@ -18,25 +18,44 @@ use crate::{style, Bus};
 use dodrio::bumpalo;
 
 pub mod button;
+pub mod grid;
+pub mod image;
+pub mod rich_text;
 pub mod scrollable;
+pub mod selectable_text;
 pub mod slider;
+pub mod tabs;
 pub mod text_input;
 
 mod checkbox;
 mod column;
 mod container;
-mod image;
+mod modal;
 mod radio;
 mod row;
+mod rule;
+mod space;
+mod stack;
+mod svg;
 mod text;
 
 #[doc(no_inline)]
 pub use button::Button;
 #[doc(no_inline)]
+pub use grid::Grid;
+#[doc(no_inline)]
+pub use image::Image;
+#[doc(no_inline)]
+pub use rich_text::RichText;
+#[doc(no_inline)]
 pub use scrollable::Scrollable;
 #[doc(no_inline)]
+pub use selectable_text::SelectableText;
+#[doc(no_inline)]
 pub use slider::Slider;
 #[doc(no_inline)]
+pub use tabs::Tabs;
+#[doc(no_inline)]
 pub use text::Text;
 #[doc(no_inline)]
 pub use text_input::TextInput;
@@ -44,9 +63,13 @@ pub use text_input::TextInput;
 pub use checkbox::Checkbox;
 pub use column::Column;
 pub use container::Container;
-pub use image::Image;
+pub use modal::Modal;
 pub use radio::Radio;
 pub use row::Row;
+pub use rule::Rule;
+pub use space::Space;
+pub use stack::Stack;
+pub use svg::Svg;
 
 /// A component that displays information and allows interaction.
 ///
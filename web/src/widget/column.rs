@@ -1,4 +1,4 @@
-use crate::{style, Align, Bus, Element, Length, Style, Widget};
+use crate::{style, Align, Bus, Element, Justify, Length, Style, Widget};
 
 use dodrio::bumpalo;
 use std::u32;
@@ -11,11 +11,14 @@ use std::u32;
 #[allow(missing_debug_implementations)]
 pub struct Column<'a, Message> {
     spacing: u16,
+    line_spacing: u16,
+    wrap: bool,
     padding: u16,
     width: Length,
     height: Length,
     max_width: u32,
     max_height: u32,
+    justify_content: Justify,
     align_items: Align,
     children: Vec<Element<'a, Message>>,
 }
@@ -27,11 +30,14 @@ impl<'a, Message> Column<'a, Message> {
     pub fn new() -> Self {
         Column {
             spacing: 0,
+            line_spacing: 0,
+            wrap: false,
             padding: 0,
             width: Length::Fill,
             height: Length::Shrink,
             max_width: u32::MAX,
             max_height: u32::MAX,
+            justify_content: Justify::Start,
             align_items: Align::Start,
             children: Vec::new(),
         }
@@ -47,6 +53,26 @@ impl<'a, Message> Column<'a, Message> {
         self
     }
 
+    /// Makes the contents of the [`Column`] wrap onto a new line when they do
+    /// not fit in the available height.
+    ///
+    /// Every line aligns its contents on its own.
+    ///
+    /// [`Column`]: struct.Column.html
+    pub fn wrap(mut self) -> Self {
+        self.wrap = true;
+        self
+    }
+
+    /// Sets the horizontal spacing _between_ the lines of a wrapping
+    /// [`Column`].
+    ///
+    /// [`Column`]: struct.Column.html
+    pub fn line_spacing(mut self, units: u16) -> Self {
+        self.line_spacing = units;
+        self
+    }
+
     /// Sets the padding of the [`Column`].
     ///
     /// [`Column`]: struct.Column.html
@@ -87,6 +113,14 @@ impl<'a, Message> Column<'a, Message> {
         self
     }
 
+    /// Sets the vertical distribution of the contents of the [`Column`].
+    ///
+    /// [`Column`]: struct.Column.html
+    pub fn justify_content(mut self, justify: Justify) -> Self {
+        self.justify_content = justify;
+        self
+    }
+
     /// Sets the horizontal alignment of the contents of the [`Column`] .
     ///
     /// [`Column`]: struct.Column.html
@@ -124,8 +158,12 @@ impl<'a, Message> Widget<Message> for Column<'a, Message> {
 
         let column_class = style_sheet.insert(bump, Style::Column);
 
-        let spacing_class =
-            style_sheet.insert(bump, Style::Spacing(self.spacing));
+        // Wrapping lines are spaced with gaps instead, as margins would leak
+        // into the edges of every line
+        let spacing_class = style_sheet.insert(
+            bump,
+            Style::Spacing(if self.wrap { 0 } else { self.spacing }),
+        );
 
         let padding_class =
             style_sheet.insert(bump, Style::Padding(self.padding));
@@ -133,6 +171,20 @@ impl<'a, Message> Widget<Message> for Column<'a, Message> {
         let width = style::length(self.width);
         let height = style::length(self.height);
 
+        let wrap = if self.wrap {
+            bumpalo::format!(
+                in bump,
+                "; flex-wrap: wrap; align-items: {}; align-content: flex-start; \
+                 column-gap: {}px; row-gap: {}px",
+                style::align(self.align_items),
+                self.line_spacing,
+                self.spacing
+            )
+            .into_bump_str()
+        } else {
+            ""
+        };
+
         // TODO: Complete styling
         div(bump)
             .attr(
@@ -142,10 +194,12 @@ impl<'a, Message> Widget<Message> for Column<'a, Message> {
             )
             .attr("style", bumpalo::format!(
                     in bump,
-                    "width: {}; height: {}; max-width: {}px",
+                    "width: {}; height: {}; max-width: {}px; justify-content: {}{}",
                     width,
                     height,
-                    self.max_width
+                    self.max_width,
+                    style::justify(self.justify_content),
+                    wrap
                 ).into_bump_str()
             )
             .children(children)
@@ -0,0 +1,275 @@
+//! Distribute content in aligned rows and columns.
+use crate::{style, Align, Bus, Element, Length, Widget};
+
+use dodrio::bumpalo;
+use std::u32;
+
+/// A container that distributes its contents in the cells of a grid.
+///
+/// The columns and the rows of a [`Grid`] are shared by all of its cells, so
+/// they stay aligned. Every track can have a fixed size, fill a portion of the
+/// remaining space or shrink to fit its contents.
+///
+/// [`Grid`]: struct.Grid.html
+#[allow(missing_debug_implementations)]
+pub struct Grid<'a, Message> {
+    columns: Vec<Length>,
+    rows: Vec<Length>,
+    column_spacing: u16,
+    row_spacing: u16,
+    padding: u16,
+    width: Length,
+    height: Length,
+    max_width: u32,
+    max_height: u32,
+    cells: Vec<Cell<'a, Message>>,
+}
+
+impl<'a, Message> Grid<'a, Message> {
+    /// Creates an empty [`Grid`] with the given columns.
+    ///
+    /// Rows shrink to fit their contents by default.
+    ///
+    /// [`Grid`]: struct.Grid.html
+    pub fn new(columns: Vec<Length>) -> Self {
+        Grid {
+            columns,
+            rows: Vec::new(),
+            column_spacing: 0,
+            row_spacing: 0,
+            padding: 0,
+            width: Length::Fill,
+            height: Length::Shrink,
+            max_width: u32::MAX,
+            max_height: u32::MAX,
+            cells: Vec::new(),
+        }
+    }
+
+    /// Sets the rows of the [`Grid`].
+    ///
+    /// Rows that are not described, but needed by some [`Cell`], shrink to
+    /// fit their contents.
+    ///
+    /// [`Grid`]: struct.Grid.html
+    /// [`Cell`]: struct.Cell.html
+    pub fn rows(mut self, rows: Vec<Length>) -> Self {
+        self.rows = rows;
+        self
+    }
+
+    /// Sets the horizontal spacing _between_ the columns of the [`Grid`].
+    ///
+    /// [`Grid`]: struct.Grid.html
+    pub fn column_spacing(mut self, units: u16) -> Self {
+        self.column_spacing = units;
+        self
+    }
+
+    /// Sets the vertical spacing _between_ the rows of the [`Grid`].
+    ///
+    /// [`Grid`]: struct.Grid.html
+    pub fn row_spacing(mut self, units: u16) -> Self {
+        self.row_spacing = units;
+        self
+    }
+
+    /// Sets the padding of the [`Grid`].
+    ///
+    /// [`Grid`]: struct.Grid.html
+    pub fn padding(mut self, units: u16) -> Self {
+        self.padding = units;
+        self
+    }
+
+    /// Sets the width of the [`Grid`].
+    ///
+    /// [`Grid`]: struct.Grid.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Grid`].
+    ///
+    /// [`Grid`]: struct.Grid.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the maximum width of the [`Grid`].
+    ///
+    /// [`Grid`]: struct.Grid.html
+    pub fn max_width(mut self, max_width: u32) -> Self {
+        self.max_width = max_width;
+        self
+    }
+
+    /// Sets the maximum height of the [`Grid`] in pixels.
+    ///
+    /// [`Grid`]: struct.Grid.html
+    pub fn max_height(mut self, max_height: u32) -> Self {
+        self.max_height = max_height;
+        self
+    }
+
+    /// Adds a [`Cell`] to the [`Grid`].
+    ///
+    /// [`Cell`]: struct.Cell.html
+    /// [`Grid`]: struct.Grid.html
+    pub fn push(mut self, cell: Cell<'a, Message>) -> Self {
+        self.cells.push(cell);
+        self
+    }
+}
+
+impl<'a, Message> Widget<Message> for Grid<'a, Message> {
+    fn node<'b>(
+        &self,
+        bump: &'b bumpalo::Bump,
+        publish: &Bus<Message>,
+        style_sheet: &mut style::Sheet<'b>,
+    ) -> dodrio::Node<'b> {
+        use dodrio::builder::*;
+
+        let tracks = |lengths: &[Length]| {
+            if lengths.is_empty() {
+                return String::from("none");
+            }
+
+            lengths
+                .iter()
+                .map(|length| style::track(*length))
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+
+        let children: Vec<_> = self
+            .cells
+            .iter()
+            .map(|cell| {
+                div(bump)
+                    .attr(
+                        "style",
+                        bumpalo::format!(
+                            in bump,
+                            "grid-row: {} / span {}; grid-column: {} / span {}; \
+                             display: flex; justify-content: {}; align-items: {}",
+                            cell.row + 1,
+                            cell.row_span.max(1),
+                            cell.column + 1,
+                            cell.column_span.max(1),
+                            style::align(cell.horizontal_alignment),
+                            style::align(cell.vertical_alignment)
+                        )
+                        .into_bump_str(),
+                    )
+                    .children(vec![cell
+                        .content
+                        .node(bump, publish, style_sheet)])
+                    .finish()
+            })
+            .collect();
+
+        div(bump)
+            .attr(
+                "style",
+                bumpalo::format!(
+                    in bump,
+                    "display: grid; grid-template-columns: {}; \
+                     grid-template-rows: {}; grid-auto-columns: max-content; \
+                     grid-auto-rows: max-content; column-gap: {}px; \
+                     row-gap: {}px; box-sizing: border-box; padding: {}px; \
+                     width: {}; height: {}; max-width: {}px; max-height: {}px",
+                    tracks(&self.columns),
+                    tracks(&self.rows),
+                    self.column_spacing,
+                    self.row_spacing,
+                    self.padding,
+                    style::length(self.width),
+                    style::length(self.height),
+                    self.max_width,
+                    self.max_height
+                )
+                .into_bump_str(),
+            )
+            .children(children)
+            .finish()
+    }
+}
+
+impl<'a, Message> From<Grid<'a, Message>> for Element<'a, Message>
+where
+    Message: 'static,
+{
+    fn from(grid: Grid<'a, Message>) -> Element<'a, Message> {
+        Element::new(grid)
+    }
+}
+
+/// An element placed in some cells of a [`Grid`].
+///
+/// [`Grid`]: struct.Grid.html
+#[allow(missing_debug_implementations)]
+pub struct Cell<'a, Message> {
+    row: usize,
+    column: usize,
+    row_span: usize,
+    column_span: usize,
+    horizontal_alignment: Align,
+    vertical_alignment: Align,
+    content: Element<'a, Message>,
+}
+
+impl<'a, Message> Cell<'a, Message> {
+    /// Creates a new [`Cell`] with some content in the given row and column.
+    ///
+    /// [`Cell`]: struct.Cell.html
+    pub fn new<T>(row: usize, column: usize, content: T) -> Self
+    where
+        T: Into<Element<'a, Message>>,
+    {
+        Cell {
+            row,
+            column,
+            row_span: 1,
+            column_span: 1,
+            horizontal_alignment: Align::Start,
+            vertical_alignment: Align::Start,
+            content: content.into(),
+        }
+    }
+
+    /// Sets the amount of columns spanned by the [`Cell`].
+    ///
+    /// [`Cell`]: struct.Cell.html
+    pub fn column_span(mut self, columns: usize) -> Self {
+        self.column_span = columns;
+        self
+    }
+
+    /// Sets the amount of rows spanned by the [`Cell`].
+    ///
+    /// [`Cell`]: struct.Cell.html
+    pub fn row_span(mut self, rows: usize) -> Self {
+        self.row_span = rows;
+        self
+    }
+
+    /// Sets the horizontal alignment of the contents of the [`Cell`].
+    ///
+    /// [`Cell`]: struct.Cell.html
+    pub fn align_x(mut self, align: Align) -> Self {
+        self.horizontal_alignment = align;
+        self
+    }
+
+    /// Sets the vertical alignment of the contents of the [`Cell`].
+    ///
+    /// [`Cell`]: struct.Cell.html
+    pub fn align_y(mut self, align: Align) -> Self {
+        self.vertical_alignment = align;
+        self
+    }
+}
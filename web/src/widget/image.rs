@@ -1,8 +1,19 @@
-use crate::{style, Bus, Element, Length, Widget};
+//! Display images in your user interface.
+use crate::{base64, style, Align, Bus, ContentFit, Element, Length, Widget};
 
 use dodrio::bumpalo;
+use std::{
+    cell::RefCell,
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+    path::PathBuf,
+    rc::Rc,
+};
 
-/// A frame that displays an image while keeping aspect ratio.
+/// A frame that displays an image.
+///
+/// By default, the image keeps its aspect ratio and it is centered inside
+/// its bounds.
 ///
 /// # Example
 ///
@@ -13,25 +24,41 @@ use dodrio::bumpalo;
 /// ```
 #[derive(Debug)]
 pub struct Image {
-    /// The image path
-    pub path: String,
+    /// The image handle
+    pub handle: Handle,
 
     /// The width of the image
     pub width: Length,
 
     /// The height of the image
     pub height: Length,
+
+    /// The strategy used to fit the image inside its bounds
+    pub content_fit: ContentFit,
+
+    /// The horizontal alignment of the image inside its bounds
+    pub horizontal_alignment: Align,
+
+    /// The vertical alignment of the image inside its bounds
+    pub vertical_alignment: Align,
 }
 
 impl Image {
-    /// Creates a new [`Image`] with the given path.
+    /// Creates a new [`Image`] with the given [`Handle`].
+    ///
+    /// A path can be used directly, as it can be converted into a
+    /// [`Handle`].
     ///
     /// [`Image`]: struct.Image.html
-    pub fn new<T: Into<String>>(path: T) -> Self {
+    /// [`Handle`]: struct.Handle.html
+    pub fn new<T: Into<Handle>>(handle: T) -> Self {
         Image {
-            path: path.into(),
+            handle: handle.into(),
             width: Length::Shrink,
             height: Length::Shrink,
+            content_fit: ContentFit::Contain,
+            horizontal_alignment: Align::Center,
+            vertical_alignment: Align::Center,
         }
     }
 
@@ -50,6 +77,33 @@ impl Image {
         self.height = height;
         self
     }
+
+    /// Sets the [`ContentFit`] of the [`Image`].
+    ///
+    /// [`ContentFit`]: ../enum.ContentFit.html
+    /// [`Image`]: struct.Image.html
+    pub fn content_fit(mut self, content_fit: ContentFit) -> Self {
+        self.content_fit = content_fit;
+        self
+    }
+
+    /// Sets the horizontal alignment of the picture inside the [`Image`]
+    /// bounds.
+    ///
+    /// [`Image`]: struct.Image.html
+    pub fn align_x(mut self, alignment: Align) -> Self {
+        self.horizontal_alignment = alignment;
+        self
+    }
+
+    /// Sets the vertical alignment of the picture inside the [`Image`]
+    /// bounds.
+    ///
+    /// [`Image`]: struct.Image.html
+    pub fn align_y(mut self, alignment: Align) -> Self {
+        self.vertical_alignment = alignment;
+        self
+    }
 }
 
 impl<Message> Widget<Message> for Image {
@@ -61,26 +115,39 @@ impl<Message> Widget<Message> for Image {
     ) -> dodrio::Node<'b> {
         use dodrio::builder::*;
 
-        let src = bumpalo::format!(in bump, "{}", self.path);
+        let src = bumpalo::format!(in bump, "{}", self.handle.src());
 
-        let mut image = img(bump).attr("src", src.into_bump_str());
-
-        match self.width {
-            Length::Shrink => {}
-            Length::Fill => {
-                image = image.attr("width", "100%");
-            }
-            Length::Units(px) => {
-                image = image.attr(
-                    "width",
-                    bumpalo::format!(in bump, "{}px", px).into_bump_str(),
-                );
-            }
-        }
+        let object_fit = match self.content_fit {
+            ContentFit::Contain => "contain",
+            ContentFit::Cover => "cover",
+            ContentFit::Fill => "fill",
+            ContentFit::ScaleDown => "scale-down",
+            ContentFit::None => "none",
+        };
 
-        // TODO: Complete styling
+        let position = |alignment| match alignment {
+            Align::Start | Align::Baseline => "0%",
+            Align::Center => "50%",
+            Align::End => "100%",
+        };
 
-        image.finish()
+        img(bump)
+            .attr("src", src.into_bump_str())
+            .attr(
+                "style",
+                bumpalo::format!(
+                    in bump,
+                    "width: {}; height: {}; object-fit: {}; \
+                     object-position: {} {}",
+                    style::length(self.width),
+                    style::length(self.height),
+                    object_fit,
+                    position(self.horizontal_alignment),
+                    position(self.vertical_alignment)
+                )
+                .into_bump_str(),
+            )
+            .finish()
     }
 }
 
@@ -89,3 +156,209 @@ impl<'a, Message> From<Image> for Element<'a, Message> {
         Element::new(image)
     }
 }
+
+/// An [`Image`] handle.
+///
+/// It can be cheaply cloned, and it has a stable identifier derived from its
+/// data.
+///
+/// In-memory images are turned into data URLs. The URL of a [`Handle`] is
+/// only computed once, so keep your handles around if you can!
+///
+/// [`Image`]: struct.Image.html
+/// [`Handle`]: struct.Handle.html
+#[derive(Debug, Clone)]
+pub struct Handle {
+    id: u64,
+    data: Rc<Data>,
+    src: Rc<RefCell<Option<String>>>,
+}
+
+impl Handle {
+    /// Creates an image [`Handle`] pointing to the image of the given path.
+    ///
+    /// [`Handle`]: struct.Handle.html
+    pub fn from_path<T: Into<PathBuf>>(path: T) -> Handle {
+        Self::from_data(Data::Path(path.into()))
+    }
+
+    /// Creates an image [`Handle`] containing the encoded bytes of an image,
+    /// in any of the formats supported by the browser (PNG, JPEG, etc).
+    ///
+    /// [`Handle`]: struct.Handle.html
+    pub fn from_memory(bytes: Vec<u8>) -> Handle {
+        Self::from_data(Data::Bytes(bytes))
+    }
+
+    /// Creates an image [`Handle`] containing the decoded pixels of an
+    /// image.
+    ///
+    /// `pixels` must be a buffer of `width * height` pixels in RGBA order,
+    /// one byte per channel.
+    ///
+    /// [`Handle`]: struct.Handle.html
+    pub fn from_pixels(width: u32, height: u32, pixels: Vec<u8>) -> Handle {
+        Self::from_data(Data::Pixels {
+            width,
+            height,
+            pixels,
+        })
+    }
+
+    fn from_data(data: Data) -> Handle {
+        let mut hasher = DefaultHasher::new();
+        data.hash(&mut hasher);
+
+        Handle {
+            id: hasher.finish(),
+            data: Rc::new(data),
+            src: Rc::new(RefCell::new(None)),
+        }
+    }
+
+    /// Returns the unique identifier of the [`Handle`].
+    ///
+    /// Two handles with the same data share the same identifier.
+    ///
+    /// [`Handle`]: struct.Handle.html
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// Returns a reference to the image [`Data`].
+    ///
+    /// [`Data`]: enum.Data.html
+    pub fn data(&self) -> &Data {
+        &self.data
+    }
+
+    fn src(&self) -> String {
+        if let Some(src) = self.src.borrow().as_ref() {
+            return src.clone();
+        }
+
+        let src = match self.data.as_ref() {
+            Data::Path(path) => path.to_string_lossy().into_owned(),
+            Data::Bytes(bytes) => {
+                format!(
+                    "data:{};base64,{}",
+                    mime_type(bytes),
+                    base64::encode(bytes)
+                )
+            }
+            Data::Pixels {
+                width,
+                height,
+                pixels,
+            } => {
+                pixels_to_data_url(*width, *height, pixels).unwrap_or_default()
+            }
+        };
+
+        *self.src.borrow_mut() = Some(src.clone());
+
+        src
+    }
+}
+
+impl From<String> for Handle {
+    fn from(path: String) -> Handle {
+        Handle::from_path(path)
+    }
+}
+
+impl From<&str> for Handle {
+    fn from(path: &str) -> Handle {
+        Handle::from_path(path)
+    }
+}
+
+/// The data of an [`Image`].
+///
+/// [`Image`]: struct.Image.html
+#[derive(Clone, Hash)]
+pub enum Data {
+    /// File data
+    Path(PathBuf),
+
+    /// In-memory data, encoded in some image format
+    Bytes(Vec<u8>),
+
+    /// Decoded pixels in RGBA order
+    Pixels {
+        /// The width of the image
+        width: u32,
+        /// The height of the image
+        height: u32,
+        /// The pixels
+        pixels: Vec<u8>,
+    },
+}
+
+impl std::fmt::Debug for Data {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Data::Path(path) => write!(f, "Path({:?})", path),
+            Data::Bytes(_) => write!(f, "Bytes(...)"),
+            Data::Pixels { width, height, .. } => {
+                write!(f, "Pixels({} * {})", width, height)
+            }
+        }
+    }
+}
+
+fn mime_type(bytes: &[u8]) -> &'static str {
+    if bytes.starts_with(b"\x89PNG") {
+        "image/png"
+    } else if bytes.starts_with(&[0xFF, 0xD8, 0xFF]) {
+        "image/jpeg"
+    } else if bytes.starts_with(b"GIF8") {
+        "image/gif"
+    } else if bytes.starts_with(b"RIFF") && bytes.get(8..12) == Some(b"WEBP") {
+        "image/webp"
+    } else if bytes.starts_with(b"BM") {
+        "image/bmp"
+    } else if bytes.starts_with(b"<svg") || bytes.starts_with(b"<?xml") {
+        "image/svg+xml"
+    } else {
+        "application/octet-stream"
+    }
+}
+
+fn pixels_to_data_url(
+    width: u32,
+    height: u32,
+    pixels: &[u8],
+) -> Option<String> {
+    use wasm_bindgen::{Clamped, JsCast};
+
+    let document = web_sys::window()?.document()?;
+
+    let canvas = document
+        .create_element("canvas")
+        .ok()?
+        .dyn_into::<web_sys::HtmlCanvasElement>()
+        .ok()?;
+
+    canvas.set_width(width);
+    canvas.set_height(height);
+
+    let context = canvas
+        .get_context("2d")
+        .ok()??
+        .dyn_into::<web_sys::CanvasRenderingContext2d>()
+        .ok()?;
+
+    let mut pixels = pixels.to_vec();
+
+    let data = web_sys::ImageData::new_with_u8_clamped_array_and_sh(
+        Clamped(&mut pixels),
+        width,
+        height,
+    )
+    .ok()?;
+
+    context.put_image_data(&data, 0.0, 0.0).ok()?;
+
+    canvas.to_data_url().ok()
+}
@@ -0,0 +1,168 @@
+use crate::{bumpalo, style, Bus, Element, Widget};
+
+/// A dialog displayed on top of some base content.
+///
+/// The base content is dimmed by a backdrop and does not receive any events
+/// while the [`Modal`] is displayed.
+///
+/// The backdrop takes the focus when the [`Modal`] is displayed, unless the
+/// dialog already has it, so `Escape` can dismiss the [`Modal`].
+///
+/// [`Modal`]: struct.Modal.html
+#[allow(missing_debug_implementations)]
+pub struct Modal<'a, Message> {
+    base: Element<'a, Message>,
+    dialog: Element<'a, Message>,
+    on_dismiss: Option<Message>,
+}
+
+impl<'a, Message> Modal<'a, Message> {
+    /// Creates a new [`Modal`] displaying the given dialog on top of the
+    /// base content.
+    ///
+    /// [`Modal`]: struct.Modal.html
+    pub fn new<B, D>(base: B, dialog: D) -> Self
+    where
+        B: Into<Element<'a, Message>>,
+        D: Into<Element<'a, Message>>,
+    {
+        Modal {
+            base: base.into(),
+            dialog: dialog.into(),
+            on_dismiss: None,
+        }
+    }
+
+    /// Sets the message that will be produced when the user dismisses the
+    /// [`Modal`], either by pressing `Escape` or by clicking the backdrop.
+    ///
+    /// [`Modal`]: struct.Modal.html
+    pub fn on_dismiss(mut self, message: Message) -> Self {
+        self.on_dismiss = Some(message);
+        self
+    }
+}
+
+impl<'a, Message> Widget<Message> for Modal<'a, Message>
+where
+    Message: 'static + Clone,
+{
+    fn node<'b>(
+        &self,
+        bump: &'b bumpalo::Bump,
+        bus: &Bus<Message>,
+        style_sheet: &mut style::Sheet<'b>,
+    ) -> dodrio::Node<'b> {
+        use dodrio::builder::*;
+        use std::sync::atomic::{AtomicU64, Ordering};
+        use wasm_bindgen::{closure::Closure, JsCast};
+
+        // Every backdrop is tagged with its own id, so nested or sibling
+        // modals focus their own backdrop
+        static NEXT_BACKDROP: AtomicU64 = AtomicU64::new(0);
+
+        let id = NEXT_BACKDROP.fetch_add(1, Ordering::Relaxed);
+
+        let dialog = div(bump)
+            .on("click", |_root, _vdom, event| {
+                // Clicks inside the dialog must not reach the backdrop
+                event.stop_propagation();
+            })
+            .children(vec![self.dialog.node(bump, bus, style_sheet)])
+            .finish();
+
+        let mut backdrop = div(bump)
+            .attr("tabindex", "-1")
+            .attr(
+                BACKDROP_ATTRIBUTE,
+                bumpalo::format!(in bump, "{}", id).into_bump_str(),
+            )
+            .attr(
+                "style",
+                "position: fixed; top: 0; left: 0; width: 100%; height: 100%; \
+                 display: flex; align-items: center; \
+                 justify-content: center; \
+                 background: rgba(0, 0, 0, 0.5); outline: none",
+            );
+
+        if let Some(on_dismiss) = self.on_dismiss.clone() {
+            let on_escape = on_dismiss.clone();
+            let event_bus = bus.clone();
+
+            backdrop = backdrop.on("click", move |root, vdom, _event| {
+                event_bus.publish(on_dismiss.clone(), root);
+                vdom.schedule_render();
+            });
+
+            let event_bus = bus.clone();
+
+            backdrop = backdrop.on("keydown", move |root, vdom, event| {
+                let is_escape = event
+                    .dyn_into::<web_sys::KeyboardEvent>()
+                    .map(|event| event.key() == "Escape")
+                    .unwrap_or(false);
+
+                if is_escape {
+                    event_bus.publish(on_escape.clone(), root);
+                    vdom.schedule_render();
+                }
+            });
+        }
+
+        // The backdrop only receives `Escape` while focused, and it can only
+        // be focused once it is in the document
+        if let Some(window) = web_sys::window() {
+            let focus = Closure::once_into_js(move || {
+                let _ = focus_backdrop(id);
+            });
+
+            let _ = window.request_animation_frame(focus.unchecked_ref());
+        }
+
+        div(bump)
+            .attr("style", "position: relative")
+            .children(vec![
+                div(bump)
+                    .attr("style", "pointer-events: none")
+                    .children(vec![self.base.node(bump, bus, style_sheet)])
+                    .finish(),
+                backdrop.children(vec![dialog]).finish(),
+            ])
+            .finish()
+    }
+}
+
+const BACKDROP_ATTRIBUTE: &str = "data-modal-backdrop";
+
+fn focus_backdrop(id: u64) -> Option<()> {
+    use wasm_bindgen::JsCast;
+
+    let document = web_sys::window()?.document()?;
+    let backdrop = document
+        .query_selector(&format!("[{}=\"{}\"]", BACKDROP_ATTRIBUTE, id))
+        .ok()??;
+
+    let has_focus = document
+        .active_element()
+        .map(|element| backdrop.contains(Some(element.as_ref())))
+        .unwrap_or(false);
+
+    if !has_focus {
+        backdrop
+            .dyn_into::<web_sys::HtmlElement>()
+            .ok()?
+            .focus()
+            .ok()?;
+    }
+
+    Some(())
+}
+
+impl<'a, Message> From<Modal<'a, Message>> for Element<'a, Message>
+where
+    Message: 'static + Clone,
+{
+    fn from(modal: Modal<'a, Message>) -> Element<'a, Message> {
+        Element::new(modal)
+    }
+}
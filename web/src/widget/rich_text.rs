@@ -0,0 +1,317 @@
+//! Write paragraphs of text mixing different styles and links.
+use crate::{
+    style, Background, Bus, Color, Element, Font, HorizontalAlignment, Length,
+    Weight, Widget,
+};
+use dodrio::bumpalo;
+
+/// A paragraph of text made of differently styled [`Span`]s.
+///
+/// # Example
+///
+/// ```
+/// # use iced_web::{rich_text::Span, RichText, Weight};
+///
+/// #[derive(Debug, Clone)]
+/// pub enum Message {
+///     DocumentationOpened,
+/// }
+///
+/// RichText::new()
+///     .push(Span::new("Rich text is "))
+///     .push(Span::new("bold").weight(Weight::Bold))
+///     .push(Span::new(" and can contain "))
+///     .push(
+///         Span::new("links")
+///             .color([0.0, 0.0, 1.0])
+///             .underline(true)
+///             .link(Message::DocumentationOpened),
+///     );
+/// ```
+///
+/// [`Span`]: struct.Span.html
+#[derive(Debug, Clone)]
+pub struct RichText<Message> {
+    spans: Vec<Span<Message>>,
+    size: Option<u16>,
+    color: Option<Color>,
+    font: Font,
+    width: Length,
+    height: Length,
+    horizontal_alignment: HorizontalAlignment,
+}
+
+impl<Message> RichText<Message> {
+    /// Creates an empty [`RichText`].
+    ///
+    /// [`RichText`]: struct.RichText.html
+    pub fn new() -> Self {
+        RichText {
+            spans: Vec::new(),
+            size: None,
+            color: None,
+            font: Font::Default,
+            width: Length::Fill,
+            height: Length::Shrink,
+            horizontal_alignment: HorizontalAlignment::Left,
+        }
+    }
+
+    /// Adds a [`Span`] at the end of the [`RichText`].
+    ///
+    /// [`Span`]: struct.Span.html
+    /// [`RichText`]: struct.RichText.html
+    pub fn push(mut self, span: Span<Message>) -> Self {
+        self.spans.push(span);
+        self
+    }
+
+    /// Sets the default size of the spans of the [`RichText`].
+    ///
+    /// [`RichText`]: struct.RichText.html
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the default [`Color`] of the spans of the [`RichText`].
+    ///
+    /// [`RichText`]: struct.RichText.html
+    /// [`Color`]: ../../struct.Color.html
+    pub fn color<C: Into<Color>>(mut self, color: C) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Sets the default [`Font`] of the spans of the [`RichText`].
+    ///
+    /// [`RichText`]: struct.RichText.html
+    /// [`Font`]: ../../struct.Font.html
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the width of the [`RichText`] boundaries.
+    ///
+    /// [`RichText`]: struct.RichText.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`RichText`] boundaries.
+    ///
+    /// [`RichText`]: struct.RichText.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the [`HorizontalAlignment`] of the [`RichText`].
+    ///
+    /// [`RichText`]: struct.RichText.html
+    /// [`HorizontalAlignment`]: ../../enum.HorizontalAlignment.html
+    pub fn horizontal_alignment(
+        mut self,
+        alignment: HorizontalAlignment,
+    ) -> Self {
+        self.horizontal_alignment = alignment;
+        self
+    }
+}
+
+impl<Message> Default for RichText<Message> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A piece of a [`RichText`] with its own style.
+///
+/// [`RichText`]: struct.RichText.html
+#[derive(Debug, Clone)]
+pub struct Span<Message> {
+    content: String,
+    size: Option<u16>,
+    color: Option<Color>,
+    font: Option<Font>,
+    weight: Weight,
+    underline: bool,
+    background: Option<Background>,
+    link: Option<Message>,
+}
+
+impl<Message> Span<Message> {
+    /// Creates a new [`Span`] with the given contents.
+    ///
+    /// [`Span`]: struct.Span.html
+    pub fn new<T: Into<String>>(content: T) -> Self {
+        Span {
+            content: content.into(),
+            size: None,
+            color: None,
+            font: None,
+            weight: Weight::Normal,
+            underline: false,
+            background: None,
+            link: None,
+        }
+    }
+
+    /// Sets the size of the [`Span`].
+    ///
+    /// [`Span`]: struct.Span.html
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the [`Color`] of the [`Span`].
+    ///
+    /// [`Span`]: struct.Span.html
+    /// [`Color`]: ../../struct.Color.html
+    pub fn color<C: Into<Color>>(mut self, color: C) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Sets the [`Font`] of the [`Span`].
+    ///
+    /// [`Span`]: struct.Span.html
+    /// [`Font`]: ../../struct.Font.html
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = Some(font);
+        self
+    }
+
+    /// Sets the [`Weight`] of the [`Span`].
+    ///
+    /// [`Span`]: struct.Span.html
+    /// [`Weight`]: ../../enum.Weight.html
+    pub fn weight(mut self, weight: Weight) -> Self {
+        self.weight = weight;
+        self
+    }
+
+    /// Sets whether the [`Span`] is underlined or not.
+    ///
+    /// [`Span`]: struct.Span.html
+    pub fn underline(mut self, underline: bool) -> Self {
+        self.underline = underline;
+        self
+    }
+
+    /// Sets the [`Background`] of the [`Span`].
+    ///
+    /// [`Span`]: struct.Span.html
+    /// [`Background`]: ../../enum.Background.html
+    pub fn background(mut self, background: Background) -> Self {
+        self.background = Some(background);
+        self
+    }
+
+    /// Turns the [`Span`] into a link that produces the given message when
+    /// clicked.
+    ///
+    /// [`Span`]: struct.Span.html
+    pub fn link(mut self, message: Message) -> Self {
+        self.link = Some(message);
+        self
+    }
+}
+
+impl<Message> Widget<Message> for RichText<Message>
+where
+    Message: 'static + Clone,
+{
+    fn node<'b>(
+        &self,
+        bump: &'b bumpalo::Bump,
+        bus: &Bus<Message>,
+        _style_sheet: &mut style::Sheet<'b>,
+    ) -> dodrio::Node<'b> {
+        use dodrio::builder::*;
+
+        let spans = self.spans.iter().map(|span| {
+            let content = bumpalo::format!(in bump, "{}", span.content);
+
+            let color =
+                style::color(span.color.or(self.color).unwrap_or(Color::BLACK));
+
+            let font = span.font.unwrap_or(self.font);
+
+            // The weight of a span overrides the one of its font
+            let weight = match (span.weight, font) {
+                (Weight::Normal, Font::Descriptor(descriptor)) => {
+                    descriptor.weight
+                }
+                (weight, _) => weight,
+            };
+
+            let background = match span.background {
+                Some(Background::Color(color)) => style::color(color),
+                None => String::from("transparent"),
+            };
+
+            let style = bumpalo::format!(
+                in bump,
+                "font-size: {}px; {}; font-weight: {}; color: {}; \
+                 background-color: {}; text-decoration: {}{}",
+                span.size.or(self.size).unwrap_or(20),
+                style::font(font),
+                weight.value(),
+                color,
+                background,
+                if span.underline { "underline" } else { "none" },
+                if span.link.is_some() { "; cursor: pointer" } else { "" },
+            );
+
+            let node = dodrio::builder::span(bump)
+                .attr("style", style.into_bump_str())
+                .children(vec![text(content.into_bump_str())]);
+
+            match span.link.clone() {
+                Some(on_click) => {
+                    let event_bus = bus.clone();
+
+                    node.on("click", move |root, vdom, _event| {
+                        event_bus.publish(on_click.clone(), root);
+
+                        vdom.schedule_render();
+                    })
+                    .finish()
+                }
+                None => node.finish(),
+            }
+        });
+
+        let text_align = match self.horizontal_alignment {
+            HorizontalAlignment::Left => "left",
+            HorizontalAlignment::Center => "center",
+            HorizontalAlignment::Right => "right",
+        };
+
+        let style = bumpalo::format!(
+            in bump,
+            "width: {}; height: {}; text-align: {}",
+            style::length(self.width),
+            style::length(self.height),
+            text_align
+        );
+
+        p(bump)
+            .attr("style", style.into_bump_str())
+            .children(spans.collect())
+            .finish()
+    }
+}
+
+impl<'a, Message> From<RichText<Message>> for Element<'a, Message>
+where
+    Message: 'static + Clone,
+{
+    fn from(rich_text: RichText<Message>) -> Element<'a, Message> {
+        Element::new(rich_text)
+    }
+}
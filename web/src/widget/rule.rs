@@ -0,0 +1,102 @@
+use crate::{style, Bus, Element, Length, Widget};
+
+use dodrio::bumpalo;
+
+/// A horizontal or vertical line dividing some content.
+///
+/// A [`Rule`] fills the main axis of its container and takes the given
+/// spacing on the other one. The line is drawn at its center.
+///
+/// [`Rule`]: struct.Rule.html
+#[derive(Debug, Clone, Copy)]
+pub struct Rule {
+    width: Length,
+    height: Length,
+    thickness: u16,
+    is_horizontal: bool,
+}
+
+impl Rule {
+    /// Creates a horizontal [`Rule`] taking the given vertical spacing.
+    ///
+    /// [`Rule`]: struct.Rule.html
+    pub fn horizontal(spacing: u16) -> Self {
+        Rule {
+            width: Length::Fill,
+            height: Length::Units(spacing),
+            thickness: 1,
+            is_horizontal: true,
+        }
+    }
+
+    /// Creates a vertical [`Rule`] taking the given horizontal spacing.
+    ///
+    /// [`Rule`]: struct.Rule.html
+    pub fn vertical(spacing: u16) -> Self {
+        Rule {
+            width: Length::Units(spacing),
+            height: Length::Fill,
+            thickness: 1,
+            is_horizontal: false,
+        }
+    }
+
+    /// Sets the thickness of the line of the [`Rule`], in pixels.
+    ///
+    /// [`Rule`]: struct.Rule.html
+    pub fn thickness(mut self, thickness: u16) -> Self {
+        self.thickness = thickness;
+        self
+    }
+}
+
+impl<Message> Widget<Message> for Rule {
+    fn node<'b>(
+        &self,
+        bump: &'b bumpalo::Bump,
+        _bus: &Bus<Message>,
+        _style_sheet: &mut style::Sheet<'b>,
+    ) -> dodrio::Node<'b> {
+        use dodrio::builder::*;
+
+        let (line_width, line_height) = if self.is_horizontal {
+            (String::from("100%"), format!("{}px", self.thickness))
+        } else {
+            (format!("{}px", self.thickness), String::from("100%"))
+        };
+
+        let line = div(bump)
+            .attr(
+                "style",
+                bumpalo::format!(
+                    in bump,
+                    "width: {}; height: {}; background: rgba(0, 0, 0, 0.2)",
+                    line_width,
+                    line_height
+                )
+                .into_bump_str(),
+            )
+            .finish();
+
+        div(bump)
+            .attr(
+                "style",
+                bumpalo::format!(
+                    in bump,
+                    "display: flex; align-items: center; \
+                     justify-content: center; width: {}; height: {}",
+                    style::length(self.width),
+                    style::length(self.height)
+                )
+                .into_bump_str(),
+            )
+            .children(vec![line])
+            .finish()
+    }
+}
+
+impl<'a, Message> From<Rule> for Element<'a, Message> {
+    fn from(rule: Rule) -> Element<'a, Message> {
+        Element::new(rule)
+    }
+}
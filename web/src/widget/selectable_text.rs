@@ -0,0 +1,183 @@
+//! Display text that your users can select and copy.
+//!
+//! A [`SelectableText`] has some local [`State`].
+//!
+//! [`SelectableText`]: struct.SelectableText.html
+//! [`State`]: struct.State.html
+use crate::{
+    style, Bus, Color, Element, Font, HorizontalAlignment, Length, Overflow,
+    Text, VerticalAlignment, Widget, Wrap,
+};
+use dodrio::bumpalo;
+
+/// A paragraph of text that can be selected and copied.
+///
+/// Browsers already know how to select and copy text, so this is a [`Text`]
+/// that opts into it.
+///
+/// # Example
+///
+/// ```
+/// # use iced_web::{selectable_text, SelectableText};
+///
+/// let mut state = selectable_text::State::new();
+///
+/// SelectableText::new(&mut state, "Error 0x80070005: access denied")
+///     .size(16);
+/// ```
+///
+/// [`Text`]: ../struct.Text.html
+#[allow(missing_debug_implementations)]
+pub struct SelectableText<'a> {
+    _state: &'a mut State,
+    text: Text,
+}
+
+impl<'a> SelectableText<'a> {
+    /// Creates a new [`SelectableText`] with the given [`State`] and
+    /// contents.
+    ///
+    /// [`SelectableText`]: struct.SelectableText.html
+    /// [`State`]: struct.State.html
+    pub fn new<T: Into<String>>(state: &'a mut State, content: T) -> Self {
+        SelectableText {
+            _state: state,
+            text: Text::new(content),
+        }
+    }
+
+    /// Sets the size of the [`SelectableText`].
+    ///
+    /// [`SelectableText`]: struct.SelectableText.html
+    pub fn size(mut self, size: u16) -> Self {
+        self.text = self.text.size(size);
+        self
+    }
+
+    /// Sets the [`Color`] of the [`SelectableText`].
+    ///
+    /// [`SelectableText`]: struct.SelectableText.html
+    /// [`Color`]: ../../struct.Color.html
+    pub fn color<C: Into<Color>>(mut self, color: C) -> Self {
+        self.text = self.text.color(color);
+        self
+    }
+
+    /// Sets the [`Font`] of the [`SelectableText`].
+    ///
+    /// [`SelectableText`]: struct.SelectableText.html
+    /// [`Font`]: ../../struct.Font.html
+    pub fn font(mut self, font: Font) -> Self {
+        self.text = self.text.font(font);
+        self
+    }
+
+    /// Sets the [`Wrap`] strategy of the [`SelectableText`].
+    ///
+    /// [`SelectableText`]: struct.SelectableText.html
+    /// [`Wrap`]: ../../enum.Wrap.html
+    pub fn wrap(mut self, wrap: Wrap) -> Self {
+        self.text = self.text.wrap(wrap);
+        self
+    }
+
+    /// Sets the [`Overflow`] strategy of the [`SelectableText`].
+    ///
+    /// [`SelectableText`]: struct.SelectableText.html
+    /// [`Overflow`]: ../../enum.Overflow.html
+    pub fn overflow(mut self, overflow: Overflow) -> Self {
+        self.text = self.text.overflow(overflow);
+        self
+    }
+
+    /// Sets the line height of the [`SelectableText`], relative to its size.
+    ///
+    /// [`SelectableText`]: struct.SelectableText.html
+    pub fn line_height(mut self, line_height: f32) -> Self {
+        self.text = self.text.line_height(line_height);
+        self
+    }
+
+    /// Sets the extra space between the characters of the
+    /// [`SelectableText`], in pixels.
+    ///
+    /// [`SelectableText`]: struct.SelectableText.html
+    pub fn letter_spacing(mut self, letter_spacing: f32) -> Self {
+        self.text = self.text.letter_spacing(letter_spacing);
+        self
+    }
+
+    /// Sets the width of the [`SelectableText`] boundaries.
+    ///
+    /// [`SelectableText`]: struct.SelectableText.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.text = self.text.width(width);
+        self
+    }
+
+    /// Sets the height of the [`SelectableText`] boundaries.
+    ///
+    /// [`SelectableText`]: struct.SelectableText.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.text = self.text.height(height);
+        self
+    }
+
+    /// Sets the [`HorizontalAlignment`] of the [`SelectableText`].
+    ///
+    /// [`SelectableText`]: struct.SelectableText.html
+    /// [`HorizontalAlignment`]: ../../enum.HorizontalAlignment.html
+    pub fn horizontal_alignment(
+        mut self,
+        alignment: HorizontalAlignment,
+    ) -> Self {
+        self.text = self.text.horizontal_alignment(alignment);
+        self
+    }
+
+    /// Sets the [`VerticalAlignment`] of the [`SelectableText`].
+    ///
+    /// [`SelectableText`]: struct.SelectableText.html
+    /// [`VerticalAlignment`]: ../../enum.VerticalAlignment.html
+    pub fn vertical_alignment(mut self, alignment: VerticalAlignment) -> Self {
+        self.text = self.text.vertical_alignment(alignment);
+        self
+    }
+}
+
+impl<'a, Message> Widget<Message> for SelectableText<'a> {
+    fn node<'b>(
+        &self,
+        bump: &'b bumpalo::Bump,
+        bus: &Bus<Message>,
+        style_sheet: &mut style::Sheet<'b>,
+    ) -> dodrio::Node<'b> {
+        use dodrio::builder::*;
+
+        div(bump)
+            .attr("style", "user-select: text; cursor: text")
+            .children(vec![self.text.node(bump, bus, style_sheet)])
+            .finish()
+    }
+}
+
+impl<'a, Message> From<SelectableText<'a>> for Element<'a, Message> {
+    fn from(selectable_text: SelectableText<'a>) -> Element<'a, Message> {
+        Element::new(selectable_text)
+    }
+}
+
+/// The state of a [`SelectableText`].
+///
+/// [`SelectableText`]: struct.SelectableText.html
+#[derive(Debug, Clone, Copy, Default)]
+pub struct State;
+
+impl State {
+    /// Creates a new [`State`] with nothing selected.
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
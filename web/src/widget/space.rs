@@ -0,0 +1,74 @@
+use crate::{style, Bus, Element, Length, Widget};
+
+use dodrio::bumpalo;
+
+/// An amount of empty space.
+///
+/// It can be useful if you want to fill some space with nothing, like pushing
+/// a button to the right edge of a [`Row`].
+///
+/// [`Row`]: struct.Row.html
+#[derive(Debug, Clone, Copy)]
+pub struct Space {
+    width: Length,
+    height: Length,
+}
+
+impl Space {
+    /// Creates an amount of empty [`Space`] with the given width and height.
+    ///
+    /// [`Space`]: struct.Space.html
+    pub fn new(width: Length, height: Length) -> Self {
+        Space { width, height }
+    }
+
+    /// Creates an amount of horizontal [`Space`].
+    ///
+    /// [`Space`]: struct.Space.html
+    pub fn with_width(width: Length) -> Self {
+        Space {
+            width,
+            height: Length::Shrink,
+        }
+    }
+
+    /// Creates an amount of vertical [`Space`].
+    ///
+    /// [`Space`]: struct.Space.html
+    pub fn with_height(height: Length) -> Self {
+        Space {
+            width: Length::Shrink,
+            height,
+        }
+    }
+}
+
+impl<Message> Widget<Message> for Space {
+    fn node<'b>(
+        &self,
+        bump: &'b bumpalo::Bump,
+        _bus: &Bus<Message>,
+        _style_sheet: &mut style::Sheet<'b>,
+    ) -> dodrio::Node<'b> {
+        use dodrio::builder::*;
+
+        div(bump)
+            .attr(
+                "style",
+                bumpalo::format!(
+                    in bump,
+                    "width: {}; height: {}",
+                    style::length(self.width),
+                    style::length(self.height)
+                )
+                .into_bump_str(),
+            )
+            .finish()
+    }
+}
+
+impl<'a, Message> From<Space> for Element<'a, Message> {
+    fn from(space: Space) -> Element<'a, Message> {
+        Element::new(space)
+    }
+}
@@ -0,0 +1,160 @@
+use crate::{style, Align, Bus, Element, Length, Widget};
+
+use dodrio::bumpalo;
+use std::u32;
+
+/// A container that displays its contents on top of each other.
+///
+/// All the children of a [`Stack`] share the same bounds, which fit the
+/// biggest of them. Every child is aligned on its own inside of them. Later
+/// children are displayed on top of earlier ones.
+///
+/// [`Stack`]: struct.Stack.html
+#[allow(missing_debug_implementations)]
+pub struct Stack<'a, Message> {
+    width: Length,
+    height: Length,
+    max_width: u32,
+    max_height: u32,
+    alignments: Vec<(Align, Align)>,
+    children: Vec<Element<'a, Message>>,
+}
+
+impl<'a, Message> Stack<'a, Message> {
+    /// Creates an empty [`Stack`].
+    ///
+    /// [`Stack`]: struct.Stack.html
+    pub fn new() -> Self {
+        Stack {
+            width: Length::Shrink,
+            height: Length::Shrink,
+            max_width: u32::MAX,
+            max_height: u32::MAX,
+            alignments: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+
+    /// Sets the width of the [`Stack`].
+    ///
+    /// [`Stack`]: struct.Stack.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Stack`].
+    ///
+    /// [`Stack`]: struct.Stack.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the maximum width of the [`Stack`].
+    ///
+    /// [`Stack`]: struct.Stack.html
+    pub fn max_width(mut self, max_width: u32) -> Self {
+        self.max_width = max_width;
+        self
+    }
+
+    /// Sets the maximum height of the [`Stack`] in pixels.
+    ///
+    /// [`Stack`]: struct.Stack.html
+    pub fn max_height(mut self, max_height: u32) -> Self {
+        self.max_height = max_height;
+        self
+    }
+
+    /// Adds an element on top of the contents of the [`Stack`], aligned to
+    /// its top left corner.
+    ///
+    /// [`Stack`]: struct.Stack.html
+    pub fn push<E>(self, child: E) -> Self
+    where
+        E: Into<Element<'a, Message>>,
+    {
+        self.push_aligned(child, Align::Start, Align::Start)
+    }
+
+    /// Adds an element on top of the contents of the [`Stack`], with the
+    /// given horizontal and vertical alignment.
+    ///
+    /// [`Stack`]: struct.Stack.html
+    pub fn push_aligned<E>(
+        mut self,
+        child: E,
+        horizontal_alignment: Align,
+        vertical_alignment: Align,
+    ) -> Self
+    where
+        E: Into<Element<'a, Message>>,
+    {
+        self.alignments
+            .push((horizontal_alignment, vertical_alignment));
+        self.children.push(child.into());
+        self
+    }
+}
+
+impl<'a, Message> Widget<Message> for Stack<'a, Message> {
+    fn node<'b>(
+        &self,
+        bump: &'b bumpalo::Bump,
+        publish: &Bus<Message>,
+        style_sheet: &mut style::Sheet<'b>,
+    ) -> dodrio::Node<'b> {
+        use dodrio::builder::*;
+
+        // Every child is placed in the only cell of a grid, so they overlap
+        // and later ones are displayed on top
+        let children: Vec<_> = self
+            .children
+            .iter()
+            .zip(&self.alignments)
+            .map(|(child, (horizontal_alignment, vertical_alignment))| {
+                div(bump)
+                    .attr(
+                        "style",
+                        bumpalo::format!(
+                            in bump,
+                            "grid-area: 1 / 1; display: flex; \
+                             justify-content: {}; align-items: {}",
+                            style::align(*horizontal_alignment),
+                            style::align(*vertical_alignment)
+                        )
+                        .into_bump_str(),
+                    )
+                    .children(vec![child.node(bump, publish, style_sheet)])
+                    .finish()
+            })
+            .collect();
+
+        div(bump)
+            .attr(
+                "style",
+                bumpalo::format!(
+                    in bump,
+                    "display: grid; width: {}; height: {}; max-width: {}px; \
+                     max-height: {}px",
+                    style::length(self.width),
+                    style::length(self.height),
+                    self.max_width,
+                    self.max_height
+                )
+                .into_bump_str(),
+            )
+            .children(children)
+            .finish()
+    }
+}
+
+impl<'a, Message> From<Stack<'a, Message>> for Element<'a, Message>
+where
+    Message: 'static,
+{
+    fn from(stack: Stack<'a, Message>) -> Element<'a, Message> {
+        Element::new(stack)
+    }
+}
@@ -0,0 +1,92 @@
+use crate::{style, Bus, Element, Length, Widget};
+
+use dodrio::bumpalo;
+
+/// A vector graphics image.
+///
+/// An [`Svg`] image resizes smoothly without losing any quality.
+///
+/// # Example
+///
+/// ```
+/// # use iced_web::Svg;
+///
+/// let svg = Svg::new("resources/tiger.svg");
+/// ```
+///
+/// [`Svg`]: struct.Svg.html
+#[derive(Debug)]
+pub struct Svg {
+    /// The path of the vector image
+    pub path: String,
+
+    /// The width of the vector image
+    pub width: Length,
+
+    /// The height of the vector image
+    pub height: Length,
+}
+
+impl Svg {
+    /// Creates a new [`Svg`] from the given path.
+    ///
+    /// [`Svg`]: struct.Svg.html
+    pub fn new<T: Into<String>>(path: T) -> Self {
+        Svg {
+            path: path.into(),
+            width: Length::Fill,
+            height: Length::Shrink,
+        }
+    }
+
+    /// Sets the width of the [`Svg`].
+    ///
+    /// [`Svg`]: struct.Svg.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Svg`].
+    ///
+    /// [`Svg`]: struct.Svg.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+}
+
+impl<Message> Widget<Message> for Svg {
+    fn node<'b>(
+        &self,
+        bump: &'b bumpalo::Bump,
+        _bus: &Bus<Message>,
+        _style_sheet: &mut style::Sheet<'b>,
+    ) -> dodrio::Node<'b> {
+        use dodrio::builder::*;
+
+        let src = bumpalo::format!(in bump, "{}", self.path);
+
+        // The browser rasterizes the document itself, keeping its aspect
+        // ratio with `object-fit`
+        img(bump)
+            .attr("src", src.into_bump_str())
+            .attr(
+                "style",
+                bumpalo::format!(
+                    in bump,
+                    "width: {}; height: {}; object-fit: contain",
+                    style::length(self.width),
+                    style::length(self.height)
+                )
+                .into_bump_str(),
+            )
+            .finish()
+    }
+}
+
+impl<'a, Message> From<Svg> for Element<'a, Message> {
+    fn from(svg: Svg) -> Element<'a, Message> {
+        Element::new(svg)
+    }
+}
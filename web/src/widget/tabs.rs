@@ -0,0 +1,323 @@
+//! Switch between different views with a bar of tabs.
+use crate::{bumpalo, style, Bus, Element, Font, Length, Widget};
+use std::rc::Rc;
+
+/// A bar of [`Tab`]s on top of the contents of the active one.
+///
+/// Only the contents of the active [`Tab`] are built. The application owns
+/// the active [`Tab`] and receives a message when the user selects another
+/// one, either by clicking it or by pressing `Ctrl+Tab` and `Ctrl+Shift+Tab`
+/// while the [`Tabs`] are focused.
+///
+/// [`Tab`]: struct.Tab.html
+/// [`Tabs`]: struct.Tabs.html
+#[allow(missing_debug_implementations)]
+pub struct Tabs<'a, Message> {
+    tabs: Vec<Tab>,
+    active: usize,
+    content: Element<'a, Message>,
+    on_select: Rc<Box<dyn Fn(usize) -> Message>>,
+    on_close: Option<Rc<Box<dyn Fn(usize) -> Message>>>,
+    width: Length,
+    height: Length,
+    text_size: Option<u16>,
+    padding: u16,
+}
+
+impl<'a, Message> Tabs<'a, Message> {
+    /// Creates a new [`Tabs`] widget with the given [`State`] and [`Tab`]s,
+    /// the index of the active one, its contents and a function producing a
+    /// message when a [`Tab`] is selected.
+    ///
+    /// [`Tabs`]: struct.Tabs.html
+    /// [`State`]: struct.State.html
+    /// [`Tab`]: struct.Tab.html
+    pub fn new<E, F>(
+        _state: &'a mut State,
+        tabs: Vec<Tab>,
+        active: usize,
+        content: E,
+        f: F,
+    ) -> Self
+    where
+        E: Into<Element<'a, Message>>,
+        F: 'static + Fn(usize) -> Message,
+    {
+        Tabs {
+            tabs,
+            active,
+            content: content.into(),
+            on_select: Rc::new(Box::new(f)),
+            on_close: None,
+            width: Length::Fill,
+            height: Length::Shrink,
+            text_size: None,
+            padding: 10,
+        }
+    }
+
+    /// Sets the width of the [`Tabs`].
+    ///
+    /// [`Tabs`]: struct.Tabs.html
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Tabs`].
+    ///
+    /// [`Tabs`]: struct.Tabs.html
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the text size of the [`Tab`]s.
+    ///
+    /// [`Tab`]: struct.Tab.html
+    pub fn text_size(mut self, size: u16) -> Self {
+        self.text_size = Some(size);
+        self
+    }
+
+    /// Sets the padding of every [`Tab`].
+    ///
+    /// [`Tab`]: struct.Tab.html
+    pub fn padding(mut self, units: u16) -> Self {
+        self.padding = units;
+        self
+    }
+
+    /// Shows a close button on every [`Tab`], producing the given message
+    /// when pressed.
+    ///
+    /// The function receives the index of the [`Tab`] to close.
+    ///
+    /// [`Tab`]: struct.Tab.html
+    pub fn on_close<F>(mut self, f: F) -> Self
+    where
+        F: 'static + Fn(usize) -> Message,
+    {
+        self.on_close = Some(Rc::new(Box::new(f)));
+        self
+    }
+}
+
+impl<'a, Message> Widget<Message> for Tabs<'a, Message>
+where
+    Message: 'static,
+{
+    fn node<'b>(
+        &self,
+        bump: &'b bumpalo::Bump,
+        bus: &Bus<Message>,
+        style_sheet: &mut style::Sheet<'b>,
+    ) -> dodrio::Node<'b> {
+        use dodrio::builder::*;
+        use wasm_bindgen::JsCast;
+
+        let text_size = self.text_size.unwrap_or(20);
+
+        let headers: Vec<_> = self
+            .tabs
+            .iter()
+            .enumerate()
+            .map(|(i, tab)| {
+                let is_active = i == self.active;
+                let mut children = Vec::new();
+
+                if let Some((icon, font)) = tab.icon {
+                    let font_family = style::font_family(font);
+
+                    children.push(
+                        span(bump)
+                            .attr(
+                                "style",
+                                bumpalo::format!(
+                                    in bump,
+                                    "font-family: {}; margin-right: {}px",
+                                    font_family,
+                                    self.padding / 2
+                                )
+                                .into_bump_str(),
+                            )
+                            .children(vec![text(
+                                bumpalo::format!(in bump, "{}", icon)
+                                    .into_bump_str(),
+                            )])
+                            .finish(),
+                    );
+                }
+
+                if let Some(label) = &tab.text {
+                    children.push(text(
+                        bumpalo::format!(in bump, "{}", label).into_bump_str(),
+                    ));
+                }
+
+                if let Some(on_close) = &self.on_close {
+                    let on_close = on_close.clone();
+                    let event_bus = bus.clone();
+
+                    children.push(
+                        span(bump)
+                            .attr(
+                                "style",
+                                bumpalo::format!(
+                                    in bump,
+                                    "margin-left: {}px; cursor: pointer",
+                                    self.padding / 2
+                                )
+                                .into_bump_str(),
+                            )
+                            .on("click", move |root, vdom, event| {
+                                event.stop_propagation();
+
+                                event_bus.publish(on_close(i), root);
+                                vdom.schedule_render();
+                            })
+                            .children(vec![text("×")])
+                            .finish(),
+                    );
+                }
+
+                let on_select = self.on_select.clone();
+                let event_bus = bus.clone();
+
+                div(bump)
+                    .attr(
+                        "style",
+                        bumpalo::format!(
+                            in bump,
+                            "padding: {}px; font-size: {}px; cursor: pointer; \
+                             background: {}; border-bottom: 3px solid {}",
+                            self.padding,
+                            text_size,
+                            if is_active { "#ffffff" } else { "transparent" },
+                            if is_active { "#4c80e6" } else { "transparent" }
+                        )
+                        .into_bump_str(),
+                    )
+                    .on("click", move |root, vdom, _event| {
+                        if !is_active {
+                            event_bus.publish(on_select(i), root);
+                            vdom.schedule_render();
+                        }
+                    })
+                    .children(children)
+                    .finish()
+            })
+            .collect();
+
+        let bar = div(bump)
+            .attr(
+                "style",
+                "display: flex; background: #e6e6e6; \
+                 border-bottom: 1px solid #b3b3b3",
+            )
+            .children(headers)
+            .finish();
+
+        let on_select = self.on_select.clone();
+        let event_bus = bus.clone();
+        let (active, count) = (self.active, self.tabs.len());
+
+        div(bump)
+            .attr("tabindex", "0")
+            .attr(
+                "style",
+                bumpalo::format!(
+                    in bump,
+                    "width: {}; height: {}; outline: none",
+                    style::length(self.width),
+                    style::length(self.height)
+                )
+                .into_bump_str(),
+            )
+            .on("keydown", move |root, vdom, event| {
+                let event = match event.dyn_into::<web_sys::KeyboardEvent>() {
+                    Ok(event) => event,
+                    Err(_) => return,
+                };
+
+                if event.key() == "Tab" && event.ctrl_key() && count > 1 {
+                    event.prevent_default();
+
+                    // Outer tabs must not switch too
+                    event.stop_propagation();
+
+                    let index = if event.shift_key() {
+                        (active + count - 1) % count
+                    } else {
+                        (active + 1) % count
+                    };
+
+                    event_bus.publish(on_select(index), root);
+                    vdom.schedule_render();
+                }
+            })
+            .children(vec![bar, self.content.node(bump, bus, style_sheet)])
+            .finish()
+    }
+}
+
+impl<'a, Message> From<Tabs<'a, Message>> for Element<'a, Message>
+where
+    Message: 'static,
+{
+    fn from(tabs: Tabs<'a, Message>) -> Element<'a, Message> {
+        Element::new(tabs)
+    }
+}
+
+/// The local state of some [`Tabs`].
+///
+/// [`Tabs`]: struct.Tabs.html
+#[derive(Debug, Clone, Copy, Default)]
+pub struct State;
+
+impl State {
+    /// Creates a new [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> Self {
+        State::default()
+    }
+}
+
+/// A tab of some [`Tabs`], with a text and/or an icon.
+///
+/// [`Tabs`]: struct.Tabs.html
+#[derive(Debug, Clone, Default)]
+pub struct Tab {
+    text: Option<String>,
+    icon: Option<(char, Font)>,
+}
+
+impl Tab {
+    /// Creates a new empty [`Tab`].
+    ///
+    /// [`Tab`]: struct.Tab.html
+    pub fn new() -> Self {
+        Tab::default()
+    }
+
+    /// Sets the text of the [`Tab`].
+    ///
+    /// [`Tab`]: struct.Tab.html
+    pub fn text(mut self, text: impl Into<String>) -> Self {
+        self.text = Some(text.into());
+        self
+    }
+
+    /// Sets the icon of the [`Tab`], a character of the given [`Font`].
+    ///
+    /// The icon is displayed before the text.
+    ///
+    /// [`Tab`]: struct.Tab.html
+    /// [`Font`]: ../../enum.Font.html
+    pub fn icon(mut self, icon: char, font: Font) -> Self {
+        self.icon = Some((icon, font));
+        self
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{
-    style, Bus, Color, Element, Font, HorizontalAlignment, Length,
-    VerticalAlignment, Widget,
+    style, Bus, Color, Element, Font, HorizontalAlignment, Length, Overflow,
+    VerticalAlignment, Widget, Wrap,
 };
 use dodrio::bumpalo;
 
@@ -20,6 +20,10 @@ pub struct Text {
     size: Option<u16>,
     color: Option<Color>,
     font: Font,
+    wrap: Wrap,
+    overflow: Overflow,
+    line_height: Option<f32>,
+    letter_spacing: f32,
     width: Length,
     height: Length,
     horizontal_alignment: HorizontalAlignment,
@@ -36,6 +40,10 @@ impl Text {
             size: None,
             color: None,
             font: Font::Default,
+            wrap: Wrap::Word,
+            overflow: Overflow::Visible,
+            line_height: None,
+            letter_spacing: 0.0,
             width: Length::Fill,
             height: Length::Shrink,
             horizontal_alignment: HorizontalAlignment::Left,
@@ -69,6 +77,46 @@ impl Text {
         self
     }
 
+    /// Sets the [`Wrap`] strategy of the [`Text`].
+    ///
+    /// [`Text`]: struct.Text.html
+    /// [`Wrap`]: ../../enum.Wrap.html
+    pub fn wrap(mut self, wrap: Wrap) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    /// Sets the [`Overflow`] strategy of the [`Text`].
+    ///
+    /// [`Overflow::MiddleEllipsis`] is not supported by CSS and behaves like
+    /// [`Overflow::Ellipsis`].
+    ///
+    /// [`Text`]: struct.Text.html
+    /// [`Overflow`]: ../../enum.Overflow.html
+    /// [`Overflow::MiddleEllipsis`]: ../../enum.Overflow.html#variant.MiddleEllipsis
+    /// [`Overflow::Ellipsis`]: ../../enum.Overflow.html#variant.Ellipsis
+    pub fn overflow(mut self, overflow: Overflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    /// Sets the line height of the [`Text`], relative to its size.
+    ///
+    /// [`Text`]: struct.Text.html
+    pub fn line_height(mut self, line_height: f32) -> Self {
+        self.line_height = Some(line_height);
+        self
+    }
+
+    /// Sets the extra space between the characters of the [`Text`], in
+    /// pixels.
+    ///
+    /// [`Text`]: struct.Text.html
+    pub fn letter_spacing(mut self, letter_spacing: f32) -> Self {
+        self.letter_spacing = letter_spacing;
+        self
+    }
+
     /// Sets the width of the [`Text`] boundaries.
     ///
     /// [`Text`]: struct.Text.html
@@ -125,12 +173,40 @@ impl<'a, Message> Widget<Message> for Text {
             HorizontalAlignment::Right => "right",
         };
 
+        let (white_space, word_break) = match self.wrap {
+            Wrap::Word => ("normal", "normal"),
+            Wrap::Character => ("normal", "break-all"),
+            Wrap::None => ("pre", "normal"),
+        };
+
+        let (overflow, text_overflow) = match self.overflow {
+            Overflow::Visible => ("visible", "clip"),
+            Overflow::Clip => ("hidden", "clip"),
+            Overflow::Ellipsis | Overflow::MiddleEllipsis => {
+                ("hidden", "ellipsis")
+            }
+        };
+
+        let line_height = match self.line_height {
+            Some(line_height) => line_height.to_string(),
+            None => String::from("normal"),
+        };
+
         let style = bumpalo::format!(
             in bump,
-            "font-size: {}px; color: {}; text-align: {}",
+            "font-size: {}px; {}; color: {}; text-align: {}; \
+             white-space: {}; word-break: {}; overflow: {}; \
+             text-overflow: {}; line-height: {}; letter-spacing: {}px",
             self.size.unwrap_or(20),
+            style::font(self.font),
             color,
-            text_align
+            text_align,
+            white_space,
+            word_break,
+            overflow,
+            text_overflow,
+            line_height,
+            self.letter_spacing
         );
 
         // TODO: Complete styling
@@ -1,11 +1,19 @@
+mod atlas;
+mod decoder;
+mod raster;
+#[cfg(feature = "svg")]
+mod vector;
+
 use crate::Transformation;
-use iced_native::Rectangle;
+use iced_native::{image, Rectangle};
 
-use std::{cell::RefCell, collections::HashMap, mem, rc::Rc};
+use std::{cell::RefCell, mem, rc::Rc, time::Instant};
 
 #[derive(Debug)]
 pub struct Pipeline {
-    cache: RefCell<HashMap<String, Memory>>,
+    raster_cache: RefCell<raster::Cache>,
+    #[cfg(feature = "svg")]
+    vector_cache: RefCell<vector::Cache>,
 
     pipeline: wgpu::RenderPipeline,
     uniforms: wgpu::Buffer,
@@ -14,10 +22,11 @@ pub struct Pipeline {
     instances: wgpu::Buffer,
     constants: wgpu::BindGroup,
     texture_layout: wgpu::BindGroupLayout,
+    redraw_request: Option<Instant>,
 }
 
 impl Pipeline {
-    pub fn new(device: &wgpu::Device) -> Self {
+    pub fn new(device: &wgpu::Device, cache_budget: usize) -> Self {
         let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
             address_mode_u: wgpu::AddressMode::ClampToEdge,
             address_mode_v: wgpu::AddressMode::ClampToEdge,
@@ -163,6 +172,16 @@ impl Pipeline {
                                 format: wgpu::VertexFormat::Float2,
                                 offset: 4 * 2,
                             },
+                            wgpu::VertexAttributeDescriptor {
+                                shader_location: 3,
+                                format: wgpu::VertexFormat::Float2,
+                                offset: 4 * 4,
+                            },
+                            wgpu::VertexAttributeDescriptor {
+                                shader_location: 4,
+                                format: wgpu::VertexFormat::Float2,
+                                offset: 4 * 6,
+                            },
                         ],
                     },
                 ],
@@ -180,12 +199,17 @@ impl Pipeline {
             .fill_from_slice(&QUAD_INDICES);
 
         let instances = device.create_buffer(&wgpu::BufferDescriptor {
-            size: mem::size_of::<Instance>() as u64,
+            size: mem::size_of::<Instance>() as u64 * Instance::MAX as u64,
             usage: wgpu::BufferUsage::VERTEX | wgpu::BufferUsage::COPY_DST,
         });
 
+        let raster_cache =
+            raster::Cache::new(device, &texture_layout, cache_budget);
+
         Pipeline {
-            cache: RefCell::new(HashMap::new()),
+            raster_cache: RefCell::new(raster_cache),
+            #[cfg(feature = "svg")]
+            vector_cache: RefCell::new(vector::Cache::new()),
 
             pipeline,
             uniforms: uniforms_buffer,
@@ -194,35 +218,55 @@ impl Pipeline {
             instances,
             constants: constant_bind_group,
             texture_layout,
+            redraw_request: None,
         }
     }
 
-    pub fn dimensions(&self, path: &str) -> (u32, u32) {
-        self.load(path);
+    pub fn dimensions(&self, handle: &image::Handle) -> (u32, u32) {
+        self.raster_cache.borrow_mut().dimensions(handle)
+    }
+
+    /// Returns whether the dimensions of some image changed since the last
+    /// call, because it finished loading.
+    pub fn take_relayout_request(&mut self) -> bool {
+        let mut raster_cache = self.raster_cache.borrow_mut();
 
-        self.cache.borrow().get(path).unwrap().dimensions()
+        raster_cache.poll();
+        raster_cache.take_stale_layout()
     }
 
-    fn load(&self, path: &str) {
-        if !self.cache.borrow().contains_key(path) {
-            let memory = if let Ok(image) = image::open(path) {
-                Memory::Host {
-                    image: image.to_bgra(),
-                }
-            } else {
-                Memory::NotFound
-            };
+    /// Returns whether some images are still being loaded.
+    pub fn is_loading(&self) -> bool {
+        self.raster_cache.borrow().is_loading()
+    }
 
-            let _ = self.cache.borrow_mut().insert(path.to_string(), memory);
-        }
+    /// Returns when the next frame of the animated images drawn so far is
+    /// due, if any, and clears it.
+    pub fn take_redraw_request(&mut self) -> Option<Instant> {
+        self.redraw_request.take()
+    }
+
+    #[cfg(feature = "svg")]
+    pub fn viewport_dimensions(&self, path: &str) -> (u32, u32) {
+        self.vector_cache
+            .borrow_mut()
+            .load(path)
+            .viewport_dimensions()
+    }
+
+    #[cfg(not(feature = "svg"))]
+    pub fn viewport_dimensions(&self, _path: &str) -> (u32, u32) {
+        (1, 1)
     }
 
+    #[cfg_attr(not(feature = "svg"), allow(unused_variables))]
     pub fn draw(
         &mut self,
         device: &mut wgpu::Device,
         encoder: &mut wgpu::CommandEncoder,
         instances: &[Image],
         transformation: Transformation,
+        scale: f32,
         bounds: Rectangle<u32>,
         target: &wgpu::TextureView,
     ) {
@@ -240,33 +284,124 @@ impl Pipeline {
             std::mem::size_of::<Uniforms>() as u64,
         );
 
-        // TODO: Batch draw calls using a texture atlas
-        // Guillotière[1] by @nical can help us a lot here.
-        //
-        // [1]: https://github.com/nical/guillotiere
+        self.raster_cache.borrow_mut().poll();
+
+        let now = Instant::now();
+
+        // Consecutive images sharing a texture are drawn in a single batch.
+        // The atlas is represented by `None`.
+        let mut batches: Vec<(Option<Rc<wgpu::BindGroup>>, Vec<Instance>)> =
+            Vec::new();
+
         for image in instances {
-            self.load(&image.path);
-
-            if let Some(texture) = self
-                .cache
-                .borrow_mut()
-                .get_mut(&image.path)
-                .unwrap()
-                .upload(device, encoder, &self.texture_layout)
-            {
+            let texture = match &image.handle {
+                Handle::Raster(handle, playback) => {
+                    let texture = self.raster_cache.borrow_mut().upload(
+                        handle,
+                        *playback,
+                        now,
+                        device,
+                        encoder,
+                        &self.texture_layout,
+                    );
+
+                    let redraw_request = &mut self.redraw_request;
+
+                    texture.map(|(texture, next_frame)| {
+                        if let Some(next_frame) = next_frame {
+                            *redraw_request = Some(match *redraw_request {
+                                Some(current) => current.min(next_frame),
+                                None => next_frame,
+                            });
+                        }
+
+                        match texture {
+                            raster::Texture::Atlas(allocation) => (
+                                None,
+                                allocation.position(),
+                                allocation.scale(),
+                            ),
+                            raster::Texture::Standalone(bind_group) => {
+                                (Some(bind_group), [0.0, 0.0], [1.0, 1.0])
+                            }
+                        }
+                    })
+                }
+                #[cfg(feature = "svg")]
+                Handle::Vector(path) => {
+                    // Rasterize at the exact physical size to stay crisp
+                    let size = [
+                        (image.scale[0] * scale).round() as u32,
+                        (image.scale[1] * scale).round() as u32,
+                    ];
+
+                    self.vector_cache
+                        .borrow_mut()
+                        .upload(
+                            path,
+                            size,
+                            device,
+                            encoder,
+                            &self.texture_layout,
+                        )
+                        .map(|bind_group| {
+                            (Some(bind_group), [0.0, 0.0], [1.0, 1.0])
+                        })
+                }
+                #[cfg(not(feature = "svg"))]
+                Handle::Vector(_) => None,
+            };
+
+            if let Some((bind_group, atlas_position, atlas_scale)) = texture {
+                let instance = Instance {
+                    _position: image.position,
+                    _scale: image.scale,
+                    _atlas_position: atlas_position,
+                    _atlas_scale: atlas_scale,
+                };
+
+                match batches.last_mut() {
+                    Some((last, batch))
+                        if match (&*last, &bind_group) {
+                            (None, None) => true,
+                            (Some(a), Some(b)) => Rc::ptr_eq(a, b),
+                            _ => false,
+                        } =>
+                    {
+                        batch.push(instance);
+                    }
+                    _ => {
+                        batches.push((bind_group, vec![instance]));
+                    }
+                }
+            }
+        }
+
+        let raster_cache = self.raster_cache.borrow();
+
+        for (bind_group, instances) in batches.iter() {
+            let bind_group = match bind_group {
+                Some(bind_group) => bind_group,
+                None => raster_cache.atlas().bind_group(),
+            };
+
+            let mut i = 0;
+            let total = instances.len();
+
+            while i < total {
+                let end = (i + Instance::MAX).min(total);
+                let amount = end - i;
+
                 let instance_buffer = device
-                    .create_buffer_mapped(1, wgpu::BufferUsage::COPY_SRC)
-                    .fill_from_slice(&[Instance {
-                        _position: image.position,
-                        _scale: image.scale,
-                    }]);
+                    .create_buffer_mapped(amount, wgpu::BufferUsage::COPY_SRC)
+                    .fill_from_slice(&instances[i..end]);
 
                 encoder.copy_buffer_to_buffer(
                     &instance_buffer,
                     0,
                     &self.instances,
                     0,
-                    mem::size_of::<Instance>() as u64,
+                    (mem::size_of::<Instance>() * amount) as u64,
                 );
 
                 {
@@ -292,7 +427,7 @@ impl Pipeline {
 
                     render_pass.set_pipeline(&self.pipeline);
                     render_pass.set_bind_group(0, &self.constants, &[]);
-                    render_pass.set_bind_group(1, &texture, &[]);
+                    render_pass.set_bind_group(1, bind_group, &[]);
                     render_pass.set_index_buffer(&self.indices, 0);
                     render_pass.set_vertex_buffers(
                         0,
@@ -308,125 +443,34 @@ impl Pipeline {
                     render_pass.draw_indexed(
                         0..QUAD_INDICES.len() as u32,
                         0,
-                        0..1 as u32,
+                        0..amount as u32,
                     );
                 }
+
+                i += Instance::MAX;
             }
         }
     }
-}
 
-#[derive(Debug)]
-enum Memory {
-    Host {
-        image: image::ImageBuffer<image::Bgra<u8>, Vec<u8>>,
-    },
-    Device {
-        bind_group: Rc<wgpu::BindGroup>,
-        width: u32,
-        height: u32,
-    },
-    NotFound,
-}
+    pub fn trim_cache(&mut self) {
+        self.raster_cache.borrow_mut().trim();
 
-impl Memory {
-    fn dimensions(&self) -> (u32, u32) {
-        match self {
-            Memory::Host { image } => image.dimensions(),
-            Memory::Device { width, height, .. } => (*width, *height),
-            Memory::NotFound => (1, 1),
-        }
-    }
-
-    fn upload(
-        &mut self,
-        device: &wgpu::Device,
-        encoder: &mut wgpu::CommandEncoder,
-        texture_layout: &wgpu::BindGroupLayout,
-    ) -> Option<Rc<wgpu::BindGroup>> {
-        match self {
-            Memory::Host { image } => {
-                let (width, height) = image.dimensions();
-
-                let extent = wgpu::Extent3d {
-                    width,
-                    height,
-                    depth: 1,
-                };
-
-                let texture = device.create_texture(&wgpu::TextureDescriptor {
-                    size: extent,
-                    array_layer_count: 1,
-                    mip_level_count: 1,
-                    sample_count: 1,
-                    dimension: wgpu::TextureDimension::D2,
-                    format: wgpu::TextureFormat::Bgra8UnormSrgb,
-                    usage: wgpu::TextureUsage::COPY_DST
-                        | wgpu::TextureUsage::SAMPLED,
-                });
-
-                let slice = image.clone().into_raw();
-
-                let temp_buf = device
-                    .create_buffer_mapped(
-                        slice.len(),
-                        wgpu::BufferUsage::COPY_SRC,
-                    )
-                    .fill_from_slice(&slice[..]);
-
-                encoder.copy_buffer_to_texture(
-                    wgpu::BufferCopyView {
-                        buffer: &temp_buf,
-                        offset: 0,
-                        row_pitch: 4 * width as u32,
-                        image_height: height as u32,
-                    },
-                    wgpu::TextureCopyView {
-                        texture: &texture,
-                        array_layer: 0,
-                        mip_level: 0,
-                        origin: wgpu::Origin3d {
-                            x: 0.0,
-                            y: 0.0,
-                            z: 0.0,
-                        },
-                    },
-                    extent,
-                );
-
-                let bind_group =
-                    device.create_bind_group(&wgpu::BindGroupDescriptor {
-                        layout: texture_layout,
-                        bindings: &[wgpu::Binding {
-                            binding: 0,
-                            resource: wgpu::BindingResource::TextureView(
-                                &texture.create_default_view(),
-                            ),
-                        }],
-                    });
-
-                let bind_group = Rc::new(bind_group);
-
-                *self = Memory::Device {
-                    bind_group: bind_group.clone(),
-                    width,
-                    height,
-                };
-
-                Some(bind_group)
-            }
-            Memory::Device { bind_group, .. } => Some(bind_group.clone()),
-            Memory::NotFound => None,
-        }
+        #[cfg(feature = "svg")]
+        self.vector_cache.borrow_mut().trim();
     }
 }
 
 pub struct Image {
-    pub path: String,
+    pub handle: Handle,
     pub position: [f32; 2],
     pub scale: [f32; 2],
 }
 
+pub enum Handle {
+    Raster(image::Handle, image::Playback),
+    Vector(String),
+}
+
 #[derive(Clone, Copy)]
 pub struct Vertex {
     _position: [f32; 2],
@@ -453,6 +497,12 @@ const QUAD_VERTS: [Vertex; 4] = [
 struct Instance {
     _position: [f32; 2],
     _scale: [f32; 2],
+    _atlas_position: [f32; 2],
+    _atlas_scale: [f32; 2],
+}
+
+impl Instance {
+    pub const MAX: usize = 1_000;
 }
 
 #[repr(C)]
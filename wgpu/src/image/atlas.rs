@@ -0,0 +1,201 @@
+use guillotiere::{size2, AllocId, AtlasAllocator};
+use std::fmt;
+
+/// The width and height of the atlas texture.
+pub const SIZE: u32 = 2048;
+
+/// The maximum width and height of an image stored in the atlas.
+///
+/// Bigger images get their own texture, as they would fill the atlas too
+/// quickly.
+pub const MAX_ENTRY_SIZE: u32 = 512;
+
+/// Every entry is surrounded by a border of this size, filled with its edge
+/// pixels, to avoid bleeding neighboring entries when filtering.
+const PADDING: u32 = 1;
+
+/// A texture shared by many small images, allowing them to be drawn in a
+/// single batch.
+pub struct Atlas {
+    texture: wgpu::Texture,
+    bind_group: wgpu::BindGroup,
+    allocator: AtlasAllocator,
+}
+
+/// The region of the [`Atlas`] where an image is stored.
+///
+/// [`Atlas`]: struct.Atlas.html
+#[derive(Debug, Clone, Copy)]
+pub struct Allocation {
+    id: AllocId,
+    x: u32,
+    y: u32,
+    width: u32,
+    height: u32,
+}
+
+impl Allocation {
+    /// Returns the position of the [`Allocation`] in texture coordinates.
+    ///
+    /// [`Allocation`]: struct.Allocation.html
+    pub fn position(&self) -> [f32; 2] {
+        [self.x as f32 / SIZE as f32, self.y as f32 / SIZE as f32]
+    }
+
+    /// Returns the size of the [`Allocation`] in texture coordinates.
+    ///
+    /// [`Allocation`]: struct.Allocation.html
+    pub fn scale(&self) -> [f32; 2] {
+        [
+            self.width as f32 / SIZE as f32,
+            self.height as f32 / SIZE as f32,
+        ]
+    }
+}
+
+impl Atlas {
+    pub fn new(
+        device: &wgpu::Device,
+        texture_layout: &wgpu::BindGroupLayout,
+    ) -> Self {
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            size: wgpu::Extent3d {
+                width: SIZE,
+                height: SIZE,
+                depth: 1,
+            },
+            array_layer_count: 1,
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: wgpu::TextureFormat::Bgra8UnormSrgb,
+            usage: wgpu::TextureUsage::COPY_DST | wgpu::TextureUsage::SAMPLED,
+        });
+
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            layout: texture_layout,
+            bindings: &[wgpu::Binding {
+                binding: 0,
+                resource: wgpu::BindingResource::TextureView(
+                    &texture.create_default_view(),
+                ),
+            }],
+        });
+
+        Atlas {
+            texture,
+            bind_group,
+            allocator: AtlasAllocator::new(size2(SIZE as i32, SIZE as i32)),
+        }
+    }
+
+    /// Returns true if an image of the given dimensions can be stored in
+    /// the [`Atlas`].
+    ///
+    /// [`Atlas`]: struct.Atlas.html
+    pub fn fits(width: u32, height: u32) -> bool {
+        width > 0
+            && height > 0
+            && width <= MAX_ENTRY_SIZE
+            && height <= MAX_ENTRY_SIZE
+    }
+
+    pub fn bind_group(&self) -> &wgpu::BindGroup {
+        &self.bind_group
+    }
+
+    /// Reserves space for an image of the given dimensions.
+    ///
+    /// Returns `None` if the [`Atlas`] is full.
+    ///
+    /// [`Atlas`]: struct.Atlas.html
+    pub fn allocate(&mut self, width: u32, height: u32) -> Option<Allocation> {
+        let allocation = self.allocator.allocate(size2(
+            (width + 2 * PADDING) as i32,
+            (height + 2 * PADDING) as i32,
+        ))?;
+
+        Some(Allocation {
+            id: allocation.id,
+            x: allocation.rectangle.min.x as u32 + PADDING,
+            y: allocation.rectangle.min.y as u32 + PADDING,
+            width,
+            height,
+        })
+    }
+
+    /// Frees the space taken by the given [`Allocation`].
+    ///
+    /// [`Allocation`]: struct.Allocation.html
+    pub fn deallocate(&mut self, allocation: &Allocation) {
+        self.allocator.deallocate(allocation.id);
+    }
+
+    /// Copies the given BGRA pixels to the region of an [`Allocation`].
+    ///
+    /// [`Allocation`]: struct.Allocation.html
+    pub fn upload(
+        &self,
+        allocation: &Allocation,
+        pixels: &[u8],
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+    ) {
+        let width = allocation.width as usize;
+        let height = allocation.height as usize;
+        let padded_width = width + 2 * PADDING as usize;
+        let padded_height = height + 2 * PADDING as usize;
+
+        // Extrude the edges of the image into the padding
+        let mut padded = Vec::with_capacity(4 * padded_width * padded_height);
+
+        for y in 0..padded_height {
+            let row = y.saturating_sub(PADDING as usize).min(height - 1);
+            let row = &pixels[4 * width * row..4 * width * (row + 1)];
+
+            for _ in 0..PADDING {
+                padded.extend_from_slice(&row[..4]);
+            }
+
+            padded.extend_from_slice(row);
+
+            for _ in 0..PADDING {
+                padded.extend_from_slice(&row[row.len() - 4..]);
+            }
+        }
+
+        let temp_buf = device
+            .create_buffer_mapped(padded.len(), wgpu::BufferUsage::COPY_SRC)
+            .fill_from_slice(&padded);
+
+        encoder.copy_buffer_to_texture(
+            wgpu::BufferCopyView {
+                buffer: &temp_buf,
+                offset: 0,
+                row_pitch: 4 * padded_width as u32,
+                image_height: padded_height as u32,
+            },
+            wgpu::TextureCopyView {
+                texture: &self.texture,
+                array_layer: 0,
+                mip_level: 0,
+                origin: wgpu::Origin3d {
+                    x: (allocation.x - PADDING) as f32,
+                    y: (allocation.y - PADDING) as f32,
+                    z: 0.0,
+                },
+            },
+            wgpu::Extent3d {
+                width: padded_width as u32,
+                height: padded_height as u32,
+                depth: 1,
+            },
+        );
+    }
+}
+
+impl fmt::Debug for Atlas {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Atlas")
+    }
+}
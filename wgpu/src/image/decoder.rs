@@ -0,0 +1,310 @@
+use ::image::AnimationDecoder;
+use iced_native::image;
+
+use std::{
+    io::Cursor,
+    sync::{mpsc, Arc, Mutex},
+    thread,
+    time::Duration,
+};
+
+/// The amount of threads decoding images in the background.
+const WORKERS: usize = 4;
+
+pub type Bgra = ::image::ImageBuffer<::image::Bgra<u8>, Vec<u8>>;
+
+/// A frame of a decoded image.
+///
+/// Images that are not animated have a single frame.
+#[derive(Debug)]
+pub struct Frame {
+    pub image: Bgra,
+    pub delay: Duration,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    NotFound,
+    Invalid,
+}
+
+/// A pool of threads decoding images off the UI thread.
+///
+/// Decoded images can be collected with `try_iter` once they are ready.
+#[derive(Debug)]
+pub struct Decoder {
+    jobs: mpsc::Sender<image::Handle>,
+    results: mpsc::Receiver<(u64, Result<Vec<Frame>, Error>)>,
+    _workers: Vec<thread::JoinHandle<()>>,
+}
+
+impl Decoder {
+    pub fn new() -> Self {
+        let (jobs, job_receiver) = mpsc::channel::<image::Handle>();
+        let (result_sender, results) = mpsc::channel();
+
+        let job_receiver = Arc::new(Mutex::new(job_receiver));
+
+        let workers = (0..WORKERS)
+            .map(|i| {
+                let jobs = job_receiver.clone();
+                let results = result_sender.clone();
+
+                thread::Builder::new()
+                    .name(format!("iced_wgpu::image::decoder::{}", i))
+                    .spawn(move || loop {
+                        let handle = match jobs.lock() {
+                            Ok(jobs) => match jobs.recv() {
+                                Ok(handle) => handle,
+                                Err(_) => break,
+                            },
+                            Err(_) => break,
+                        };
+
+                        let result = decode(&handle);
+
+                        if results.send((handle.id(), result)).is_err() {
+                            break;
+                        }
+                    })
+                    .expect("Spawn image decoder thread")
+            })
+            .collect();
+
+        Decoder {
+            jobs,
+            results,
+            _workers: workers,
+        }
+    }
+
+    /// Queues the given image to be decoded.
+    pub fn decode(&self, handle: &image::Handle) {
+        // Workers only stop once the `Decoder` is dropped
+        let _ = self.jobs.send(handle.clone());
+    }
+
+    /// Returns the images that have been decoded since the last call, without
+    /// blocking.
+    pub fn try_iter(
+        &self,
+    ) -> impl Iterator<Item = (u64, Result<Vec<Frame>, Error>)> + '_ {
+        self.results.try_iter()
+    }
+}
+
+fn decode(handle: &image::Handle) -> Result<Vec<Frame>, Error> {
+    match handle.data() {
+        image::Data::Path(path) => {
+            let bytes = std::fs::read(path).map_err(|_| Error::NotFound)?;
+
+            match ::image::guess_format(&bytes) {
+                Ok(::image::ImageFormat::GIF) => decode_gif(&bytes),
+                Ok(::image::ImageFormat::PNG) => decode_png(&bytes),
+                // Some formats can only be guessed from the file extension
+                _ => ::image::open(path)
+                    .map(|image| vec![still(image)])
+                    .map_err(|_| Error::Invalid),
+            }
+        }
+        image::Data::Bytes(bytes) => match ::image::guess_format(bytes) {
+            Ok(::image::ImageFormat::GIF) => decode_gif(bytes),
+            Ok(::image::ImageFormat::PNG) => decode_png(bytes),
+            _ => ::image::load_from_memory(bytes)
+                .map(|image| vec![still(image)])
+                .map_err(|_| Error::Invalid),
+        },
+        image::Data::Pixels {
+            width,
+            height,
+            pixels,
+        } => ::image::ImageBuffer::from_vec(*width, *height, pixels.to_vec())
+            .map(|image| vec![still(::image::DynamicImage::ImageRgba8(image))])
+            .ok_or(Error::Invalid),
+    }
+}
+
+fn still(image: ::image::DynamicImage) -> Frame {
+    Frame {
+        image: image.to_bgra(),
+        delay: Duration::from_secs(0),
+    }
+}
+
+fn decode_gif(bytes: &[u8]) -> Result<Vec<Frame>, Error> {
+    let decoder = ::image::gif::Decoder::new(Cursor::new(bytes))
+        .map_err(|_| Error::Invalid)?;
+
+    let frames = decoder
+        .into_frames()
+        .collect_frames()
+        .map_err(|_| Error::Invalid)?;
+
+    Ok(frames
+        .into_iter()
+        .map(|frame| {
+            let delay = frame.delay();
+
+            // The delay is given in milliseconds
+            let delay = Duration::from_millis(
+                u64::from(*delay.numer()) / u64::from(*delay.denom()).max(1),
+            );
+
+            Frame {
+                image: ::image::DynamicImage::ImageRgba8(frame.into_buffer())
+                    .to_bgra(),
+                delay,
+            }
+        })
+        .collect())
+}
+
+/// Decodes a PNG file, which may be animated (APNG).
+///
+/// The frames of an APNG are composed following their blend and dispose
+/// operations, so every resulting frame is complete.
+fn decode_png(bytes: &[u8]) -> Result<Vec<Frame>, Error> {
+    let mut decoder = png::Decoder::new(bytes);
+    decoder.set_transformations(
+        png::Transformations::EXPAND | png::Transformations::STRIP_16,
+    );
+
+    let (info, mut reader) = decoder.read_info().map_err(|_| Error::Invalid)?;
+
+    let animation = match reader.info().animation_control() {
+        Some(animation) if animation.num_frames > 1 => *animation,
+        _ => {
+            return ::image::load_from_memory(bytes)
+                .map(|image| vec![still(image)])
+                .map_err(|_| Error::Invalid);
+        }
+    };
+
+    let (color_type, _) = reader.output_color_type();
+    let samples = color_type.samples();
+
+    let mut buffer = vec![0; reader.output_buffer_size()];
+
+    // The default image is not part of the animation when it is not
+    // preceded by a frame control chunk
+    if reader.info().frame_control().is_none() {
+        reader.next_frame(&mut buffer).map_err(|_| Error::Invalid)?;
+    }
+
+    let mut canvas = ::image::RgbaImage::new(info.width, info.height);
+    let mut frames = Vec::with_capacity(animation.num_frames as usize);
+
+    for _ in 0..animation.num_frames {
+        reader.next_frame(&mut buffer).map_err(|_| Error::Invalid)?;
+
+        let control = *reader.info().frame_control().ok_or(Error::Invalid)?;
+
+        let previous = if control.dispose_op == png::DisposeOp::Previous {
+            Some(canvas.clone())
+        } else {
+            None
+        };
+
+        for y in 0..control.height {
+            for x in 0..control.width {
+                let (canvas_x, canvas_y) =
+                    (control.x_offset + x, control.y_offset + y);
+
+                if canvas_x >= info.width || canvas_y >= info.height {
+                    continue;
+                }
+
+                let i = (y * control.width + x) as usize * samples;
+                let pixel = &buffer[i..i + samples];
+
+                let color = match color_type {
+                    png::ColorType::Grayscale => {
+                        [pixel[0], pixel[0], pixel[0], 255]
+                    }
+                    png::ColorType::GrayscaleAlpha => {
+                        [pixel[0], pixel[0], pixel[0], pixel[1]]
+                    }
+                    png::ColorType::RGB => [pixel[0], pixel[1], pixel[2], 255],
+                    png::ColorType::RGBA => {
+                        [pixel[0], pixel[1], pixel[2], pixel[3]]
+                    }
+                    // Palettes are expanded by the decoder
+                    png::ColorType::Indexed => return Err(Error::Invalid),
+                };
+
+                let target = canvas.get_pixel_mut(canvas_x, canvas_y);
+
+                *target = match control.blend_op {
+                    png::BlendOp::Source => ::image::Rgba(color),
+                    png::BlendOp::Over => blend(color, target.0),
+                };
+            }
+        }
+
+        // A denominator of 0 means hundredths of a second
+        let denominator = match control.delay_den {
+            0 => 100,
+            denominator => u64::from(denominator),
+        };
+
+        frames.push(Frame {
+            image: ::image::DynamicImage::ImageRgba8(canvas.clone()).to_bgra(),
+            delay: Duration::from_millis(
+                1000 * u64::from(control.delay_num) / denominator,
+            ),
+        });
+
+        match control.dispose_op {
+            png::DisposeOp::None => {}
+            png::DisposeOp::Background => {
+                for y in 0..control.height {
+                    for x in 0..control.width {
+                        let (canvas_x, canvas_y) =
+                            (control.x_offset + x, control.y_offset + y);
+
+                        if canvas_x < info.width && canvas_y < info.height {
+                            canvas.put_pixel(
+                                canvas_x,
+                                canvas_y,
+                                ::image::Rgba([0, 0, 0, 0]),
+                            );
+                        }
+                    }
+                }
+            }
+            png::DisposeOp::Previous => {
+                if let Some(previous) = previous {
+                    canvas = previous;
+                }
+            }
+        }
+    }
+
+    Ok(frames)
+}
+
+/// Composes a non-premultiplied RGBA color over another one.
+fn blend(source: [u8; 4], destination: [u8; 4]) -> ::image::Rgba<u8> {
+    let source_alpha = f32::from(source[3]) / 255.0;
+    let destination_alpha =
+        f32::from(destination[3]) / 255.0 * (1.0 - source_alpha);
+    let alpha = source_alpha + destination_alpha;
+
+    if alpha <= 0.0 {
+        return ::image::Rgba([0, 0, 0, 0]);
+    }
+
+    let channel = |i: usize| {
+        ((f32::from(source[i]) * source_alpha
+            + f32::from(destination[i]) * destination_alpha)
+            / alpha)
+            .round() as u8
+    };
+
+    ::image::Rgba([
+        channel(0),
+        channel(1),
+        channel(2),
+        (alpha * 255.0).round() as u8,
+    ])
+}
@@ -0,0 +1,564 @@
+use crate::image::{
+    atlas::{self, Atlas},
+    decoder::{self, Decoder},
+};
+use iced_native::image;
+
+use std::{
+    collections::HashMap,
+    rc::Rc,
+    time::{Duration, Instant},
+};
+
+#[derive(Debug)]
+pub enum Memory {
+    Loading,
+    Host {
+        frames: Vec<decoder::Frame>,
+    },
+    Device {
+        frames: Vec<Texture>,
+        delays: Vec<Duration>,
+        width: u32,
+        height: u32,
+    },
+    Evicted {
+        width: u32,
+        height: u32,
+        is_reloading: bool,
+    },
+    NotFound,
+    Invalid,
+}
+
+impl Memory {
+    pub fn dimensions(&self) -> (u32, u32) {
+        match self {
+            Memory::Loading => (1, 1),
+            Memory::Host { frames } => frames[0].image.dimensions(),
+            Memory::Device { width, height, .. } => (*width, *height),
+            Memory::Evicted { width, height, .. } => (*width, *height),
+            Memory::NotFound => (1, 1),
+            Memory::Invalid => (1, 1),
+        }
+    }
+
+    /// Returns the amount of memory taken by the decoded image, in bytes.
+    fn size(&self) -> usize {
+        match self {
+            Memory::Host { frames } => frames
+                .iter()
+                .map(|frame| {
+                    let (width, height) = frame.image.dimensions();
+
+                    4 * width as usize * height as usize
+                })
+                .sum(),
+            Memory::Device {
+                frames,
+                width,
+                height,
+                ..
+            } => 4 * frames.len() * *width as usize * *height as usize,
+            _ => 0,
+        }
+    }
+
+    fn is_in_atlas(&self) -> bool {
+        match self {
+            Memory::Device { frames, .. } => frames.iter().any(|frame| {
+                if let Texture::Atlas(_) = frame {
+                    true
+                } else {
+                    false
+                }
+            }),
+            _ => false,
+        }
+    }
+}
+
+/// Where an uploaded image is stored in the GPU.
+#[derive(Debug, Clone)]
+pub enum Texture {
+    Atlas(atlas::Allocation),
+    Standalone(Rc<wgpu::BindGroup>),
+}
+
+#[derive(Debug)]
+struct Entry {
+    memory: Memory,
+    last_used: u64,
+    last_drawn: u64,
+    measured_while_loading: bool,
+    timeline: Timeline,
+}
+
+/// The playback state of an animated image.
+#[derive(Debug, Default)]
+struct Timeline {
+    frame: usize,
+    elapsed: Duration,
+    last_tick: Option<Instant>,
+}
+
+impl Timeline {
+    /// Advances the animation to the given instant and returns when its next
+    /// frame is due, if it is playing.
+    ///
+    /// The animation only advances if it was also drawn during the previous
+    /// frame. Thus, off-screen animations are paused.
+    fn advance(
+        &mut self,
+        delays: &[Duration],
+        playback: image::Playback,
+        was_drawn: bool,
+        now: Instant,
+    ) -> Option<Instant> {
+        let last_tick = self.last_tick.replace(now);
+
+        if delays.len() < 2 || !playback.playing {
+            return None;
+        }
+
+        if let (true, Some(last_tick)) = (was_drawn, last_tick) {
+            self.elapsed += now - last_tick;
+        }
+
+        loop {
+            // Like browsers do, tiny delays are slowed down
+            let delay = if delays[self.frame] <= Duration::from_millis(10) {
+                Duration::from_millis(100)
+            } else {
+                delays[self.frame]
+            };
+
+            if self.elapsed < delay {
+                return Some(now + (delay - self.elapsed));
+            }
+
+            if self.frame + 1 == delays.len() && !playback.looping {
+                self.elapsed = delay;
+
+                return None;
+            }
+
+            self.elapsed -= delay;
+            self.frame = (self.frame + 1) % delays.len();
+        }
+    }
+}
+
+/// A cache of raster images.
+///
+/// Images are decoded in the background and they are not drawn until they
+/// are ready. If the dimensions of an image were needed while it was loading,
+/// the layout is marked as stale once it finishes.
+///
+/// Small images are packed in a shared [`Atlas`], while bigger ones get their
+/// own texture. When the decoded images exceed the memory budget, the least
+/// recently used ones are evicted on `trim`. Decoded images that are not
+/// drawn are evicted after a few frames as well. Evicted images keep their
+/// dimensions, so the layout does not change, and they are loaded again the
+/// next time they are drawn.
+///
+/// Images that are not used for a while are dropped from the cache.
+///
+/// [`Atlas`]: ../atlas/struct.Atlas.html
+#[derive(Debug)]
+pub struct Cache {
+    entries: HashMap<u64, Entry>,
+    atlas: Atlas,
+    decoder: Decoder,
+    loading: usize,
+    stale_layout: bool,
+    budget: usize,
+    frame: u64,
+}
+
+impl Cache {
+    pub fn new(
+        device: &wgpu::Device,
+        texture_layout: &wgpu::BindGroupLayout,
+        budget: usize,
+    ) -> Self {
+        Cache {
+            entries: HashMap::new(),
+            atlas: Atlas::new(device, texture_layout),
+            decoder: Decoder::new(),
+            loading: 0,
+            stale_layout: false,
+            budget,
+            frame: 0,
+        }
+    }
+
+    pub fn atlas(&self) -> &Atlas {
+        &self.atlas
+    }
+
+    pub fn load(&mut self, handle: &image::Handle) -> &Memory {
+        let frame = self.frame;
+        let decoder = &self.decoder;
+        let loading = &mut self.loading;
+
+        let entry = self.entries.entry(handle.id()).or_insert_with(|| {
+            decoder.decode(handle);
+            *loading += 1;
+
+            Entry {
+                memory: Memory::Loading,
+                last_used: frame,
+                last_drawn: frame,
+                measured_while_loading: false,
+                timeline: Timeline::default(),
+            }
+        });
+
+        entry.last_used = frame;
+
+        &entry.memory
+    }
+
+    pub fn dimensions(&mut self, handle: &image::Handle) -> (u32, u32) {
+        let dimensions = self.load(handle).dimensions();
+
+        if let Some(entry) = self.entries.get_mut(&handle.id()) {
+            if let Memory::Loading = entry.memory {
+                entry.measured_while_loading = true;
+            }
+        }
+
+        dimensions
+    }
+
+    /// Stores the images decoded in the background since the last call.
+    pub fn poll(&mut self) {
+        for (id, result) in self.decoder.try_iter() {
+            self.loading -= 1;
+
+            if let Some(entry) = self.entries.get_mut(&id) {
+                entry.memory = match result {
+                    Ok(frames) if frames.is_empty() => Memory::Invalid,
+                    Ok(frames) => Memory::Host { frames },
+                    Err(decoder::Error::NotFound) => Memory::NotFound,
+                    Err(decoder::Error::Invalid) => Memory::Invalid,
+                };
+
+                if entry.measured_while_loading {
+                    entry.measured_while_loading = false;
+                    self.stale_layout = true;
+                }
+            }
+        }
+    }
+
+    /// Returns whether some images are still being decoded.
+    pub fn is_loading(&self) -> bool {
+        self.loading > 0
+    }
+
+    /// Returns whether the dimensions of some image changed since the last
+    /// layout, and clears the flag.
+    pub fn take_stale_layout(&mut self) -> bool {
+        std::mem::replace(&mut self.stale_layout, false)
+    }
+
+    /// Uploads the given image to the GPU, if needed, and returns the
+    /// texture of the frame to draw.
+    ///
+    /// If the image is animated, it also returns when its next frame is due.
+    pub fn upload(
+        &mut self,
+        handle: &image::Handle,
+        playback: image::Playback,
+        now: Instant,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        texture_layout: &wgpu::BindGroupLayout,
+    ) -> Option<(Texture, Option<Instant>)> {
+        let id = handle.id();
+        let frame = self.frame;
+        let _ = self.load(handle);
+
+        let entry = self.entries.get_mut(&id)?;
+        let was_drawn = entry.last_drawn + 1 == frame;
+        entry.last_drawn = frame;
+
+        if let Memory::Evicted { is_reloading, .. } = &mut entry.memory {
+            if !*is_reloading {
+                *is_reloading = true;
+
+                self.decoder.decode(handle);
+                self.loading += 1;
+            }
+        }
+
+        if let Memory::Host { .. } = entry.memory {
+            if let Memory::Host { frames } =
+                std::mem::replace(&mut entry.memory, Memory::Loading)
+            {
+                let memory =
+                    self.upload_frames(frames, device, encoder, texture_layout);
+
+                self.entries.get_mut(&id)?.memory = memory;
+            }
+        }
+
+        let Entry {
+            memory, timeline, ..
+        } = self.entries.get_mut(&id)?;
+
+        match memory {
+            Memory::Device { frames, delays, .. } => {
+                let next_frame =
+                    timeline.advance(delays, playback, was_drawn, now);
+
+                Some((frames[timeline.frame].clone(), next_frame))
+            }
+            _ => None,
+        }
+    }
+
+    /// Drops the images that have not been used for a while, evicts the
+    /// decoded images that are not drawn and the least recently used images
+    /// until the memory budget is respected, and starts a new frame.
+    ///
+    /// Images used during the current frame are never evicted.
+    pub fn trim(&mut self) {
+        let frame = self.frame;
+
+        let unused: Vec<u64> = self
+            .entries
+            .iter()
+            .filter(|(_, entry)| frame - entry.last_used > MAX_UNUSED_FRAMES)
+            .map(|(id, _)| *id)
+            .collect();
+
+        for id in unused {
+            self.evict(id);
+            let _ = self.entries.remove(&id);
+        }
+
+        let undrawn: Vec<u64> = self
+            .entries
+            .iter()
+            .filter(|(_, entry)| match entry.memory {
+                Memory::Host { .. } => {
+                    frame - entry.last_drawn > MAX_UNDRAWN_FRAMES
+                }
+                _ => false,
+            })
+            .map(|(id, _)| *id)
+            .collect();
+
+        for id in undrawn {
+            self.evict(id);
+        }
+
+        let mut usage: usize =
+            self.entries.values().map(|entry| entry.memory.size()).sum();
+
+        if usage > self.budget {
+            let mut candidates: Vec<(u64, u64)> = self
+                .entries
+                .iter()
+                .filter(|(_, entry)| {
+                    entry.last_used < frame && entry.memory.size() > 0
+                })
+                .map(|(id, entry)| (entry.last_used, *id))
+                .collect();
+
+            candidates.sort();
+
+            for (_, id) in candidates {
+                if usage <= self.budget {
+                    break;
+                }
+
+                usage -= self.entries[&id].memory.size();
+                self.evict(id);
+            }
+        }
+
+        self.frame += 1;
+    }
+
+    fn upload_frames(
+        &mut self,
+        frames: Vec<decoder::Frame>,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        texture_layout: &wgpu::BindGroupLayout,
+    ) -> Memory {
+        let (width, height) = frames[0].image.dimensions();
+
+        let mut textures = Vec::with_capacity(frames.len());
+        let mut delays = Vec::with_capacity(frames.len());
+
+        for frame in frames {
+            let allocation = if Atlas::fits(width, height) {
+                self.allocate(width, height)
+            } else {
+                None
+            };
+
+            textures.push(if let Some(allocation) = allocation {
+                self.atlas
+                    .upload(&allocation, &frame.image, device, encoder);
+
+                Texture::Atlas(allocation)
+            } else {
+                Texture::Standalone(Rc::new(upload(
+                    &frame.image,
+                    device,
+                    encoder,
+                    texture_layout,
+                )))
+            });
+
+            delays.push(frame.delay);
+        }
+
+        Memory::Device {
+            frames: textures,
+            delays,
+            width,
+            height,
+        }
+    }
+
+    fn allocate(
+        &mut self,
+        width: u32,
+        height: u32,
+    ) -> Option<atlas::Allocation> {
+        loop {
+            if let Some(allocation) = self.atlas.allocate(width, height) {
+                return Some(allocation);
+            }
+
+            // Make room by evicting the least recently drawn image in the
+            // atlas, unless it is being drawn in the current frame
+            let frame = self.frame;
+
+            let (id, _) = self
+                .entries
+                .iter()
+                .filter(|(_, entry)| {
+                    entry.last_drawn < frame && entry.memory.is_in_atlas()
+                })
+                .min_by_key(|(_, entry)| entry.last_drawn)?;
+
+            let id = *id;
+            self.evict(id);
+        }
+    }
+
+    /// Releases the memory of the given decoded image, keeping its
+    /// dimensions.
+    fn evict(&mut self, id: u64) {
+        let entry = match self.entries.get_mut(&id) {
+            Some(entry) => entry,
+            None => return,
+        };
+
+        if let Memory::Host { .. } = entry.memory {
+            let (width, height) = entry.memory.dimensions();
+
+            entry.memory = Memory::Evicted {
+                width,
+                height,
+                is_reloading: false,
+            };
+        }
+
+        if let Memory::Device { width, height, .. } = entry.memory {
+            let memory = std::mem::replace(
+                &mut entry.memory,
+                Memory::Evicted {
+                    width,
+                    height,
+                    is_reloading: false,
+                },
+            );
+
+            if let Memory::Device { frames, .. } = memory {
+                for frame in frames {
+                    if let Texture::Atlas(allocation) = frame {
+                        self.atlas.deallocate(&allocation);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// The amount of frames after which an image that is not used anymore is
+/// dropped from the cache.
+const MAX_UNUSED_FRAMES: u64 = 600;
+
+/// The amount of frames after which a decoded image that is not drawn is
+/// evicted, even if the memory budget is respected.
+const MAX_UNDRAWN_FRAMES: u64 = 60;
+
+fn upload(
+    image: &decoder::Bgra,
+    device: &wgpu::Device,
+    encoder: &mut wgpu::CommandEncoder,
+    texture_layout: &wgpu::BindGroupLayout,
+) -> wgpu::BindGroup {
+    let (width, height) = image.dimensions();
+
+    let extent = wgpu::Extent3d {
+        width,
+        height,
+        depth: 1,
+    };
+
+    let texture = device.create_texture(&wgpu::TextureDescriptor {
+        size: extent,
+        array_layer_count: 1,
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format: wgpu::TextureFormat::Bgra8UnormSrgb,
+        usage: wgpu::TextureUsage::COPY_DST | wgpu::TextureUsage::SAMPLED,
+    });
+
+    let slice = image.as_raw();
+
+    let temp_buf = device
+        .create_buffer_mapped(slice.len(), wgpu::BufferUsage::COPY_SRC)
+        .fill_from_slice(&slice[..]);
+
+    encoder.copy_buffer_to_texture(
+        wgpu::BufferCopyView {
+            buffer: &temp_buf,
+            offset: 0,
+            row_pitch: 4 * width,
+            image_height: height,
+        },
+        wgpu::TextureCopyView {
+            texture: &texture,
+            array_layer: 0,
+            mip_level: 0,
+            origin: wgpu::Origin3d {
+                x: 0.0,
+                y: 0.0,
+                z: 0.0,
+            },
+        },
+        extent,
+    );
+
+    device.create_bind_group(&wgpu::BindGroupDescriptor {
+        layout: texture_layout,
+        bindings: &[wgpu::Binding {
+            binding: 0,
+            resource: wgpu::BindingResource::TextureView(
+                &texture.create_default_view(),
+            ),
+        }],
+    })
+}
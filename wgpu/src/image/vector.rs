@@ -0,0 +1,186 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+    rc::Rc,
+};
+
+pub enum Svg {
+    Loaded { tree: resvg::usvg::Tree },
+    NotFound,
+}
+
+impl Svg {
+    pub fn viewport_dimensions(&self) -> (u32, u32) {
+        match self {
+            Svg::Loaded { tree } => {
+                let size = tree.svg_node().size;
+
+                (size.width() as u32, size.height() as u32)
+            }
+            Svg::NotFound => (1, 1),
+        }
+    }
+}
+
+impl fmt::Debug for Svg {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Svg::Loaded { .. } => write!(f, "Svg::Loaded"),
+            Svg::NotFound => write!(f, "Svg::NotFound"),
+        }
+    }
+}
+
+/// A cache of parsed vector images and their rasterizations.
+///
+/// Every vector image is rasterized at the exact physical size it is drawn
+/// with, and the rasterizations that were not drawn during the last frame
+/// are dropped on `trim`.
+#[derive(Debug)]
+pub struct Cache {
+    svgs: HashMap<String, Svg>,
+    rasterized: HashMap<(String, u32, u32), Rc<wgpu::BindGroup>>,
+    svg_hits: HashSet<String>,
+    rasterized_hits: HashSet<(String, u32, u32)>,
+}
+
+impl Cache {
+    pub fn new() -> Self {
+        Cache {
+            svgs: HashMap::new(),
+            rasterized: HashMap::new(),
+            svg_hits: HashSet::new(),
+            rasterized_hits: HashSet::new(),
+        }
+    }
+
+    pub fn load(&mut self, path: &str) -> &Svg {
+        if !self.svgs.contains_key(path) {
+            let options = resvg::Options::default();
+
+            let svg = match resvg::usvg::Tree::from_file(path, &options.usvg) {
+                Ok(tree) => Svg::Loaded { tree },
+                Err(_) => Svg::NotFound,
+            };
+
+            let _ = self.svgs.insert(path.to_string(), svg);
+        }
+
+        let _ = self.svg_hits.insert(path.to_string());
+
+        self.svgs.get(path).unwrap()
+    }
+
+    pub fn upload(
+        &mut self,
+        path: &str,
+        [width, height]: [u32; 2],
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        texture_layout: &wgpu::BindGroupLayout,
+    ) -> Option<Rc<wgpu::BindGroup>> {
+        let key = (path.to_string(), width, height);
+
+        if let Some(bind_group) = self.rasterized.get(&key) {
+            let _ = self.svg_hits.insert(path.to_string());
+            let _ = self.rasterized_hits.insert(key);
+
+            return Some(bind_group.clone());
+        }
+
+        if width == 0 || height == 0 {
+            return None;
+        }
+
+        let bind_group = match self.load(path) {
+            Svg::Loaded { tree } => {
+                let extent = wgpu::Extent3d {
+                    width,
+                    height,
+                    depth: 1,
+                };
+
+                let texture = device.create_texture(&wgpu::TextureDescriptor {
+                    size: extent,
+                    array_layer_count: 1,
+                    mip_level_count: 1,
+                    sample_count: 1,
+                    dimension: wgpu::TextureDimension::D2,
+                    format: wgpu::TextureFormat::Bgra8UnormSrgb,
+                    usage: wgpu::TextureUsage::COPY_DST
+                        | wgpu::TextureUsage::SAMPLED,
+                });
+
+                let mut canvas =
+                    resvg::raqote::DrawTarget::new(width as i32, height as i32);
+
+                resvg::backend_raqote::render_to_canvas(
+                    tree,
+                    &resvg::Options::default(),
+                    resvg::ScreenSize::new(width, height)?,
+                    &mut canvas,
+                );
+
+                let slice = canvas.get_data();
+
+                let temp_buf = device
+                    .create_buffer_mapped(
+                        slice.len(),
+                        wgpu::BufferUsage::COPY_SRC,
+                    )
+                    .fill_from_slice(slice);
+
+                encoder.copy_buffer_to_texture(
+                    wgpu::BufferCopyView {
+                        buffer: &temp_buf,
+                        offset: 0,
+                        row_pitch: 4 * width,
+                        image_height: height,
+                    },
+                    wgpu::TextureCopyView {
+                        texture: &texture,
+                        array_layer: 0,
+                        mip_level: 0,
+                        origin: wgpu::Origin3d {
+                            x: 0.0,
+                            y: 0.0,
+                            z: 0.0,
+                        },
+                    },
+                    extent,
+                );
+
+                let bind_group =
+                    device.create_bind_group(&wgpu::BindGroupDescriptor {
+                        layout: texture_layout,
+                        bindings: &[wgpu::Binding {
+                            binding: 0,
+                            resource: wgpu::BindingResource::TextureView(
+                                &texture.create_default_view(),
+                            ),
+                        }],
+                    });
+
+                Rc::new(bind_group)
+            }
+            Svg::NotFound => return None,
+        };
+
+        let _ = self.rasterized.insert(key.clone(), bind_group.clone());
+        let _ = self.rasterized_hits.insert(key);
+
+        Some(bind_group)
+    }
+
+    pub fn trim(&mut self) {
+        let svg_hits = &self.svg_hits;
+        let rasterized_hits = &self.rasterized_hits;
+
+        self.svgs.retain(|path, _| svg_hits.contains(path));
+        self.rasterized
+            .retain(|key, _| rasterized_hits.contains(key));
+
+        self.svg_hits.clear();
+        self.rasterized_hits.clear();
+    }
+}
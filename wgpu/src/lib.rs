@@ -11,7 +11,11 @@
 //! Currently, `iced_wgpu` supports the following primitives:
 //! - Text, which is rendered using [`wgpu_glyph`]. No shaping at all.
 //! - Quads or rectangles, with rounded borders and a solid background color.
-//! - Images, lazily loaded from the filesystem.
+//! - Images, lazily decoded in the background. Small images are packed in a
+//!   texture atlas and drawn in batches. Animated GIF and APNG files are
+//!   played while they are visible.
+//! - Vector images, rasterized with [`resvg`] at their exact physical size.
+//!   It needs the `svg` feature.
 //! - Clip areas, useful to implement scrollables or hide overflowing content.
 //!
 //! [Iced]: https://github.com/hecrj/iced
@@ -19,6 +23,7 @@
 //! [`wgpu`]: https://github.com/gfx-rs/wgpu-rs
 //! [WebGPU API]: https://gpuweb.github.io/gpuweb/
 //! [`wgpu_glyph`]: https://github.com/hecrj/wgpu_glyph
+//! [`resvg`]: https://github.com/RazrFalcon/resvg
 #![deny(missing_docs)]
 #![deny(missing_debug_implementations)]
 #![deny(unused_results)]
@@ -28,6 +33,7 @@ mod image;
 mod primitive;
 mod quad;
 mod renderer;
+mod settings;
 mod text;
 mod transformation;
 
@@ -35,5 +41,6 @@ pub(crate) use crate::image::Image;
 pub(crate) use quad::Quad;
 pub(crate) use transformation::Transformation;
 
-pub use primitive::Primitive;
+pub use primitive::{Primitive, Span};
 pub use renderer::{Renderer, Target};
+pub use settings::Settings;
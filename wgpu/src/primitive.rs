@@ -1,6 +1,6 @@
 use iced_native::{
-    Background, Color, Font, HorizontalAlignment, Rectangle, Vector,
-    VerticalAlignment,
+    image, text, Background, Color, Font, HorizontalAlignment, Rectangle,
+    Vector, VerticalAlignment, Weight,
 };
 
 /// A rendering primitive.
@@ -13,6 +13,11 @@ pub enum Primitive {
         /// The primitives of the group
         primitives: Vec<Primitive>,
     },
+    /// A stack of primitives, each one drawn on top of the previous ones
+    Stack {
+        /// The primitives of the stack, from bottom to top
+        primitives: Vec<Primitive>,
+    },
     /// A text primitive
     Text {
         /// The contents of the text
@@ -25,11 +30,22 @@ pub enum Primitive {
         size: f32,
         /// The font of the text
         font: Font,
+        /// The format of the text
+        format: text::Format,
         /// The horizontal alignment of the text
         horizontal_alignment: HorizontalAlignment,
         /// The vertical alignment of the text
         vertical_alignment: VerticalAlignment,
     },
+    /// A paragraph of text made of multiple spans
+    RichText {
+        /// The spans of the paragraph
+        spans: Vec<Span>,
+        /// The bounds of the paragraph
+        bounds: Rectangle,
+        /// The horizontal alignment of the paragraph
+        horizontal_alignment: HorizontalAlignment,
+    },
     /// A quad primitive
     Quad {
         /// The bounds of the quad
@@ -41,10 +57,19 @@ pub enum Primitive {
     },
     /// An image primitive
     Image {
-        /// The path of the image
-        path: String,
+        /// The handle of the image
+        handle: image::Handle,
         /// The bounds of the image
         bounds: Rectangle,
+        /// The playback of the image, if it is animated
+        playback: image::Playback,
+    },
+    /// A vector image primitive
+    Svg {
+        /// The path of the vector image
+        path: String,
+        /// The bounds of the vector image
+        bounds: Rectangle,
     },
     /// A clip primitive
     Clip {
@@ -56,3 +81,20 @@ pub enum Primitive {
         content: Box<Primitive>,
     },
 }
+
+/// A span of text inside a [`Primitive::RichText`].
+///
+/// [`Primitive::RichText`]: enum.Primitive.html#variant.RichText
+#[derive(Debug, Clone)]
+pub struct Span {
+    /// The contents of the span
+    pub content: String,
+    /// The color of the span
+    pub color: Color,
+    /// The size of the span
+    pub size: f32,
+    /// The font of the span
+    pub font: Font,
+    /// The weight of the span
+    pub weight: Weight,
+}
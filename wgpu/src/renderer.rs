@@ -1,9 +1,13 @@
-use crate::{quad, text, Image, Primitive, Quad, Transformation};
+use crate::{
+    image, quad, text, Image, Primitive, Quad, Settings, Transformation,
+};
 use iced_native::{
+    font,
     renderer::{Debugger, Windowed},
     Background, Color, Layout, MouseCursor, Point, Rectangle, Vector, Widget,
 };
 
+use std::time::{Duration, Instant};
 use wgpu::{
     Adapter, BackendBit, CommandEncoderDescriptor, Device, DeviceDescriptor,
     Extensions, Limits, PowerPreference, Queue, RequestAdapterOptions,
@@ -14,6 +18,15 @@ mod widget;
 
 pub use target::Target;
 
+/// How often a new frame is drawn while images are being decoded.
+///
+/// Polling is deliberate. The decoder runs on its own threads and the
+/// renderer has no way to wake up the event loop of the shell, as an event
+/// loop proxy can only deliver application messages. The loop only wakes up
+/// while some decode is pending, and decoding rarely takes more than a few
+/// frames.
+const IMAGE_LOADING_INTERVAL: Duration = Duration::from_millis(50);
+
 /// A [`wgpu`] renderer.
 ///
 /// [`wgpu`]: https://github.com/gfx-rs/wgpu-rs
@@ -24,6 +37,7 @@ pub struct Renderer {
     quad_pipeline: quad::Pipeline,
     image_pipeline: crate::image::Pipeline,
     text_pipeline: text::Pipeline,
+    redraw_request: Option<Instant>,
 }
 
 struct Layer<'a> {
@@ -31,7 +45,7 @@ struct Layer<'a> {
     offset: Vector<u32>,
     quads: Vec<Quad>,
     images: Vec<Image>,
-    text: Vec<wgpu_glyph::Section<'a>>,
+    text: Vec<(wgpu_glyph::VariedSection<'a>, text::Layout)>,
 }
 
 impl<'a> Layer<'a> {
@@ -47,7 +61,7 @@ impl<'a> Layer<'a> {
 }
 
 impl Renderer {
-    fn new() -> Self {
+    fn new(settings: Settings) -> Self {
         let adapter = Adapter::request(&RequestAdapterOptions {
             power_preference: PowerPreference::LowPower,
             backends: BackendBit::all(),
@@ -63,7 +77,10 @@ impl Renderer {
 
         let text_pipeline = text::Pipeline::new(&mut device);
         let quad_pipeline = quad::Pipeline::new(&mut device);
-        let image_pipeline = crate::image::Pipeline::new(&mut device);
+        let image_pipeline = crate::image::Pipeline::new(
+            &mut device,
+            settings.image_cache_budget,
+        );
 
         Self {
             device,
@@ -71,6 +88,7 @@ impl Renderer {
             quad_pipeline,
             image_pipeline,
             text_pipeline,
+            redraw_request: None,
         }
     }
 
@@ -128,6 +146,21 @@ impl Renderer {
 
         self.queue.submit(&[encoder.finish()]);
 
+        self.image_pipeline.trim_cache();
+
+        // Keep drawing until the images being decoded in the background are
+        // ready
+        if self.image_pipeline.is_loading() {
+            iced_native::Renderer::request_redraw(
+                self,
+                Instant::now() + IMAGE_LOADING_INTERVAL,
+            );
+        }
+
+        if let Some(next_frame) = self.image_pipeline.take_redraw_request() {
+            iced_native::Renderer::request_redraw(self, next_frame);
+        }
+
         *mouse_cursor
     }
 
@@ -146,12 +179,32 @@ impl Renderer {
                     self.draw_primitive(primitive, layers)
                 }
             }
+            Primitive::Stack { primitives } => {
+                let bounds = layer.bounds;
+                let offset = layer.offset;
+
+                // Quads, images and text are drawn in that order inside a
+                // layer, so every primitive of the stack needs its own
+                for (i, primitive) in primitives.iter().enumerate() {
+                    if i > 0 {
+                        layers.push(Layer::new(bounds, offset));
+                    }
+
+                    self.draw_primitive(primitive, layers);
+                }
+
+                // Anything drawn after the stack must stay on top of it
+                if primitives.len() > 1 {
+                    layers.push(Layer::new(bounds, offset));
+                }
+            }
             Primitive::Text {
                 content,
                 bounds,
                 size,
                 color,
                 font,
+                format,
                 horizontal_alignment,
                 vertical_alignment,
             } => {
@@ -175,41 +228,94 @@ impl Renderer {
                     }
                 };
 
-                layer.text.push(wgpu_glyph::Section {
-                    text: &content,
+                let layout = text::Layout::new(*format, *size)
+                    .h_align(match horizontal_alignment {
+                        iced_native::HorizontalAlignment::Left => {
+                            wgpu_glyph::HorizontalAlign::Left
+                        }
+                        iced_native::HorizontalAlignment::Center => {
+                            wgpu_glyph::HorizontalAlign::Center
+                        }
+                        iced_native::HorizontalAlignment::Right => {
+                            wgpu_glyph::HorizontalAlign::Right
+                        }
+                    })
+                    .v_align(match vertical_alignment {
+                        iced_native::VerticalAlignment::Top => {
+                            wgpu_glyph::VerticalAlign::Top
+                        }
+                        iced_native::VerticalAlignment::Center => {
+                            wgpu_glyph::VerticalAlign::Center
+                        }
+                        iced_native::VerticalAlignment::Bottom => {
+                            wgpu_glyph::VerticalAlign::Bottom
+                        }
+                    });
+
+                layer.text.push((
+                    wgpu_glyph::Section {
+                        text: &content,
+                        screen_position: (
+                            x - layer.offset.x as f32,
+                            y - layer.offset.y as f32,
+                        ),
+                        bounds: (bounds.width, bounds.height),
+                        scale: wgpu_glyph::Scale { x: *size, y: *size },
+                        color: color.into_linear(),
+                        font_id: self.text_pipeline.find_font(*font).draw,
+                        ..Default::default()
+                    }
+                    .into(),
+                    layout,
+                ))
+            }
+            Primitive::RichText {
+                spans,
+                bounds,
+                horizontal_alignment,
+            } => {
+                let (x, h_align) = match horizontal_alignment {
+                    iced_native::HorizontalAlignment::Left => {
+                        (bounds.x, wgpu_glyph::HorizontalAlign::Left)
+                    }
+                    iced_native::HorizontalAlignment::Center => (
+                        bounds.x + bounds.width / 2.0,
+                        wgpu_glyph::HorizontalAlign::Center,
+                    ),
+                    iced_native::HorizontalAlignment::Right => (
+                        bounds.x + bounds.width,
+                        wgpu_glyph::HorizontalAlign::Right,
+                    ),
+                };
+
+                let text_pipeline = &self.text_pipeline;
+
+                let section = wgpu_glyph::VariedSection {
                     screen_position: (
                         x - layer.offset.x as f32,
-                        y - layer.offset.y as f32,
+                        bounds.y - layer.offset.y as f32,
                     ),
                     bounds: (bounds.width, bounds.height),
-                    scale: wgpu_glyph::Scale { x: *size, y: *size },
-                    color: color.into_linear(),
-                    font_id: self.text_pipeline.find_font(*font),
-                    layout: wgpu_glyph::Layout::default()
-                        .h_align(match horizontal_alignment {
-                            iced_native::HorizontalAlignment::Left => {
-                                wgpu_glyph::HorizontalAlign::Left
-                            }
-                            iced_native::HorizontalAlignment::Center => {
-                                wgpu_glyph::HorizontalAlign::Center
-                            }
-                            iced_native::HorizontalAlignment::Right => {
-                                wgpu_glyph::HorizontalAlign::Right
-                            }
+                    text: spans
+                        .iter()
+                        .map(|span| wgpu_glyph::SectionText {
+                            text: &span.content,
+                            scale: wgpu_glyph::Scale {
+                                x: span.size,
+                                y: span.size,
+                            },
+                            color: span.color.into_linear(),
+                            font_id: text_pipeline
+                                .find_weighted_font(span.font, span.weight)
+                                .draw,
                         })
-                        .v_align(match vertical_alignment {
-                            iced_native::VerticalAlignment::Top => {
-                                wgpu_glyph::VerticalAlign::Top
-                            }
-                            iced_native::VerticalAlignment::Center => {
-                                wgpu_glyph::VerticalAlign::Center
-                            }
-                            iced_native::VerticalAlignment::Bottom => {
-                                wgpu_glyph::VerticalAlign::Bottom
-                            }
-                        }),
+                        .collect(),
                     ..Default::default()
-                })
+                };
+
+                layer
+                    .text
+                    .push((section, text::Layout::default().h_align(h_align)))
             }
             Primitive::Quad {
                 bounds,
@@ -229,9 +335,36 @@ impl Renderer {
                     border_radius: *border_radius as f32,
                 });
             }
-            Primitive::Image { path, bounds } => {
+            Primitive::Image {
+                handle,
+                bounds,
+                playback,
+            } => {
+                let x = bounds.x - layer.offset.x as f32;
+                let y = bounds.y - layer.offset.y as f32;
+
+                // Images outside of the layer are not drawn at all, so their
+                // animations do not keep requesting frames
+                let is_visible = x
+                    < (layer.bounds.x + layer.bounds.width) as f32
+                    && y < (layer.bounds.y + layer.bounds.height) as f32
+                    && x + bounds.width > layer.bounds.x as f32
+                    && y + bounds.height > layer.bounds.y as f32;
+
+                if is_visible {
+                    layer.images.push(Image {
+                        handle: image::Handle::Raster(
+                            handle.clone(),
+                            *playback,
+                        ),
+                        position: [bounds.x, bounds.y],
+                        scale: [bounds.width, bounds.height],
+                    });
+                }
+            }
+            Primitive::Svg { path, bounds } => {
                 layer.images.push(Image {
-                    path: path.clone(),
+                    handle: image::Handle::Vector(path.clone()),
                     position: [bounds.x, bounds.y],
                     scale: [bounds.width, bounds.height],
                 });
@@ -282,23 +415,31 @@ impl Renderer {
         let scale = wgpu_glyph::Scale { x: 20.0, y: 20.0 };
 
         for (i, line) in lines.iter().enumerate() {
-            overlay.text.push(wgpu_glyph::Section {
-                text: line.as_ref(),
-                screen_position: (11.0, 11.0 + 25.0 * i as f32),
-                color: [0.9, 0.9, 0.9, 1.0],
-                scale,
-                font_id,
-                ..wgpu_glyph::Section::default()
-            });
-
-            overlay.text.push(wgpu_glyph::Section {
-                text: line.as_ref(),
-                screen_position: (10.0, 10.0 + 25.0 * i as f32),
-                color: [0.0, 0.0, 0.0, 1.0],
-                scale,
-                font_id,
-                ..wgpu_glyph::Section::default()
-            });
+            overlay.text.push((
+                wgpu_glyph::Section {
+                    text: line.as_ref(),
+                    screen_position: (11.0, 11.0 + 25.0 * i as f32),
+                    color: [0.9, 0.9, 0.9, 1.0],
+                    scale,
+                    font_id,
+                    ..wgpu_glyph::Section::default()
+                }
+                .into(),
+                text::Layout::default(),
+            ));
+
+            overlay.text.push((
+                wgpu_glyph::Section {
+                    text: line.as_ref(),
+                    screen_position: (10.0, 10.0 + 25.0 * i as f32),
+                    color: [0.0, 0.0, 0.0, 1.0],
+                    scale,
+                    font_id,
+                    ..wgpu_glyph::Section::default()
+                }
+                .into(),
+                text::Layout::default(),
+            ));
         }
 
         layers.push(overlay);
@@ -339,15 +480,16 @@ impl Renderer {
                 encoder,
                 &layer.images,
                 translated_and_scaled,
+                dpi,
                 bounds,
                 target,
             );
         }
 
         if layer.text.len() > 0 {
-            for text in layer.text.iter() {
+            for (text, layout) in layer.text.iter() {
                 // Target physical coordinates directly to avoid blurry text
-                let text = wgpu_glyph::Section {
+                let text = wgpu_glyph::VariedSection {
                     // TODO: We `round` here to avoid rerasterizing text when
                     // its position changes slightly. This can make text feel a
                     // bit "jumpy". We may be able to do better once we improve
@@ -369,14 +511,21 @@ impl Renderer {
                         (text.bounds.0 * dpi).ceil(),
                         (text.bounds.1 * dpi).ceil(),
                     ),
-                    scale: wgpu_glyph::Scale {
-                        x: text.scale.x * dpi,
-                        y: text.scale.y * dpi,
-                    },
+                    text: text
+                        .text
+                        .iter()
+                        .map(|section| wgpu_glyph::SectionText {
+                            scale: wgpu_glyph::Scale {
+                                x: section.scale.x * dpi,
+                                y: section.scale.y * dpi,
+                            },
+                            ..*section
+                        })
+                        .collect(),
                     ..*text
                 };
 
-                self.text_pipeline.queue(text);
+                self.text_pipeline.queue(text, layout);
             }
 
             self.text_pipeline.draw_queued(
@@ -404,17 +553,29 @@ impl iced_native::Renderer for Renderer {
     ) -> iced_native::layout::Node {
         let node = element.layout(self, &iced_native::layout::Limits::NONE);
 
-        self.text_pipeline.clear_measurement_cache();
+        self.text_pipeline.trim_measurement_cache();
 
         node
     }
+
+    fn request_redraw(&mut self, at: Instant) {
+        self.redraw_request = Some(match self.redraw_request {
+            Some(current) => current.min(at),
+            None => at,
+        });
+    }
+
+    fn take_relayout_request(&mut self) -> bool {
+        self.image_pipeline.take_relayout_request()
+    }
 }
 
 impl Windowed for Renderer {
     type Target = Target;
+    type Settings = Settings;
 
-    fn new() -> Self {
-        Self::new()
+    fn new(settings: Settings) -> Self {
+        Self::new(settings)
     }
 
     fn draw<T: AsRef<str>>(
@@ -425,6 +586,23 @@ impl Windowed for Renderer {
     ) -> MouseCursor {
         self.draw(output, overlay, target)
     }
+
+    fn take_redraw_request(&mut self) -> Option<Instant> {
+        self.redraw_request.take()
+    }
+
+    fn load_font(&mut self, id: font::Id, name: String, bytes: Vec<u8>) {
+        self.text_pipeline.load_font(id, name, bytes);
+    }
+
+    fn debug_info(&self) -> Vec<String> {
+        let stats = self.text_pipeline.measurement_stats();
+
+        vec![format!(
+            "{: <30} {} hits, {} misses ({} cached)",
+            "Text measurements:", stats.hits, stats.misses, stats.entries
+        )]
+    }
 }
 
 impl Debugger for Renderer {
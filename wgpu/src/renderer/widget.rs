@@ -1,10 +1,22 @@
 mod button;
 mod checkbox;
 mod column;
+mod grid;
 mod image;
+mod modal;
+mod pane_grid;
 mod radio;
+mod rich_text;
 mod row;
+mod rule;
 mod scrollable;
+mod selectable_text;
 mod slider;
+mod space;
+mod stack;
+mod svg;
+mod table;
+mod tabs;
 mod text;
 mod text_input;
+mod tree;
@@ -1,6 +1,6 @@
 use crate::{Primitive, Renderer};
 use iced_native::{
-    checkbox, Background, HorizontalAlignment, MouseCursor, Rectangle,
+    checkbox, text, Background, HorizontalAlignment, MouseCursor, Rectangle,
     VerticalAlignment,
 };
 
@@ -49,6 +49,7 @@ impl checkbox::Renderer for Renderer {
                     let check = Primitive::Text {
                         content: crate::text::CHECKMARK_ICON.to_string(),
                         font: crate::text::BUILTIN_ICONS,
+                        format: text::Format::default(),
                         size: bounds.height * 0.7,
                         bounds: bounds,
                         color: [0.3, 0.3, 0.3].into(),
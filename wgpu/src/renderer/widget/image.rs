@@ -1,16 +1,40 @@
 use crate::{Primitive, Renderer};
-use iced_native::{image, Layout, MouseCursor};
+use iced_native::{image, Layout, MouseCursor, Rectangle, Vector};
 
 impl image::Renderer for Renderer {
-    fn dimensions(&self, path: &str) -> (u32, u32) {
-        self.image_pipeline.dimensions(path)
+    fn dimensions(&self, handle: &image::Handle) -> (u32, u32) {
+        self.image_pipeline.dimensions(handle)
     }
 
-    fn draw(&mut self, path: &str, layout: Layout<'_>) -> Self::Output {
+    fn draw(
+        &mut self,
+        handle: image::Handle,
+        bounds: Rectangle,
+        playback: image::Playback,
+        layout: Layout<'_>,
+    ) -> Self::Output {
+        let clip_bounds = layout.bounds();
+
+        let image = Primitive::Image {
+            handle,
+            bounds,
+            playback,
+        };
+
+        let is_overflowing = bounds.x < clip_bounds.x
+            || bounds.y < clip_bounds.y
+            || bounds.x + bounds.width > clip_bounds.x + clip_bounds.width
+            || bounds.y + bounds.height > clip_bounds.y + clip_bounds.height;
+
         (
-            Primitive::Image {
-                path: String::from(path),
-                bounds: layout.bounds(),
+            if is_overflowing {
+                Primitive::Clip {
+                    bounds: clip_bounds,
+                    offset: Vector::new(0, 0),
+                    content: Box::new(image),
+                }
+            } else {
+                image
             },
             MouseCursor::OutOfBounds,
         )
@@ -0,0 +1,34 @@
+use crate::{Primitive, Renderer};
+use iced_native::{modal, Background, MouseCursor, Rectangle, Vector};
+
+impl modal::Renderer for Renderer {
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        (base, _): Self::Output,
+        (dialog, mouse_cursor): Self::Output,
+    ) -> Self::Output {
+        let backdrop = Primitive::Quad {
+            bounds,
+            background: Background::Color([0.0, 0.0, 0.0, 0.5].into()),
+            border_radius: 0,
+        };
+
+        (
+            Primitive::Group {
+                primitives: vec![
+                    base,
+                    // Clipping pushes a new layer, drawn on top of the base
+                    Primitive::Clip {
+                        bounds,
+                        offset: Vector::new(0, 0),
+                        content: Box::new(Primitive::Group {
+                            primitives: vec![backdrop, dialog],
+                        }),
+                    },
+                ],
+            },
+            mouse_cursor.max(MouseCursor::Idle),
+        )
+    }
+}
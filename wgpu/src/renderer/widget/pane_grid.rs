@@ -0,0 +1,64 @@
+use crate::{Primitive, Renderer};
+use iced_native::{
+    pane_grid::{self, Axis, Pane},
+    Background, Element, Layout, MouseCursor, Point,
+};
+
+impl pane_grid::Renderer for Renderer {
+    fn draw<Message>(
+        &mut self,
+        content: &[(Pane, Element<'_, Message, Self>)],
+        dragging: Option<Pane>,
+        resizing: Option<Axis>,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Self::Output {
+        let mut mouse_cursor = MouseCursor::OutOfBounds;
+        let mut primitives = Vec::with_capacity(content.len());
+
+        for ((pane, element), layout) in content.iter().zip(layout.children()) {
+            let (primitive, new_mouse_cursor) =
+                element.draw(self, layout, cursor_position);
+
+            if new_mouse_cursor > mouse_cursor {
+                mouse_cursor = new_mouse_cursor;
+            }
+
+            primitives.push(primitive);
+
+            if let Some(dragging) = dragging {
+                let bounds = layout.bounds();
+
+                let overlay: Option<[f32; 4]> = if *pane == dragging {
+                    Some([0.0, 0.0, 0.0, 0.2])
+                } else if bounds.contains(cursor_position) {
+                    Some([0.3, 0.5, 0.9, 0.2])
+                } else {
+                    None
+                };
+
+                if let Some(color) = overlay {
+                    primitives.push(Primitive::Quad {
+                        bounds,
+                        background: Background::Color(color.into()),
+                        border_radius: 0,
+                    });
+                }
+            }
+        }
+
+        (
+            Primitive::Group { primitives },
+            if dragging.is_some() {
+                MouseCursor::Grabbing
+            } else if let Some(axis) = resizing {
+                match axis {
+                    Axis::Horizontal => MouseCursor::ResizingVertically,
+                    Axis::Vertical => MouseCursor::ResizingHorizontally,
+                }
+            } else {
+                mouse_cursor
+            },
+        )
+    }
+}
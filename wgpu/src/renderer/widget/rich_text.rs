@@ -0,0 +1,96 @@
+use crate::{Primitive, Renderer, Span};
+use iced_native::{
+    rich_text, text, Background, Color, HorizontalAlignment, MouseCursor,
+    Rectangle,
+};
+
+impl rich_text::Renderer for Renderer {
+    fn regions(
+        &self,
+        fragments: &[text::Fragment<'_>],
+        bounds: Rectangle,
+        horizontal_alignment: HorizontalAlignment,
+    ) -> Vec<Vec<Rectangle>> {
+        self.text_pipeline
+            .regions(fragments, bounds, horizontal_alignment)
+            .into_iter()
+            .map(|regions| {
+                regions.into_iter().map(|region| region.bounds).collect()
+            })
+            .collect()
+    }
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        fragments: &[text::Fragment<'_>],
+        styles: &[rich_text::Style],
+        horizontal_alignment: HorizontalAlignment,
+        is_mouse_over_link: bool,
+    ) -> Self::Output {
+        let regions =
+            self.text_pipeline
+                .regions(fragments, bounds, horizontal_alignment);
+
+        let mut primitives = Vec::new();
+
+        for (style, regions) in styles.iter().zip(regions.iter()) {
+            if let Some(background) = style.background {
+                primitives.extend(regions.iter().map(|region| {
+                    Primitive::Quad {
+                        bounds: region.bounds,
+                        background,
+                        border_radius: 0,
+                    }
+                }));
+            }
+        }
+
+        primitives.push(Primitive::RichText {
+            spans: fragments
+                .iter()
+                .zip(styles.iter())
+                .map(|(fragment, style)| Span {
+                    content: fragment.content.to_string(),
+                    color: style.color.unwrap_or(Color::BLACK),
+                    size: f32::from(fragment.size),
+                    font: fragment.font,
+                    weight: fragment.weight,
+                })
+                .collect(),
+            bounds,
+            horizontal_alignment,
+        });
+
+        for ((fragment, style), regions) in
+            fragments.iter().zip(styles.iter()).zip(regions.iter())
+        {
+            if style.underline {
+                let thickness = (f32::from(fragment.size) / 14.0).max(1.0);
+                let color = style.color.unwrap_or(Color::BLACK);
+
+                primitives.extend(regions.iter().map(|region| {
+                    Primitive::Quad {
+                        bounds: Rectangle {
+                            x: region.bounds.x,
+                            y: region.baseline + thickness,
+                            width: region.bounds.width,
+                            height: thickness,
+                        },
+                        background: Background::Color(color),
+                        border_radius: 0,
+                    }
+                }));
+            }
+        }
+
+        (
+            Primitive::Group { primitives },
+            if is_mouse_over_link {
+                MouseCursor::Pointer
+            } else {
+                MouseCursor::OutOfBounds
+            },
+        )
+    }
+}
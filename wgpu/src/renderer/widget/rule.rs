@@ -0,0 +1,20 @@
+use crate::{Primitive, Renderer};
+use iced_native::{rule, Background, Color, MouseCursor, Rectangle};
+
+impl rule::Renderer for Renderer {
+    fn draw(&mut self, _bounds: Rectangle, line: Rectangle) -> Self::Output {
+        (
+            Primitive::Quad {
+                bounds: line,
+                background: Background::Color(Color {
+                    r: 0.0,
+                    g: 0.0,
+                    b: 0.0,
+                    a: 0.2,
+                }),
+                border_radius: 0,
+            },
+            MouseCursor::OutOfBounds,
+        )
+    }
+}
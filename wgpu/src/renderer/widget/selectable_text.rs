@@ -0,0 +1,51 @@
+use crate::{Primitive, Renderer};
+use iced_native::{selectable_text, text, Background, Color, MouseCursor};
+
+impl selectable_text::Renderer for Renderer {
+    fn draw(
+        &mut self,
+        paragraph: &text::Paragraph<'_>,
+        color: Option<Color>,
+        selection: Option<(usize, usize)>,
+        is_mouse_over: bool,
+    ) -> Self::Output {
+        let mut primitives: Vec<Primitive> = match selection {
+            Some(range) => self
+                .text_pipeline
+                .selection(paragraph, range)
+                .into_iter()
+                .map(|bounds| Primitive::Quad {
+                    bounds,
+                    background: Background::Color(Color {
+                        r: 0.36,
+                        g: 0.55,
+                        b: 0.95,
+                        a: 0.35,
+                    }),
+                    border_radius: 0,
+                })
+                .collect(),
+            None => Vec::new(),
+        };
+
+        primitives.push(Primitive::Text {
+            content: paragraph.content.to_string(),
+            size: f32::from(paragraph.size),
+            bounds: paragraph.bounds,
+            color: color.unwrap_or(Color::BLACK),
+            font: paragraph.font,
+            format: paragraph.format,
+            horizontal_alignment: paragraph.horizontal_alignment,
+            vertical_alignment: paragraph.vertical_alignment,
+        });
+
+        (
+            Primitive::Group { primitives },
+            if is_mouse_over {
+                MouseCursor::Text
+            } else {
+                MouseCursor::OutOfBounds
+            },
+        )
+    }
+}
@@ -0,0 +1,8 @@
+use crate::{Primitive, Renderer};
+use iced_native::{space, Layout, MouseCursor};
+
+impl space::Renderer for Renderer {
+    fn draw(&mut self, _layout: Layout<'_>) -> Self::Output {
+        (Primitive::None, MouseCursor::OutOfBounds)
+    }
+}
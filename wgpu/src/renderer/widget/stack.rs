@@ -0,0 +1,49 @@
+use crate::{Primitive, Renderer};
+use iced_native::{stack, Element, Layout, MouseCursor, Point};
+
+impl stack::Renderer for Renderer {
+    fn draw<Message>(
+        &mut self,
+        content: &[Element<'_, Message, Self>],
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Self::Output {
+        // Only the topmost child under the cursor is hovered
+        let hovered = layout
+            .children()
+            .enumerate()
+            .filter(|(_, layout)| layout.bounds().contains(cursor_position))
+            .map(|(i, _)| i)
+            .last();
+
+        let mut mouse_cursor = MouseCursor::OutOfBounds;
+
+        (
+            Primitive::Stack {
+                primitives: content
+                    .iter()
+                    .zip(layout.children())
+                    .enumerate()
+                    .map(|(i, (child, layout))| {
+                        let cursor_position = match hovered {
+                            Some(hovered) if i < hovered => {
+                                Point::new(f32::NEG_INFINITY, f32::NEG_INFINITY)
+                            }
+                            _ => cursor_position,
+                        };
+
+                        let (primitive, new_mouse_cursor) =
+                            child.draw(self, layout, cursor_position);
+
+                        if Some(i) == hovered {
+                            mouse_cursor = new_mouse_cursor;
+                        }
+
+                        primitive
+                    })
+                    .collect(),
+            },
+            mouse_cursor,
+        )
+    }
+}
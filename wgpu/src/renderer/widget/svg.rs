@@ -0,0 +1,18 @@
+use crate::{Primitive, Renderer};
+use iced_native::{svg, Layout, MouseCursor};
+
+impl svg::Renderer for Renderer {
+    fn viewport_dimensions(&self, path: &str) -> (u32, u32) {
+        self.image_pipeline.viewport_dimensions(path)
+    }
+
+    fn draw(&mut self, path: &str, layout: Layout<'_>) -> Self::Output {
+        (
+            Primitive::Svg {
+                path: String::from(path),
+                bounds: layout.bounds(),
+            },
+            MouseCursor::OutOfBounds,
+        )
+    }
+}
@@ -0,0 +1,148 @@
+use crate::{Primitive, Renderer};
+use iced_native::{
+    table, text, Background, Color, Font, HorizontalAlignment, MouseCursor,
+    Rectangle, Vector, VerticalAlignment,
+};
+
+const PADDING: f32 = 8.0;
+const TITLE_SIZE: f32 = 18.0;
+
+impl table::Renderer for Renderer {
+    fn draw_rows(
+        &mut self,
+        rows: &[table::Row],
+        (cells, mouse_cursor): Self::Output,
+    ) -> Self::Output {
+        let mut primitives: Vec<Primitive> = rows
+            .iter()
+            .filter_map(|row| {
+                let background: Option<Color> = if row.is_selected {
+                    Some([0.8, 0.87, 1.0].into())
+                } else if row.is_hovered {
+                    Some([0.95, 0.95, 0.95].into())
+                } else {
+                    None
+                };
+
+                background.map(|background| Primitive::Quad {
+                    bounds: row.bounds,
+                    background: Background::Color(background),
+                    border_radius: 0,
+                })
+            })
+            .collect();
+
+        primitives.push(cells);
+
+        (Primitive::Group { primitives }, mouse_cursor)
+    }
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        header: &[table::Header<'_>],
+        is_resizing: bool,
+        (body, mouse_cursor): Self::Output,
+    ) -> Self::Output {
+        let header_bounds = Rectangle {
+            height: header
+                .first()
+                .map(|header| header.bounds.height)
+                .unwrap_or(0.0),
+            ..bounds
+        };
+
+        let mut primitives = vec![
+            Primitive::Quad {
+                bounds: header_bounds,
+                background: Background::Color([0.9, 0.9, 0.9].into()),
+                border_radius: 0,
+            },
+            Primitive::Quad {
+                bounds: Rectangle {
+                    y: header_bounds.y + header_bounds.height - 1.0,
+                    height: 1.0,
+                    ..header_bounds
+                },
+                background: Background::Color([0.7, 0.7, 0.7].into()),
+                border_radius: 0,
+            },
+        ];
+
+        let mut is_mouse_over_title = false;
+
+        for column in header {
+            if column.is_hovered {
+                is_mouse_over_title = true;
+
+                primitives.push(Primitive::Quad {
+                    bounds: column.bounds,
+                    background: Background::Color([0.85, 0.85, 0.85].into()),
+                    border_radius: 0,
+                });
+            }
+
+            let text_bounds = Rectangle {
+                x: column.bounds.x + PADDING,
+                width: (column.bounds.width - 2.0 * PADDING).max(0.0),
+                ..column.bounds
+            };
+
+            primitives.push(Primitive::Text {
+                content: column.title.to_string(),
+                bounds: text_bounds,
+                color: Color::BLACK,
+                size: TITLE_SIZE,
+                font: Font::Default,
+                format: text::Format::default(),
+                horizontal_alignment: HorizontalAlignment::Left,
+                vertical_alignment: VerticalAlignment::Center,
+            });
+
+            if let Some(sort) = column.sort {
+                primitives.push(Primitive::Text {
+                    content: match sort {
+                        table::Sort::Ascending => String::from("▲"),
+                        table::Sort::Descending => String::from("▼"),
+                    },
+                    bounds: text_bounds,
+                    color: [0.4, 0.4, 0.4].into(),
+                    size: TITLE_SIZE * 0.6,
+                    font: Font::Default,
+                    format: text::Format::default(),
+                    horizontal_alignment: HorizontalAlignment::Right,
+                    vertical_alignment: VerticalAlignment::Center,
+                });
+            }
+
+            primitives.push(Primitive::Quad {
+                bounds: Rectangle {
+                    x: column.bounds.x + column.bounds.width - 1.0,
+                    width: 1.0,
+                    ..column.bounds
+                },
+                background: Background::Color([0.7, 0.7, 0.7].into()),
+                border_radius: 0,
+            });
+        }
+
+        let header = Primitive::Clip {
+            bounds: header_bounds,
+            offset: Vector::new(0, 0),
+            content: Box::new(Primitive::Group { primitives }),
+        };
+
+        (
+            Primitive::Group {
+                primitives: vec![body, header],
+            },
+            if is_resizing {
+                MouseCursor::ResizingHorizontally
+            } else if is_mouse_over_title {
+                MouseCursor::Pointer
+            } else {
+                mouse_cursor
+            },
+        )
+    }
+}
@@ -0,0 +1,149 @@
+use crate::{Primitive, Renderer};
+use iced_native::{
+    tabs, text, Background, Color, Font, HorizontalAlignment, MouseCursor,
+    Rectangle, VerticalAlignment,
+};
+
+impl tabs::Renderer for Renderer {
+    fn draw(
+        &mut self,
+        bar_bounds: Rectangle,
+        headers: &[tabs::Header<'_>],
+        text_size: u16,
+        (content, mouse_cursor): Self::Output,
+    ) -> Self::Output {
+        let size = f32::from(text_size);
+
+        let mut primitives = vec![
+            content,
+            Primitive::Quad {
+                bounds: bar_bounds,
+                background: Background::Color([0.9, 0.9, 0.9].into()),
+                border_radius: 0,
+            },
+            Primitive::Quad {
+                bounds: Rectangle {
+                    y: bar_bounds.y + bar_bounds.height - 1.0,
+                    height: 1.0,
+                    ..bar_bounds
+                },
+                background: Background::Color([0.7, 0.7, 0.7].into()),
+                border_radius: 0,
+            },
+        ];
+
+        let mut is_mouse_over_tab = false;
+
+        for header in headers {
+            let bounds = header.bounds;
+
+            is_mouse_over_tab = is_mouse_over_tab || header.is_hovered;
+
+            let background: Option<Color> = if header.is_active {
+                Some(Color::WHITE)
+            } else if header.is_hovered {
+                Some([0.95, 0.95, 0.95].into())
+            } else {
+                None
+            };
+
+            if let Some(background) = background {
+                primitives.push(Primitive::Quad {
+                    bounds,
+                    background: Background::Color(background),
+                    border_radius: 0,
+                });
+            }
+
+            if header.is_active {
+                primitives.push(Primitive::Quad {
+                    bounds: Rectangle {
+                        y: bounds.y + bounds.height - 3.0,
+                        height: 3.0,
+                        ..bounds
+                    },
+                    background: Background::Color([0.3, 0.5, 0.9].into()),
+                    border_radius: 0,
+                });
+            }
+
+            let color = if header.is_active {
+                Color::BLACK
+            } else {
+                [0.3, 0.3, 0.3].into()
+            };
+
+            let padding = (bounds.height - size) / 2.0;
+            let spacing = padding / 2.0;
+            let mut x = bounds.x + padding;
+
+            if let Some((icon, font)) = header.icon {
+                primitives.push(Primitive::Text {
+                    content: icon.to_string(),
+                    bounds: Rectangle {
+                        x,
+                        width: size,
+                        ..bounds
+                    },
+                    color,
+                    size,
+                    font,
+                    format: text::Format::default(),
+                    horizontal_alignment: HorizontalAlignment::Center,
+                    vertical_alignment: VerticalAlignment::Center,
+                });
+
+                x += size + spacing;
+            }
+
+            if let Some(text) = header.text {
+                primitives.push(Primitive::Text {
+                    content: text.to_string(),
+                    bounds: Rectangle {
+                        x,
+                        width: bounds.x + bounds.width - x,
+                        ..bounds
+                    },
+                    color,
+                    size,
+                    font: Font::Default,
+                    format: text::Format::default(),
+                    horizontal_alignment: HorizontalAlignment::Left,
+                    vertical_alignment: VerticalAlignment::Center,
+                });
+            }
+
+            if let Some(close_bounds) = header.close_bounds {
+                if header.is_close_hovered {
+                    primitives.push(Primitive::Quad {
+                        bounds: close_bounds,
+                        background: Background::Color(
+                            [0.0, 0.0, 0.0, 0.1].into(),
+                        ),
+                        border_radius: 3,
+                    });
+                }
+
+                primitives.push(Primitive::Text {
+                    content: String::from("×"),
+                    bounds: close_bounds,
+                    color,
+                    size,
+                    font: Font::Default,
+                    format: text::Format::default(),
+                    horizontal_alignment: HorizontalAlignment::Center,
+                    vertical_alignment: VerticalAlignment::Center,
+                });
+            }
+        }
+
+        (
+            Primitive::Group { primitives },
+            if is_mouse_over_tab {
+                MouseCursor::Pointer
+            } else {
+                mouse_cursor
+            },
+        )
+    }
+}
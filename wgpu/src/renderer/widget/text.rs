@@ -1,7 +1,7 @@
 use crate::{Primitive, Renderer};
 use iced_native::{
-    text, Color, Font, HorizontalAlignment, MouseCursor, Rectangle, Size,
-    VerticalAlignment,
+    text, Color, Font, HorizontalAlignment, MouseCursor, Point, Rectangle,
+    Size, VerticalAlignment,
 };
 
 use std::f32;
@@ -19,10 +19,32 @@ impl text::Renderer for Renderer {
         content: &str,
         size: u16,
         font: Font,
+        format: text::Format,
         bounds: Size,
     ) -> (f32, f32) {
-        self.text_pipeline
-            .measure(content, f32::from(size), font, bounds)
+        self.text_pipeline.measure(
+            content,
+            f32::from(size),
+            font,
+            format,
+            bounds,
+        )
+    }
+
+    fn ascent(&self, size: u16, font: Font, format: text::Format) -> f32 {
+        self.text_pipeline.ascent(f32::from(size), font, format)
+    }
+
+    fn measure_fragments(
+        &self,
+        fragments: &[text::Fragment<'_>],
+        bounds: Size,
+    ) -> (f32, f32) {
+        self.text_pipeline.measure_fragments(fragments, bounds)
+    }
+
+    fn hit_test(&self, paragraph: &text::Paragraph<'_>, point: Point) -> usize {
+        self.text_pipeline.hit_test(paragraph, point)
     }
 
     fn draw(
@@ -31,6 +53,7 @@ impl text::Renderer for Renderer {
         content: &str,
         size: u16,
         font: Font,
+        format: text::Format,
         color: Option<Color>,
         horizontal_alignment: HorizontalAlignment,
         vertical_alignment: VerticalAlignment,
@@ -42,6 +65,7 @@ impl text::Renderer for Renderer {
                 bounds,
                 color: color.unwrap_or(Color::BLACK),
                 font,
+                format,
                 horizontal_alignment,
                 vertical_alignment,
             },
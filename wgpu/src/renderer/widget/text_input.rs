@@ -1,8 +1,8 @@
 use crate::{Primitive, Renderer};
 
 use iced_native::{
-    text_input, Background, Color, Font, HorizontalAlignment, MouseCursor,
-    Point, Rectangle, Size, Vector, VerticalAlignment,
+    text, text_input, Background, Color, Font, HorizontalAlignment,
+    MouseCursor, Point, Rectangle, Size, Vector, VerticalAlignment,
 };
 use std::f32;
 
@@ -12,6 +12,28 @@ impl text_input::Renderer for Renderer {
         20
     }
 
+    fn offset(
+        &self,
+        text_bounds: Rectangle,
+        size: u16,
+        value: &text_input::Value,
+        state: &text_input::State,
+    ) -> f32 {
+        if state.is_focused() {
+            let (_, offset) = measure_cursor_and_scroll_offset(
+                self,
+                text_bounds,
+                value,
+                f32::from(size),
+                state.cursor_position(value),
+            );
+
+            offset
+        } else {
+            0.0
+        }
+    }
+
     fn draw(
         &mut self,
         bounds: Rectangle,
@@ -64,6 +86,7 @@ impl text_input::Renderer for Renderer {
             }
             .into(),
             font: Font::Default,
+            format: text::Format::default(),
             bounds: Rectangle {
                 width: f32::INFINITY,
                 ..text_bounds
@@ -74,24 +97,14 @@ impl text_input::Renderer for Renderer {
         };
 
         let (contents_primitive, offset) = if state.is_focused() {
-            let text_before_cursor =
-                value.until(state.cursor_position(value)).to_string();
-
-            let (mut text_value_width, _) = self.text_pipeline.measure(
-                &text_before_cursor,
+            let (text_value_width, offset) = measure_cursor_and_scroll_offset(
+                self,
+                text_bounds,
+                value,
                 size,
-                Font::Default,
-                Size::new(f32::INFINITY, text_bounds.height),
+                state.cursor_position(value),
             );
 
-            let spaces_at_the_end =
-                text_before_cursor.len() - text_before_cursor.trim_end().len();
-
-            if spaces_at_the_end > 0 {
-                let space_width = self.text_pipeline.space_width(size);
-                text_value_width += spaces_at_the_end as f32 * space_width;
-            }
-
             let cursor = Primitive::Quad {
                 bounds: Rectangle {
                     x: text_bounds.x + text_value_width,
@@ -107,11 +120,7 @@ impl text_input::Renderer for Renderer {
                 Primitive::Group {
                     primitives: vec![text_value, cursor],
                 },
-                Vector::new(
-                    ((text_value_width + 5.0) - text_bounds.width).max(0.0)
-                        as u32,
-                    0,
-                ),
+                Vector::new(offset as u32, 0),
             )
         } else {
             (text_value, Vector::new(0, 0))
@@ -135,3 +144,33 @@ impl text_input::Renderer for Renderer {
         )
     }
 }
+
+fn measure_cursor_and_scroll_offset(
+    renderer: &Renderer,
+    text_bounds: Rectangle,
+    value: &text_input::Value,
+    size: f32,
+    cursor_index: usize,
+) -> (f32, f32) {
+    let text_before_cursor = value.until(cursor_index).to_string();
+
+    let (mut text_value_width, _) = renderer.text_pipeline.measure(
+        &text_before_cursor,
+        size,
+        Font::Default,
+        text::Format::default(),
+        Size::new(f32::INFINITY, text_bounds.height),
+    );
+
+    let spaces_at_the_end =
+        text_before_cursor.len() - text_before_cursor.trim_end().len();
+
+    if spaces_at_the_end > 0 {
+        let space_width = renderer.text_pipeline.space_width(size);
+        text_value_width += spaces_at_the_end as f32 * space_width;
+    }
+
+    let offset = ((text_value_width + 5.0) - text_bounds.width).max(0.0);
+
+    (text_value_width, offset)
+}
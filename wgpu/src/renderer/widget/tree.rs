@@ -0,0 +1,68 @@
+use crate::{Primitive, Renderer};
+use iced_native::{
+    text, tree, Background, Color, Font, HorizontalAlignment, MouseCursor,
+    VerticalAlignment,
+};
+
+const ARROW_SIZE: f32 = 14.0;
+
+impl tree::Renderer for Renderer {
+    fn draw(
+        &mut self,
+        rows: &[tree::Row],
+        (labels, mouse_cursor): Self::Output,
+    ) -> Self::Output {
+        let mut primitives = Vec::new();
+        let mut is_mouse_over_toggle = false;
+
+        for row in rows {
+            let background: Option<Color> = if row.is_selected {
+                Some([0.8, 0.87, 1.0].into())
+            } else if row.is_hovered {
+                Some([0.95, 0.95, 0.95].into())
+            } else {
+                None
+            };
+
+            if let Some(background) = background {
+                primitives.push(Primitive::Quad {
+                    bounds: row.bounds,
+                    background: Background::Color(background),
+                    border_radius: 0,
+                });
+            }
+
+            if row.is_expandable {
+                if row.is_hovered {
+                    is_mouse_over_toggle = true;
+                }
+
+                primitives.push(Primitive::Text {
+                    content: String::from(if row.is_expanded {
+                        "▾"
+                    } else {
+                        "▸"
+                    }),
+                    bounds: row.toggle_bounds,
+                    color: [0.3, 0.3, 0.3].into(),
+                    size: ARROW_SIZE,
+                    font: Font::Default,
+                    format: text::Format::default(),
+                    horizontal_alignment: HorizontalAlignment::Center,
+                    vertical_alignment: VerticalAlignment::Center,
+                });
+            }
+        }
+
+        primitives.push(labels);
+
+        (
+            Primitive::Group { primitives },
+            if is_mouse_over_toggle && mouse_cursor < MouseCursor::Pointer {
+                MouseCursor::Pointer
+            } else {
+                mouse_cursor
+            },
+        )
+    }
+}
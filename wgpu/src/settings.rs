@@ -0,0 +1,21 @@
+/// The settings of a [`Renderer`].
+///
+/// [`Renderer`]: struct.Renderer.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Settings {
+    /// The maximum amount of memory, in bytes, that can be used to keep
+    /// decoded images, either in the GPU or waiting to be uploaded.
+    ///
+    /// When the budget is exceeded, the least recently used images are
+    /// evicted at the end of a frame. Images used in the current frame are
+    /// never evicted.
+    pub image_cache_budget: usize,
+}
+
+impl Default for Settings {
+    fn default() -> Settings {
+        Settings {
+            image_cache_budget: 64 * 1024 * 1024,
+        }
+    }
+}
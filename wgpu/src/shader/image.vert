@@ -3,6 +3,8 @@
 layout(location = 0) in vec2 v_Pos;
 layout(location = 1) in vec2 i_Pos;
 layout(location = 2) in vec2 i_Scale;
+layout(location = 3) in vec2 i_Atlas_Pos;
+layout(location = 4) in vec2 i_Atlas_Scale;
 
 layout (set = 0, binding = 0) uniform Globals {
     mat4 u_Transform;
@@ -11,7 +13,7 @@ layout (set = 0, binding = 0) uniform Globals {
 layout(location = 0) out vec2 o_Uv;
 
 void main() {
-    o_Uv = v_Pos;
+    o_Uv = i_Atlas_Pos + v_Pos * i_Atlas_Scale;
 
     mat4 i_Transform = mat4(
         vec4(i_Scale.x, 0.0, 0.0, 0.0),
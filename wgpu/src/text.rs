@@ -1,8 +1,20 @@
+mod cache;
 mod font;
+mod layout;
+mod registry;
 
 use crate::Transformation;
+use iced_native::{
+    text::{Format, Fragment, Paragraph},
+    HorizontalAlignment, Point, Rectangle, VerticalAlignment,
+};
+
+pub use cache::Stats;
+pub use layout::Layout;
 
-use std::{cell::RefCell, collections::HashMap};
+use registry::{Entry, Registry};
+
+use std::cell::RefCell;
 
 pub const BUILTIN_ICONS: iced_native::Font = iced_native::Font::External {
     name: "iced_wgpu icons",
@@ -14,9 +26,9 @@ pub const CHECKMARK_ICON: char = '\u{F00C}';
 #[derive(Debug)]
 pub struct Pipeline {
     draw_brush: RefCell<wgpu_glyph::GlyphBrush<'static, ()>>,
-    draw_font_map: RefCell<HashMap<String, wgpu_glyph::FontId>>,
-
     measure_brush: RefCell<glyph_brush::GlyphBrush<'static, ()>>,
+    registry: RefCell<Registry>,
+    measurement_cache: RefCell<cache::Cache>,
 }
 
 impl Pipeline {
@@ -32,23 +44,32 @@ impl Pipeline {
             .load(&[font::Family::Monospace])
             .expect("Find monospace font");
 
-        let draw_brush =
-            wgpu_glyph::GlyphBrushBuilder::using_fonts_bytes(vec![
-                mono_font,
-                default_font.clone(),
-            ])
-            .initial_cache_size((2048, 2048))
-            .build(device, wgpu::TextureFormat::Bgra8UnormSrgb);
+        let draw_brush = wgpu_glyph::GlyphBrushBuilder::using_fonts(vec![
+            mono_font,
+            default_font.clone(),
+        ])
+        .initial_cache_size((2048, 2048))
+        .build(device, wgpu::TextureFormat::Bgra8UnormSrgb);
 
         let measure_brush =
-            glyph_brush::GlyphBrushBuilder::using_font_bytes(default_font)
-                .build();
+            glyph_brush::GlyphBrushBuilder::using_font(default_font).build();
+
+        // The overlay font is only available in the `draw_brush`
+        let mut registry = Registry::default();
+
+        registry.insert_default(
+            iced_native::Weight::Normal,
+            Entry {
+                draw: wgpu_glyph::FontId(1),
+                measure: wgpu_glyph::FontId(0),
+            },
+        );
 
         Pipeline {
             draw_brush: RefCell::new(draw_brush),
-            draw_font_map: RefCell::new(HashMap::new()),
-
             measure_brush: RefCell::new(measure_brush),
+            registry: RefCell::new(registry),
+            measurement_cache: RefCell::new(cache::Cache::default()),
         }
     }
 
@@ -56,8 +77,14 @@ impl Pipeline {
         wgpu_glyph::FontId(0)
     }
 
-    pub fn queue(&mut self, section: wgpu_glyph::Section<'_>) {
-        self.draw_brush.borrow_mut().queue(section);
+    pub fn queue(
+        &mut self,
+        section: wgpu_glyph::VariedSection<'_>,
+        layout: &Layout,
+    ) {
+        self.draw_brush
+            .borrow_mut()
+            .queue_custom_layout(section, layout);
     }
 
     pub fn draw_queued(
@@ -85,34 +112,270 @@ impl Pipeline {
         content: &str,
         size: f32,
         font: iced_native::Font,
+        format: Format,
+        bounds: iced_native::Size,
+    ) -> (f32, f32) {
+        let font_id = self.find_font(font).measure;
+
+        let key = cache::Key {
+            content,
+            size,
+            font: font_id,
+            format,
+            bounds,
+        };
+
+        self.measurement_cache.borrow_mut().measure(key, || {
+            let section = wgpu_glyph::SectionText {
+                text: content,
+                scale: wgpu_glyph::Scale { x: size, y: size },
+                font_id,
+                ..Default::default()
+            };
+
+            self.measure_sections(
+                &Layout::new(format, size),
+                &[section],
+                bounds,
+            )
+        })
+    }
+
+    pub fn measure_fragments(
+        &self,
+        fragments: &[Fragment<'_>],
+        bounds: iced_native::Size,
+    ) -> (f32, f32) {
+        self.measure_sections(
+            &Layout::default(),
+            &self.measure_text(fragments),
+            bounds,
+        )
+    }
+
+    pub fn ascent(
+        &self,
+        size: f32,
+        font: iced_native::Font,
+        format: Format,
+    ) -> f32 {
+        let font_id = self.find_font(font).measure;
+        let measure_brush = self.measure_brush.borrow();
+
+        let v_metrics = measure_brush.fonts()[font_id.0]
+            .v_metrics(wgpu_glyph::Scale { x: size, y: size });
+
+        // The first line is laid out exactly like in `Layout`
+        let leading = match format.line_height {
+            Some(line_height) => {
+                (line_height * size - (v_metrics.ascent - v_metrics.descent))
+                    / 2.0
+            }
+            None => 0.0,
+        };
+
+        leading + v_metrics.ascent
+    }
+
+    fn measure_sections(
+        &self,
+        layout: &Layout,
+        sections: &[wgpu_glyph::SectionText<'_>],
         bounds: iced_native::Size,
     ) -> (f32, f32) {
         use wgpu_glyph::GlyphCruncher;
 
-        let wgpu_glyph::FontId(font_id) = self.find_font(font);
-
-        let section = wgpu_glyph::Section {
-            text: content,
-            scale: wgpu_glyph::Scale { x: size, y: size },
-            bounds: (bounds.width, bounds.height),
-
-            // TODO: This is a bit hacky. We are loading the debug font as the
-            // first font in the `draw_brush`. The `measure_brush` does not
-            // contain this, hence we subtract 1.
-            //
-            // This should go away once we unify `draw_brush` and
-            // `measure_brush`.
-            font_id: wgpu_glyph::FontId(font_id - 1),
-            ..Default::default()
+        let measure_brush = self.measure_brush.borrow();
+
+        let (width, height) = layout.measure(
+            &measure_brush.fonts(),
+            (bounds.width, bounds.height),
+            sections,
+        );
+
+        (width.ceil(), height.ceil())
+    }
+
+    pub fn regions(
+        &self,
+        fragments: &[Fragment<'_>],
+        bounds: Rectangle,
+        horizontal_alignment: HorizontalAlignment,
+    ) -> Vec<Vec<Region>> {
+        use wgpu_glyph::{GlyphCruncher, GlyphPositioner};
+
+        let (x, h_align) = match horizontal_alignment {
+            HorizontalAlignment::Left => {
+                (bounds.x, wgpu_glyph::HorizontalAlign::Left)
+            }
+            HorizontalAlignment::Center => (
+                bounds.x + bounds.width / 2.0,
+                wgpu_glyph::HorizontalAlign::Center,
+            ),
+            HorizontalAlignment::Right => {
+                (bounds.x + bounds.width, wgpu_glyph::HorizontalAlign::Right)
+            }
         };
 
-        if let Some(bounds) =
-            self.measure_brush.borrow_mut().glyph_bounds(section)
-        {
-            (bounds.width().ceil(), bounds.height().ceil())
-        } else {
-            (0.0, 0.0)
+        let text = self.measure_text(fragments);
+
+        let measure_brush = self.measure_brush.borrow();
+        let fonts = measure_brush.fonts();
+
+        let glyphs = Layout::default().h_align(h_align).calculate_glyphs(
+            &fonts,
+            &wgpu_glyph::SectionGeometry {
+                screen_position: (x, bounds.y),
+                bounds: (bounds.width, bounds.height),
+            },
+            &text,
+        );
+
+        let mut regions = vec![Vec::new(); fragments.len()];
+
+        for (glyph, color, wgpu_glyph::FontId(font_id)) in glyphs {
+            let fragment = color[0] as usize;
+
+            let position = glyph.position();
+            let advance = glyph.unpositioned().h_metrics().advance_width;
+            let v_metrics = fonts[font_id].v_metrics(glyph.scale());
+
+            let fragment_regions: &mut Vec<Region> = &mut regions[fragment];
+
+            match fragment_regions.last_mut() {
+                // Glyphs of the same fragment sharing a baseline belong to the
+                // same line
+                Some(region) if region.baseline == position.y => {
+                    region.bounds.width =
+                        position.x + advance - region.bounds.x;
+                }
+                _ => fragment_regions.push(Region {
+                    bounds: Rectangle {
+                        x: position.x,
+                        y: position.y - v_metrics.ascent,
+                        width: advance,
+                        height: v_metrics.ascent - v_metrics.descent,
+                    },
+                    baseline: position.y,
+                }),
+            }
         }
+
+        regions
+    }
+
+    pub fn hit_test(&self, paragraph: &Paragraph<'_>, point: Point) -> usize {
+        use wgpu_glyph::GlyphCruncher;
+
+        let (layout, geometry, section) = self.paragraph(paragraph);
+        let measure_brush = self.measure_brush.borrow();
+
+        layout.hit_test(
+            &measure_brush.fonts(),
+            &geometry,
+            &[section],
+            (point.x, point.y),
+        )
+    }
+
+    pub fn selection(
+        &self,
+        paragraph: &Paragraph<'_>,
+        range: (usize, usize),
+    ) -> Vec<Rectangle> {
+        use wgpu_glyph::GlyphCruncher;
+
+        let (layout, geometry, section) = self.paragraph(paragraph);
+        let measure_brush = self.measure_brush.borrow();
+
+        layout
+            .selection(&measure_brush.fonts(), &geometry, &[section], range)
+            .into_iter()
+            .map(|rect| Rectangle {
+                x: rect.min.x,
+                y: rect.min.y,
+                width: rect.width(),
+                height: rect.height(),
+            })
+            .collect()
+    }
+
+    fn paragraph<'a>(
+        &self,
+        paragraph: &Paragraph<'a>,
+    ) -> (
+        Layout,
+        wgpu_glyph::SectionGeometry,
+        wgpu_glyph::SectionText<'a>,
+    ) {
+        let bounds = paragraph.bounds;
+        let size = f32::from(paragraph.size);
+
+        let (x, h_align) = match paragraph.horizontal_alignment {
+            HorizontalAlignment::Left => {
+                (bounds.x, wgpu_glyph::HorizontalAlign::Left)
+            }
+            HorizontalAlignment::Center => (
+                bounds.x + bounds.width / 2.0,
+                wgpu_glyph::HorizontalAlign::Center,
+            ),
+            HorizontalAlignment::Right => {
+                (bounds.x + bounds.width, wgpu_glyph::HorizontalAlign::Right)
+            }
+        };
+
+        let (y, v_align) = match paragraph.vertical_alignment {
+            VerticalAlignment::Top => {
+                (bounds.y, wgpu_glyph::VerticalAlign::Top)
+            }
+            VerticalAlignment::Center => (
+                bounds.y + bounds.height / 2.0,
+                wgpu_glyph::VerticalAlign::Center,
+            ),
+            VerticalAlignment::Bottom => {
+                (bounds.y + bounds.height, wgpu_glyph::VerticalAlign::Bottom)
+            }
+        };
+
+        (
+            Layout::new(paragraph.format, size)
+                .h_align(h_align)
+                .v_align(v_align),
+            wgpu_glyph::SectionGeometry {
+                screen_position: (x, y),
+                bounds: (bounds.width, bounds.height),
+            },
+            wgpu_glyph::SectionText {
+                text: paragraph.content,
+                scale: wgpu_glyph::Scale { x: size, y: size },
+                font_id: self.find_font(paragraph.font).measure,
+                ..Default::default()
+            },
+        )
+    }
+
+    fn measure_text<'a>(
+        &self,
+        fragments: &[Fragment<'a>],
+    ) -> Vec<wgpu_glyph::SectionText<'a>> {
+        fragments
+            .iter()
+            .enumerate()
+            .map(|(i, fragment)| {
+                let size = f32::from(fragment.size);
+
+                wgpu_glyph::SectionText {
+                    text: fragment.content,
+                    scale: wgpu_glyph::Scale { x: size, y: size },
+                    // The color is never drawn when measuring, so we use it
+                    // to trace every glyph back to its fragment
+                    color: [i as f32, 0.0, 0.0, 1.0],
+                    font_id: self
+                        .find_weighted_font(fragment.font, fragment.weight)
+                        .measure,
+                }
+            })
+            .collect()
     }
 
     pub fn space_width(&self, size: f32) -> f32 {
@@ -129,7 +392,9 @@ impl Pipeline {
             .advance_width
     }
 
-    pub fn clear_measurement_cache(&mut self) {
+    pub fn trim_measurement_cache(&mut self) {
+        self.measurement_cache.borrow_mut().trim();
+
         // TODO: We should probably use a `GlyphCalculator` for this. However,
         // it uses a lifetimed `GlyphCalculatorGuard` with side-effects on drop.
         // This makes stuff quite inconvenient. A manual method for trimming the
@@ -141,27 +406,159 @@ impl Pipeline {
             .expect("Trim text measurements");
     }
 
-    pub fn find_font(&self, font: iced_native::Font) -> wgpu_glyph::FontId {
+    pub fn measurement_stats(&self) -> Stats {
+        self.measurement_cache.borrow().stats()
+    }
+
+    pub fn load_font(
+        &mut self,
+        id: iced_native::font::Id,
+        name: String,
+        bytes: Vec<u8>,
+    ) {
+        let properties = font::read_properties(&bytes).unwrap_or_default();
+
+        match self.add_font(bytes) {
+            Ok(entry) => self
+                .registry
+                .borrow_mut()
+                .insert_loaded(id, name, properties, entry),
+            Err(error) => log::warn!("Invalid font \"{}\": {}", name, error),
+        }
+    }
+
+    pub fn find_font(&self, font: iced_native::Font) -> Entry {
+        self.find_weighted_font(font, iced_native::Weight::Normal)
+    }
+
+    pub fn find_weighted_font(
+        &self,
+        font: iced_native::Font,
+        weight: iced_native::Weight,
+    ) -> Entry {
+        let default = || {
+            self.registry
+                .borrow()
+                .default_font(iced_native::Weight::Normal)
+                .expect("Default font")
+        };
+
         match font {
-            iced_native::Font::Default => wgpu_glyph::FontId(1),
+            iced_native::Font::Default => {
+                if let Some(entry) = self.registry.borrow().default_font(weight)
+                {
+                    return entry;
+                }
+
+                let entry = font::Source::new()
+                    .load_weighted(
+                        &[font::Family::SansSerif, font::Family::Serif],
+                        weight,
+                    )
+                    .map(|font| self.add_parsed_font(font))
+                    .unwrap_or_else(|_| default());
+
+                self.registry.borrow_mut().insert_default(weight, entry);
+
+                entry
+            }
+            // External and loaded fonts are a single face, so their weight is
+            // fixed
             iced_native::Font::External { name, bytes } => {
-                if let Some(font_id) = self.draw_font_map.borrow().get(name) {
-                    return *font_id;
+                if let Some(entry) =
+                    self.registry.borrow().external_font(name, bytes)
+                {
+                    return entry;
                 }
 
-                // TODO: Find a way to share font data
-                let _ = self.measure_brush.borrow_mut().add_font_bytes(bytes);
+                let entry = self.add_font(bytes).unwrap_or_else(|error| {
+                    log::warn!("Invalid font \"{}\": {}", name, error);
 
-                let font_id =
-                    self.draw_brush.borrow_mut().add_font_bytes(bytes);
+                    default()
+                });
 
-                let _ = self
-                    .draw_font_map
+                self.registry
                     .borrow_mut()
-                    .insert(String::from(name), font_id);
+                    .insert_external(name, bytes, entry);
+
+                entry
+            }
+            iced_native::Font::Loaded(id) => {
+                if let Some(entry) = self.registry.borrow().loaded_font(id) {
+                    return entry;
+                }
+
+                // The font failed to load, so we remember the fallback to
+                // only warn once
+                log::warn!("Unknown loaded font: {:?}", id);
+
+                let entry = default();
 
-                font_id
+                self.registry.borrow_mut().insert_unknown(id, entry);
+
+                entry
+            }
+            iced_native::Font::Descriptor(descriptor) => {
+                let descriptor = if weight == iced_native::Weight::Normal {
+                    descriptor
+                } else {
+                    descriptor.weight(weight)
+                };
+
+                // Fonts loaded at runtime take precedence over the ones
+                // installed in the system. The closest face of the family is
+                // used.
+                if let iced_native::font::Family::Name(name) = descriptor.family
+                {
+                    if let Some(entry) = self
+                        .registry
+                        .borrow()
+                        .named_font(name, &font::properties(descriptor))
+                    {
+                        return entry;
+                    }
+                }
+
+                if let Some(entry) =
+                    self.registry.borrow().descriptor_font(&descriptor)
+                {
+                    return entry;
+                }
+
+                let entry = font::Source::new()
+                    .load_descriptor(descriptor)
+                    .map(|font| self.add_parsed_font(font))
+                    .unwrap_or_else(|_| default());
+
+                self.registry
+                    .borrow_mut()
+                    .insert_descriptor(descriptor, entry);
+
+                entry
             }
         }
     }
+
+    // Parses the font once and shares its data between both brushes
+    fn add_font(
+        &self,
+        bytes: impl Into<glyph_brush::rusttype::SharedBytes<'static>>,
+    ) -> Result<Entry, glyph_brush::rusttype::Error> {
+        let font = glyph_brush::rusttype::Font::from_bytes(bytes)?;
+
+        Ok(self.add_parsed_font(font))
+    }
+
+    fn add_parsed_font(&self, font: font::Font) -> Entry {
+        Entry {
+            draw: self.draw_brush.borrow_mut().add_font(font.clone()),
+            measure: self.measure_brush.borrow_mut().add_font(font),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Region {
+    pub bounds: Rectangle,
+    pub baseline: f32,
 }
@@ -0,0 +1,127 @@
+use glyph_brush::FontId;
+use iced_native::{text::Format, Size};
+
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+
+/// A cache of text measurements.
+///
+/// Measurements are keyed by everything that can change the size of some
+/// text. The ones that were not used since the last `trim` are evicted, so
+/// the cache only holds the text of the current layout.
+#[derive(Debug, Default)]
+pub struct Cache {
+    entries: HashMap<u64, Entry>,
+    frame: u64,
+    hits: usize,
+    misses: usize,
+    stats: Stats,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Entry {
+    size: (f32, f32),
+    last_used: u64,
+}
+
+/// The hits and misses of a [`Cache`] during the last frame.
+///
+/// [`Cache`]: struct.Cache.html
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Stats {
+    pub hits: usize,
+    pub misses: usize,
+    pub entries: usize,
+}
+
+/// The text to measure.
+#[derive(Debug, Clone, Copy)]
+pub struct Key<'a> {
+    pub content: &'a str,
+    pub size: f32,
+    pub font: FontId,
+    pub format: Format,
+    pub bounds: Size,
+}
+
+impl Key<'_> {
+    fn id(&self) -> u64 {
+        let mut hasher = iced_native::Hasher::default();
+
+        self.content.hash(&mut hasher);
+        self.size.to_bits().hash(&mut hasher);
+        self.font.hash(&mut hasher);
+        self.format.wrap.hash(&mut hasher);
+        self.format.overflow.hash(&mut hasher);
+        self.format.line_height.map(f32::to_bits).hash(&mut hasher);
+        self.format.letter_spacing.to_bits().hash(&mut hasher);
+        self.bounds.width.to_bits().hash(&mut hasher);
+        self.bounds.height.to_bits().hash(&mut hasher);
+
+        hasher.finish()
+    }
+}
+
+impl Cache {
+    /// Returns the measurement of the given [`Key`], computing it with the
+    /// given closure if it is not cached.
+    ///
+    /// [`Key`]: struct.Key.html
+    pub fn measure(
+        &mut self,
+        key: Key<'_>,
+        measure: impl FnOnce() -> (f32, f32),
+    ) -> (f32, f32) {
+        let id = key.id();
+        let frame = self.frame;
+
+        match self.entries.get_mut(&id) {
+            Some(entry) => {
+                self.hits += 1;
+                entry.last_used = frame;
+
+                entry.size
+            }
+            None => {
+                self.misses += 1;
+
+                let size = measure();
+
+                let _ = self.entries.insert(
+                    id,
+                    Entry {
+                        size,
+                        last_used: frame,
+                    },
+                );
+
+                size
+            }
+        }
+    }
+
+    /// Evicts the measurements that were not used during the current frame,
+    /// and starts a new one.
+    pub fn trim(&mut self) {
+        let frame = self.frame;
+
+        self.entries.retain(|_, entry| entry.last_used == frame);
+
+        self.stats = Stats {
+            hits: self.hits,
+            misses: self.misses,
+            entries: self.entries.len(),
+        };
+
+        self.hits = 0;
+        self.misses = 0;
+        self.frame += 1;
+    }
+
+    /// Returns the [`Stats`] of the last frame.
+    ///
+    /// [`Stats`]: struct.Stats.html
+    pub fn stats(&self) -> Stats {
+        self.stats
+    }
+}
@@ -1,5 +1,12 @@
-pub use font_kit::error::SelectionError as LoadError;
 pub use font_kit::family_name::FamilyName as Family;
+pub use font_kit::properties::Properties;
+
+use glyph_brush::rusttype;
+use iced_native::font::{self, Descriptor};
+
+use std::sync::Arc;
+
+pub type Font = rusttype::Font<'static>;
 
 pub struct Source {
     raw: font_kit::source::SystemSource,
@@ -12,25 +19,144 @@ impl Source {
         }
     }
 
-    pub fn load(&self, families: &[Family]) -> Result<Vec<u8>, LoadError> {
-        let font = self.raw.select_best_match(
+    pub fn load(&self, families: &[Family]) -> Result<Font, LoadError> {
+        self.load_weighted(families, iced_native::Weight::Normal)
+    }
+
+    pub fn load_weighted(
+        &self,
+        families: &[Family],
+        weight: iced_native::Weight,
+    ) -> Result<Font, LoadError> {
+        self.load_properties(
             families,
-            &font_kit::properties::Properties::default(),
-        )?;
+            &font_kit::properties::Properties {
+                weight: font_kit::properties::Weight(f32::from(weight.value())),
+                ..font_kit::properties::Properties::default()
+            },
+        )
+    }
+
+    pub fn load_descriptor(
+        &self,
+        descriptor: Descriptor,
+    ) -> Result<Font, LoadError> {
+        let family = match descriptor.family {
+            font::Family::Name(name) => Family::Title(String::from(name)),
+            font::Family::Serif => Family::Serif,
+            font::Family::SansSerif => Family::SansSerif,
+            font::Family::Monospace => Family::Monospace,
+            font::Family::Cursive => Family::Cursive,
+            font::Family::Fantasy => Family::Fantasy,
+        };
 
-        match font {
-            font_kit::handle::Handle::Path { path, .. } => {
-                use std::io::Read;
+        // When the family is not installed, we keep the properties and fall
+        // back to the families of the default font
+        self.load_properties(
+            &[family, Family::SansSerif, Family::Serif],
+            &properties(descriptor),
+        )
+    }
 
-                let mut buf = Vec::new();
-                let mut reader = std::fs::File::open(path).expect("Read font");
-                let _ = reader.read_to_end(&mut buf);
+    fn load_properties(
+        &self,
+        families: &[Family],
+        properties: &font_kit::properties::Properties,
+    ) -> Result<Font, LoadError> {
+        let font = self.raw.select_best_match(families, properties)?;
 
-                Ok(buf)
+        // Font collections contain many faces, so we need to pick the one
+        // that was matched
+        let (bytes, index) = match font {
+            font_kit::handle::Handle::Path { path, font_index } => {
+                (std::fs::read(path)?, font_index)
             }
-            font_kit::handle::Handle::Memory { bytes, .. } => {
-                Ok(bytes.as_ref().clone())
+            font_kit::handle::Handle::Memory { bytes, font_index } => {
+                (bytes.as_ref().clone(), font_index)
             }
+        };
+
+        let font = rusttype::FontCollection::from_bytes(bytes)?
+            .font_at(index as usize)?;
+
+        Ok(font)
+    }
+}
+
+/// Returns the properties of the fonts matching the given [`Descriptor`].
+///
+/// [`Descriptor`]: ../../../iced_native/font/struct.Descriptor.html
+pub fn properties(descriptor: Descriptor) -> Properties {
+    let style = match descriptor.style {
+        font::Style::Normal => font_kit::properties::Style::Normal,
+        font::Style::Italic => font_kit::properties::Style::Italic,
+        font::Style::Oblique => font_kit::properties::Style::Oblique,
+    };
+
+    Properties {
+        weight: font_kit::properties::Weight(f32::from(
+            descriptor.weight.value(),
+        )),
+        stretch: font_kit::properties::Stretch(descriptor.stretch.value()),
+        style,
+    }
+}
+
+/// Reads the properties of the first face of the given font data.
+pub fn read_properties(bytes: &[u8]) -> Option<Properties> {
+    font_kit::loaders::default::Font::from_bytes(Arc::new(bytes.to_vec()), 0)
+        .ok()
+        .map(|font| font.properties())
+}
+
+/// Returns the index of the given faces that best matches the given
+/// properties, if any.
+pub fn best_match(
+    faces: &[Properties],
+    properties: &Properties,
+) -> Option<usize> {
+    font_kit::matching::find_best_match(faces, properties).ok()
+}
+
+/// An error that occurred while loading a font from the system.
+#[derive(Debug)]
+pub enum LoadError {
+    /// No font matched the requested properties.
+    Selection(font_kit::error::SelectionError),
+
+    /// The file of the matched font could not be read.
+    Io(std::io::Error),
+
+    /// The data of the matched font is invalid.
+    Invalid(rusttype::Error),
+}
+
+impl std::fmt::Display for LoadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LoadError::Selection(error) => write!(f, "{:?}", error),
+            LoadError::Io(error) => write!(f, "{}", error),
+            LoadError::Invalid(error) => write!(f, "{}", error),
         }
     }
 }
+
+impl std::error::Error for LoadError {}
+
+impl From<font_kit::error::SelectionError> for LoadError {
+    fn from(error: font_kit::error::SelectionError) -> LoadError {
+        LoadError::Selection(error)
+    }
+}
+
+impl From<std::io::Error> for LoadError {
+    fn from(error: std::io::Error) -> LoadError {
+        LoadError::Io(error)
+    }
+}
+
+impl From<rusttype::Error> for LoadError {
+    fn from(error: rusttype::Error) -> LoadError {
+        LoadError::Invalid(error)
+    }
+}
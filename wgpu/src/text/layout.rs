@@ -0,0 +1,712 @@
+use glyph_brush::{
+    rusttype::{point, PositionedGlyph, Rect, Scale, ScaledGlyph},
+    FontId, FontMap, GlyphPositioner, HorizontalAlign, SectionGeometry,
+    SectionText, VerticalAlign,
+};
+use iced_native::{text::Format, Overflow, Wrap};
+
+use std::hash::{Hash, Hasher};
+
+const ELLIPSIS: char = '\u{2026}';
+
+/// A [`GlyphPositioner`] supporting the different wrapping and overflow
+/// strategies of a `Text`, custom line heights and letter spacing.
+///
+/// Both measuring and drawing use it, so they always agree on how the text is
+/// broken and truncated.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Layout {
+    wrap: Wrap,
+    overflow: Overflow,
+    // Relative to the size of the text, so the layout does not change when
+    // the DPI factor is applied to a section.
+    line_height: Option<f32>,
+    letter_spacing: f32,
+    h_align: HorizontalAlign,
+    v_align: VerticalAlign,
+}
+
+impl Layout {
+    pub fn new(format: Format, size: f32) -> Self {
+        Layout {
+            wrap: format.wrap,
+            overflow: format.overflow,
+            line_height: format.line_height,
+            letter_spacing: if size > 0.0 {
+                format.letter_spacing / size
+            } else {
+                0.0
+            },
+            ..Layout::default()
+        }
+    }
+
+    pub fn h_align(mut self, h_align: HorizontalAlign) -> Self {
+        self.h_align = h_align;
+        self
+    }
+
+    pub fn v_align(mut self, v_align: VerticalAlign) -> Self {
+        self.v_align = v_align;
+        self
+    }
+
+    /// Returns the size of the given sections once laid out.
+    pub fn measure<'font, F: FontMap<'font>>(
+        &self,
+        fonts: &F,
+        bounds: (f32, f32),
+        sections: &[SectionText<'_>],
+    ) -> (f32, f32) {
+        let lines = self.lines(fonts, bounds, sections);
+
+        let width = lines.iter().map(Line::width).fold(0.0, f32::max);
+        let height = lines.iter().map(|line| line.height).sum();
+
+        match self.overflow {
+            Overflow::Clip => (width.min(bounds.0), height.min(bounds.1)),
+            _ => (width, height),
+        }
+    }
+
+    /// Returns the index of the character boundary closest to the given
+    /// point, which is where a caret should be placed when clicking it.
+    pub fn hit_test<'font, F: FontMap<'font>>(
+        &self,
+        fonts: &F,
+        geometry: &SectionGeometry,
+        sections: &[SectionText<'_>],
+        (x, y): (f32, f32),
+    ) -> usize {
+        let lines = self.place(fonts, geometry, sections);
+
+        let line = match lines
+            .iter()
+            .find(|line| y < line.top + line.line.height)
+            .or_else(|| lines.last())
+        {
+            Some(line) => line,
+            None => return 0,
+        };
+
+        let mut end = line.line.start;
+
+        for (character, left) in line.characters() {
+            if let Some(index) = character.index {
+                if x < left + character.advance / 2.0 {
+                    return index;
+                }
+
+                end = index + 1;
+            }
+        }
+
+        end
+    }
+
+    /// Returns the rectangles covering the characters in the given range,
+    /// one per line.
+    pub fn selection<'font, F: FontMap<'font>>(
+        &self,
+        fonts: &F,
+        geometry: &SectionGeometry,
+        sections: &[SectionText<'_>],
+        (start, end): (usize, usize),
+    ) -> Vec<Rect<f32>> {
+        self.place(fonts, geometry, sections)
+            .iter()
+            .filter_map(|line| {
+                let selected: Vec<_> = line
+                    .characters()
+                    .into_iter()
+                    .filter(|(character, _)| {
+                        character
+                            .index
+                            .map(|index| start <= index && index < end)
+                            .unwrap_or(false)
+                    })
+                    .collect();
+
+                let (first, left) = selected.first()?;
+                let (last, right) = selected.last()?;
+
+                Some(Rect {
+                    min: point(left - first.kerning, line.top),
+                    max: point(
+                        right + last.advance,
+                        line.top + line.line.height,
+                    ),
+                })
+            })
+            .collect()
+    }
+
+    fn place<'font, F: FontMap<'font>>(
+        &self,
+        fonts: &F,
+        geometry: &SectionGeometry,
+        sections: &[SectionText<'_>],
+    ) -> Vec<Placed<'font>> {
+        let (x, y) = geometry.screen_position;
+        let lines = self.lines(fonts, geometry.bounds, sections);
+
+        let height: f32 = lines.iter().map(|line| line.height).sum();
+
+        let mut top = match self.v_align {
+            VerticalAlign::Top => y,
+            VerticalAlign::Center => y - height / 2.0,
+            VerticalAlign::Bottom => y - height,
+        };
+
+        lines
+            .into_iter()
+            .map(|line| {
+                let width = line.width();
+
+                let left = match self.h_align {
+                    HorizontalAlign::Left => x,
+                    HorizontalAlign::Center => x - width / 2.0,
+                    HorizontalAlign::Right => x - width,
+                };
+
+                let placed = Placed { line, top, left };
+
+                top += placed.line.height;
+
+                placed
+            })
+            .collect()
+    }
+
+    fn lines<'font, F: FontMap<'font>>(
+        &self,
+        fonts: &F,
+        (max_width, max_height): (f32, f32),
+        sections: &[SectionText<'_>],
+    ) -> Vec<Line<'font>> {
+        if sections.iter().all(|section| section.text.is_empty()) {
+            return Vec::new();
+        }
+
+        let mut lines = Vec::new();
+        let mut paragraph = Vec::new();
+        let mut last = None;
+
+        // The index of the first character of the paragraph of every line,
+        // used to locate empty lines
+        let mut starts = Vec::new();
+        let mut start = 0;
+        let mut index = 0;
+
+        for section in sections {
+            let font = fonts.font(section.font_id);
+
+            for c in section.text.chars() {
+                match c {
+                    '\n' => {
+                        self.wrap_paragraph(&paragraph, max_width, &mut lines);
+                        starts.resize(lines.len(), start);
+                        paragraph.clear();
+
+                        start = index + 1;
+                    }
+                    '\r' => {}
+                    _ => {
+                        let glyph = font.glyph(c).scaled(section.scale);
+
+                        let kerning = match paragraph.last() {
+                            Some(previous) if previous.is_like(section) => font
+                                .pair_kerning(
+                                    section.scale,
+                                    previous.character,
+                                    c,
+                                ),
+                            _ => 0.0,
+                        };
+
+                        paragraph.push(Character {
+                            advance: glyph.h_metrics().advance_width
+                                + self.letter_spacing * section.scale.x,
+                            kerning,
+                            character: c,
+                            index: Some(index),
+                            glyph,
+                            color: section.color,
+                            font_id: section.font_id,
+                            scale: section.scale,
+                        });
+                    }
+                }
+
+                index += 1;
+            }
+
+            last = Some(section);
+        }
+
+        self.wrap_paragraph(&paragraph, max_width, &mut lines);
+        starts.resize(lines.len(), start);
+
+        // Empty lines take the metrics of the last section
+        let (font_id, scale) = match last {
+            Some(section) => (section.font_id, section.scale),
+            None => return Vec::new(),
+        };
+
+        let mut lines: Vec<Line<'font>> = lines
+            .into_iter()
+            .zip(starts)
+            .map(|(characters, paragraph_start)| {
+                Line::new(
+                    characters,
+                    paragraph_start,
+                    fonts,
+                    font_id,
+                    scale,
+                    self.line_height,
+                )
+            })
+            .collect();
+
+        let is_ellipsis = match self.overflow {
+            Overflow::Ellipsis | Overflow::MiddleEllipsis => true,
+            _ => false,
+        };
+
+        if !is_ellipsis {
+            return lines;
+        }
+
+        // Drop the lines that do not fit vertically, keeping at least one
+        let mut height = 0.0;
+        let mut visible = 0;
+
+        for line in &lines {
+            height += line.height;
+
+            if visible > 0 && height > max_height {
+                break;
+            }
+
+            visible += 1;
+        }
+
+        let is_truncated = visible < lines.len();
+        lines.truncate(visible);
+
+        let last = lines.len() - 1;
+
+        for (i, line) in lines.iter_mut().enumerate() {
+            if i == last && is_truncated {
+                line.truncate_end(fonts, max_width, true);
+            } else if line.width() > max_width {
+                match self.overflow {
+                    Overflow::MiddleEllipsis => {
+                        line.truncate_middle(fonts, max_width)
+                    }
+                    _ => line.truncate_end(fonts, max_width, false),
+                }
+            }
+        }
+
+        lines
+    }
+
+    fn wrap_paragraph<'font>(
+        &self,
+        paragraph: &[Character<'font>],
+        max_width: f32,
+        lines: &mut Vec<Vec<Character<'font>>>,
+    ) {
+        let mut line: Vec<Character<'font>> = Vec::new();
+        let mut width = 0.0;
+
+        match self.wrap {
+            Wrap::None => {
+                line.extend(paragraph.iter().cloned());
+            }
+            Wrap::Character => {
+                for character in paragraph {
+                    let fits = width + character.kerning + character.advance
+                        <= max_width;
+
+                    if !line.is_empty()
+                        && !fits
+                        && !character.character.is_whitespace()
+                    {
+                        lines.push(std::mem::replace(&mut line, Vec::new()));
+                        width = 0.0;
+                    }
+
+                    let mut character = character.clone();
+
+                    if line.is_empty() {
+                        character.kerning = 0.0;
+                    }
+
+                    width += character.kerning + character.advance;
+                    line.push(character);
+                }
+            }
+            Wrap::Word => {
+                let mut start = 0;
+
+                while start < paragraph.len() {
+                    // A word is a run of visible characters followed by the
+                    // whitespace after it, which can hang past the line end
+                    let visible = paragraph[start..]
+                        .iter()
+                        .position(|c| c.character.is_whitespace())
+                        .map(|i| start + i)
+                        .unwrap_or(paragraph.len());
+
+                    let end = paragraph[visible..]
+                        .iter()
+                        .position(|c| !c.character.is_whitespace())
+                        .map(|i| visible + i)
+                        .unwrap_or(paragraph.len());
+
+                    let word_width: f32 = paragraph[start..visible]
+                        .iter()
+                        .map(|c| c.kerning + c.advance)
+                        .sum();
+
+                    if !line.is_empty() && width + word_width > max_width {
+                        lines.push(std::mem::replace(&mut line, Vec::new()));
+                        width = 0.0;
+                    }
+
+                    for character in &paragraph[start..end] {
+                        let mut character = character.clone();
+
+                        if line.is_empty() {
+                            character.kerning = 0.0;
+                        }
+
+                        width += character.kerning + character.advance;
+                        line.push(character);
+                    }
+
+                    start = end;
+                }
+            }
+        }
+
+        lines.push(line);
+    }
+}
+
+impl Default for Layout {
+    fn default() -> Self {
+        Layout {
+            wrap: Wrap::Word,
+            overflow: Overflow::Visible,
+            line_height: None,
+            letter_spacing: 0.0,
+            h_align: HorizontalAlign::Left,
+            v_align: VerticalAlign::Top,
+        }
+    }
+}
+
+impl Hash for Layout {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.wrap.hash(state);
+        self.overflow.hash(state);
+        self.line_height.map(f32::to_bits).hash(state);
+        self.letter_spacing.to_bits().hash(state);
+        self.h_align.hash(state);
+        self.v_align.hash(state);
+    }
+}
+
+impl GlyphPositioner for Layout {
+    fn calculate_glyphs<'font, F: FontMap<'font>>(
+        &self,
+        fonts: &F,
+        geometry: &SectionGeometry,
+        sections: &[SectionText<'_>],
+    ) -> Vec<(PositionedGlyph<'font>, [f32; 4], FontId)> {
+        let mut glyphs = Vec::new();
+
+        for line in self.place(fonts, geometry, sections) {
+            let baseline = line.top + line.line.leading + line.line.ascent;
+
+            for (character, x) in line.characters() {
+                glyphs.push((
+                    character.glyph.clone().positioned(point(x, baseline)),
+                    character.color,
+                    character.font_id,
+                ));
+            }
+        }
+
+        glyphs
+    }
+
+    fn bounds_rect(&self, geometry: &SectionGeometry) -> Rect<f32> {
+        use std::f32;
+
+        if self.overflow == Overflow::Visible {
+            return Rect {
+                min: point(f32::NEG_INFINITY, f32::NEG_INFINITY),
+                max: point(f32::INFINITY, f32::INFINITY),
+            };
+        }
+
+        let (x, y) = geometry.screen_position;
+        let (width, height) = geometry.bounds;
+
+        let x = match self.h_align {
+            HorizontalAlign::Left => x,
+            HorizontalAlign::Center => x - width / 2.0,
+            HorizontalAlign::Right => x - width,
+        };
+
+        let y = match self.v_align {
+            VerticalAlign::Top => y,
+            VerticalAlign::Center => y - height / 2.0,
+            VerticalAlign::Bottom => y - height,
+        };
+
+        Rect {
+            min: point(x, y),
+            max: point(x + width, y + height),
+        }
+    }
+}
+
+#[derive(Clone)]
+struct Character<'font> {
+    character: char,
+    // `None` for the characters of an ellipsis
+    index: Option<usize>,
+    glyph: ScaledGlyph<'font>,
+    kerning: f32,
+    advance: f32,
+    color: [f32; 4],
+    font_id: FontId,
+    scale: Scale,
+}
+
+impl<'font> Character<'font> {
+    fn is_like(&self, section: &SectionText<'_>) -> bool {
+        self.font_id == section.font_id && self.scale == section.scale
+    }
+}
+
+struct Line<'font> {
+    characters: Vec<Character<'font>>,
+    start: usize,
+    ascent: f32,
+    leading: f32,
+    height: f32,
+}
+
+/// A line positioned on the screen.
+struct Placed<'font> {
+    line: Line<'font>,
+    top: f32,
+    left: f32,
+}
+
+impl<'font> Placed<'font> {
+    /// Returns the characters of the line together with the horizontal
+    /// position of their glyphs.
+    fn characters(&self) -> Vec<(&Character<'font>, f32)> {
+        let mut x = self.left;
+
+        self.line
+            .characters
+            .iter()
+            .map(|character| {
+                x += character.kerning;
+
+                let position = x;
+
+                x += character.advance;
+
+                (character, position)
+            })
+            .collect()
+    }
+}
+
+impl<'font> Line<'font> {
+    fn new<F: FontMap<'font>>(
+        characters: Vec<Character<'font>>,
+        paragraph_start: usize,
+        fonts: &F,
+        font_id: FontId,
+        scale: Scale,
+        line_height: Option<f32>,
+    ) -> Self {
+        let metrics = |font_id, scale| fonts.font(font_id).v_metrics(scale);
+
+        let (ascent, descent, line_gap, size) = if characters.is_empty() {
+            let v_metrics = metrics(font_id, scale);
+
+            (
+                v_metrics.ascent,
+                v_metrics.descent,
+                v_metrics.line_gap,
+                scale.y,
+            )
+        } else {
+            characters.iter().fold(
+                (0.0, 0.0, 0.0, 0.0),
+                |(ascent, descent, line_gap, size): (f32, f32, f32, f32),
+                 character| {
+                    let v_metrics = metrics(character.font_id, character.scale);
+
+                    (
+                        ascent.max(v_metrics.ascent),
+                        descent.min(v_metrics.descent),
+                        line_gap.max(v_metrics.line_gap),
+                        size.max(character.scale.y),
+                    )
+                },
+            )
+        };
+
+        let (leading, height) = match line_height {
+            Some(line_height) => {
+                let height = line_height * size;
+
+                ((height - (ascent - descent)) / 2.0, height)
+            }
+            None => (0.0, ascent - descent + line_gap),
+        };
+
+        let start = characters
+            .first()
+            .and_then(|c| c.index)
+            .unwrap_or(paragraph_start);
+
+        Line {
+            characters,
+            start,
+            ascent,
+            leading,
+            height,
+        }
+    }
+
+    /// Returns the width of the line, ignoring trailing whitespace.
+    fn width(&self) -> f32 {
+        let visible = self
+            .characters
+            .iter()
+            .rposition(|c| !c.character.is_whitespace())
+            .map(|i| i + 1)
+            .unwrap_or(0);
+
+        self.characters[..visible]
+            .iter()
+            .map(|c| c.kerning + c.advance)
+            .sum()
+    }
+
+    fn truncate_end<F: FontMap<'font>>(
+        &mut self,
+        fonts: &F,
+        max_width: f32,
+        force: bool,
+    ) {
+        if !force && self.width() <= max_width {
+            return;
+        }
+
+        let ellipsis = match self.characters.last() {
+            Some(last) => ellipsis(fonts, last),
+            None => return,
+        };
+
+        let ellipsis_width: f32 = ellipsis.iter().map(|c| c.advance).sum();
+
+        while !self.characters.is_empty()
+            && self.width() + ellipsis_width > max_width
+        {
+            let _ = self.characters.pop();
+        }
+
+        while self
+            .characters
+            .last()
+            .map(|c| c.character.is_whitespace())
+            .unwrap_or(false)
+        {
+            let _ = self.characters.pop();
+        }
+
+        self.characters.extend(ellipsis);
+    }
+
+    fn truncate_middle<F: FontMap<'font>>(
+        &mut self,
+        fonts: &F,
+        max_width: f32,
+    ) {
+        let ellipsis = match self.characters.last() {
+            Some(last) => ellipsis(fonts, last),
+            None => return,
+        };
+
+        let ellipsis_width: f32 = ellipsis.iter().map(|c| c.advance).sum();
+
+        let mut tail = self.characters.split_off(self.characters.len() / 2);
+
+        let width = |characters: &[Character<'_>]| -> f32 {
+            characters.iter().map(|c| c.kerning + c.advance).sum()
+        };
+
+        while !(self.characters.is_empty() && tail.is_empty())
+            && width(&self.characters) + ellipsis_width + width(&tail)
+                > max_width
+        {
+            if self.characters.len() >= tail.len() {
+                let _ = self.characters.pop();
+            } else {
+                let _ = tail.remove(0);
+            }
+        }
+
+        if let Some(first) = tail.first_mut() {
+            first.kerning = 0.0;
+        }
+
+        self.characters.extend(ellipsis);
+        self.characters.extend(tail);
+    }
+}
+
+/// Builds an ellipsis in the style of the given character, falling back to
+/// three dots when the font does not have a glyph for it.
+fn ellipsis<'font, F: FontMap<'font>>(
+    fonts: &F,
+    style: &Character<'font>,
+) -> Vec<Character<'font>> {
+    let font = fonts.font(style.font_id);
+
+    let (character, count) = if font.glyph(ELLIPSIS).id().0 == 0 {
+        ('.', 3)
+    } else {
+        (ELLIPSIS, 1)
+    };
+
+    let glyph = font.glyph(character).scaled(style.scale);
+    let advance = glyph.h_metrics().advance_width;
+
+    (0..count)
+        .map(|_| Character {
+            character,
+            index: None,
+            glyph: glyph.clone(),
+            kerning: 0.0,
+            advance,
+            color: style.color,
+            font_id: style.font_id,
+            scale: style.scale,
+        })
+        .collect()
+}
@@ -0,0 +1,107 @@
+use super::font::{self, Properties};
+
+use glyph_brush::FontId;
+use iced_native::{
+    font::{Descriptor, Id},
+    Weight,
+};
+
+use std::collections::HashMap;
+
+/// The fonts known by the text [`Pipeline`].
+///
+/// Every font is added to both the draw and the measure brushes, which give
+/// it different ids. The registry remembers both.
+///
+/// [`Pipeline`]: ../struct.Pipeline.html
+#[derive(Debug, Default)]
+pub struct Registry {
+    defaults: HashMap<Weight, Entry>,
+    // External fonts are keyed by name and data, as different fonts may
+    // share the same name
+    external: HashMap<(&'static str, usize), Entry>,
+    loaded: HashMap<Id, Entry>,
+    // Many faces of a family can be loaded at runtime under the same name
+    named: HashMap<String, Vec<(Properties, Entry)>>,
+    descriptors: HashMap<Descriptor, Entry>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Entry {
+    pub draw: FontId,
+    pub measure: FontId,
+}
+
+impl Registry {
+    pub fn default_font(&self, weight: Weight) -> Option<Entry> {
+        self.defaults.get(&weight).copied()
+    }
+
+    pub fn external_font(
+        &self,
+        name: &'static str,
+        bytes: &'static [u8],
+    ) -> Option<Entry> {
+        self.external.get(&(name, bytes.as_ptr() as usize)).copied()
+    }
+
+    pub fn loaded_font(&self, id: Id) -> Option<Entry> {
+        self.loaded.get(&id).copied()
+    }
+
+    pub fn named_font(
+        &self,
+        name: &str,
+        properties: &Properties,
+    ) -> Option<Entry> {
+        let faces = self.named.get(name)?;
+
+        let candidates: Vec<Properties> =
+            faces.iter().map(|(properties, _)| *properties).collect();
+
+        let index = font::best_match(&candidates, properties)?;
+
+        Some(faces[index].1)
+    }
+
+    pub fn descriptor_font(&self, descriptor: &Descriptor) -> Option<Entry> {
+        self.descriptors.get(descriptor).copied()
+    }
+
+    pub fn insert_default(&mut self, weight: Weight, entry: Entry) {
+        let _ = self.defaults.insert(weight, entry);
+    }
+
+    pub fn insert_external(
+        &mut self,
+        name: &'static str,
+        bytes: &'static [u8],
+        entry: Entry,
+    ) {
+        let _ = self.external.insert((name, bytes.as_ptr() as usize), entry);
+    }
+
+    pub fn insert_loaded(
+        &mut self,
+        id: Id,
+        name: String,
+        properties: Properties,
+        entry: Entry,
+    ) {
+        let _ = self.loaded.insert(id, entry);
+
+        let faces = self.named.entry(name).or_insert_with(Vec::new);
+
+        // A face with the same properties replaces the previous one
+        faces.retain(|(face, _)| *face != properties);
+        faces.push((properties, entry));
+    }
+
+    pub fn insert_unknown(&mut self, id: Id, entry: Entry) {
+        let _ = self.loaded.insert(id, entry);
+    }
+
+    pub fn insert_descriptor(&mut self, descriptor: Descriptor, entry: Entry) {
+        let _ = self.descriptors.insert(descriptor, entry);
+    }
+}
@@ -1,9 +1,9 @@
 use crate::{
-    conversion,
+    command, conversion,
     input::{keyboard, mouse},
     renderer::{Target, Windowed},
-    Cache, Command, Container, Debug, Element, Event, Length, MouseCursor,
-    Settings, UserInterface,
+    Cache, Clipboard, Command, Container, Debug, Element, Event, Length,
+    MouseCursor, Settings, UserInterface,
 };
 
 /// An interactive, native cross-platform application.
@@ -71,8 +71,14 @@ pub trait Application: Sized {
     ///
     /// It should probably be that last thing you call in your `main` function.
     ///
+    /// The renderer settings are used to create the [`Renderer`].
+    ///
     /// [`Application`]: trait.Application.html
-    fn run(settings: Settings)
+    /// [`Renderer`]: #associatedtype.Renderer
+    fn run(
+        settings: Settings,
+        renderer_settings: <Self::Renderer as Windowed>::Settings,
+    )
     where
         Self: 'static,
     {
@@ -92,7 +98,6 @@ pub trait Application: Sized {
         let mut external_messages = Vec::new();
 
         let (mut application, init_command) = Self::new();
-        spawn(init_command, &mut thread_pool, &proxy);
 
         let mut title = application.title();
 
@@ -112,7 +117,10 @@ pub trait Application: Sized {
         let mut size = window.inner_size();
         let mut new_size: Option<winit::dpi::LogicalSize> = None;
 
-        let mut renderer = Self::Renderer::new();
+        let mut renderer = Self::Renderer::new(renderer_settings);
+        let clipboard = Clipboard::new();
+
+        let _ = spawn(init_command, &mut thread_pool, &proxy, &mut renderer);
 
         let mut target = {
             let (width, height) = to_physical(size, dpi);
@@ -137,6 +145,7 @@ pub trait Application: Sized {
         let mut cache = Some(user_interface.into_cache());
         let mut events = Vec::new();
         let mut mouse_cursor = MouseCursor::OutOfBounds;
+        let mut redraw_at = None;
         debug.startup_finished();
 
         window.request_redraw();
@@ -157,8 +166,11 @@ pub trait Application: Sized {
                 debug.layout_finished();
 
                 debug.event_processing_started();
-                let mut messages =
-                    user_interface.update(&renderer, events.drain(..));
+                let mut messages = user_interface.update(
+                    &renderer,
+                    clipboard.as_ref().map(|c| c as _),
+                    events.drain(..),
+                );
                 messages.extend(external_messages.drain(..));
                 debug.event_processing_finished();
 
@@ -171,7 +183,8 @@ pub trait Application: Sized {
                 } else {
                     // When there are messages, we are forced to rebuild twice
                     // for now :^)
-                    let temp_cache = user_interface.into_cache();
+                    let mut temp_cache = user_interface.into_cache();
+                    let mut has_loaded_fonts = false;
 
                     for message in messages {
                         log::debug!("Updating");
@@ -181,10 +194,21 @@ pub trait Application: Sized {
                         debug.update_started();
                         let command = application.update(message);
 
-                        spawn(command, &mut thread_pool, &proxy);
+                        has_loaded_fonts |= spawn(
+                            command,
+                            &mut thread_pool,
+                            &proxy,
+                            &mut renderer,
+                        );
                         debug.update_finished();
                     }
 
+                    // New fonts may change the size of any text, so we need
+                    // to lay out everything again
+                    if has_loaded_fonts {
+                        temp_cache = Cache::default();
+                    }
+
                     // Update window title
                     let new_title = application.title();
 
@@ -231,8 +255,10 @@ pub trait Application: Sized {
                     size = new_size;
                 }
 
+                let overlay = debug.overlay(&renderer.debug_info());
+
                 let new_mouse_cursor =
-                    renderer.draw(&primitive, &debug.overlay(), &mut target);
+                    renderer.draw(&primitive, &overlay, &mut target);
 
                 debug.render_finished();
 
@@ -244,8 +270,7 @@ pub trait Application: Sized {
                     mouse_cursor = new_mouse_cursor;
                 }
 
-                // TODO: Handle animations!
-                // Maybe we can use `ControlFlow::WaitUntil` for this.
+                redraw_at = renderer.take_redraw_request();
             }
             event::Event::WindowEvent {
                 event: window_event,
@@ -300,6 +325,7 @@ pub trait Application: Sized {
                         winit::event::KeyboardInput {
                             virtual_keycode: Some(virtual_keycode),
                             state,
+                            modifiers,
                             ..
                         },
                     ..
@@ -315,6 +341,7 @@ pub trait Application: Sized {
                     events.push(Event::Keyboard(keyboard::Event::Input {
                         key_code: conversion::key_code(virtual_keycode),
                         state: conversion::button_state(state),
+                        modifiers: conversion::modifiers_state(modifiers),
                     }));
                 }
                 WindowEvent::CloseRequested => {
@@ -328,7 +355,10 @@ pub trait Application: Sized {
                 _ => {}
             },
             _ => {
-                *control_flow = ControlFlow::Wait;
+                *control_flow = match redraw_at {
+                    Some(at) => ControlFlow::WaitUntil(at),
+                    None => ControlFlow::Wait,
+                };
             }
         })
     }
@@ -362,26 +392,48 @@ where
         .into()
 }
 
-fn spawn<Message: Send>(
+// Returns whether the command loaded any fonts
+fn spawn<Message: Send, Renderer: Windowed>(
     command: Command<Message>,
     thread_pool: &mut futures::executor::ThreadPool,
     proxy: &winit::event_loop::EventLoopProxy<Message>,
-) {
+    renderer: &mut Renderer,
+) -> bool {
     use futures::FutureExt;
 
-    let futures = command.futures();
+    let mut has_loaded_fonts = false;
 
-    for future in futures {
-        let proxy = proxy.clone();
+    for action in command.actions() {
+        match action {
+            command::Action::Future(future) => {
+                let proxy = proxy.clone();
 
-        let future = future.map(move |message| {
-            proxy
-                .send_event(message)
-                .expect("Send command result to event loop");
-        });
+                let future = future.map(move |message| {
+                    proxy
+                        .send_event(message)
+                        .expect("Send command result to event loop");
+                });
 
-        thread_pool.spawn_ok(future);
+                thread_pool.spawn_ok(future);
+            }
+            command::Action::LoadFont {
+                id,
+                name,
+                bytes,
+                message,
+            } => {
+                renderer.load_font(id, name, bytes);
+
+                proxy
+                    .send_event(message)
+                    .expect("Send font message to event loop");
+
+                has_loaded_fonts = true;
+            }
+        }
     }
+
+    has_loaded_fonts
 }
 
 // As defined in: http://www.unicode.org/faq/private_use.html
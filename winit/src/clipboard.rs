@@ -0,0 +1,29 @@
+use clipboard::{ClipboardContext, ClipboardProvider};
+use std::cell::RefCell;
+
+/// A buffer for short-term storage and transfer within and between
+/// applications, backed by the system clipboard.
+#[allow(missing_debug_implementations)]
+pub struct Clipboard(RefCell<ClipboardContext>);
+
+impl Clipboard {
+    /// Creates a new [`Clipboard`] connected to the system clipboard, if
+    /// available.
+    ///
+    /// [`Clipboard`]: struct.Clipboard.html
+    pub fn new() -> Option<Clipboard> {
+        ClipboardContext::new()
+            .ok()
+            .map(|context| Clipboard(RefCell::new(context)))
+    }
+}
+
+impl iced_native::Clipboard for Clipboard {
+    fn content(&self) -> Option<String> {
+        self.0.borrow_mut().get_contents().ok()
+    }
+
+    fn write(&self, content: String) {
+        let _ = self.0.borrow_mut().set_contents(content);
+    }
+}
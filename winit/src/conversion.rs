@@ -3,7 +3,10 @@
 //! [`winit`]: https://github.com/rust-windowing/winit
 //! [`iced_native`]: https://github.com/hecrj/iced/tree/master/native
 use crate::{
-    input::{keyboard::KeyCode, mouse, ButtonState},
+    input::{
+        keyboard::{KeyCode, ModifiersState},
+        mouse, ButtonState,
+    },
     MouseCursor,
 };
 
@@ -19,6 +22,10 @@ pub fn mouse_cursor(mouse_cursor: MouseCursor) -> winit::window::CursorIcon {
         MouseCursor::Working => winit::window::CursorIcon::Progress,
         MouseCursor::Grab => winit::window::CursorIcon::Grab,
         MouseCursor::Grabbing => winit::window::CursorIcon::Grabbing,
+        MouseCursor::ResizingHorizontally => {
+            winit::window::CursorIcon::EwResize
+        }
+        MouseCursor::ResizingVertically => winit::window::CursorIcon::NsResize,
         MouseCursor::Text => winit::window::CursorIcon::Text,
     }
 }
@@ -47,6 +54,22 @@ pub fn button_state(element_state: winit::event::ElementState) -> ButtonState {
     }
 }
 
+/// Convert some `ModifiersState` from [`winit`] to an [`iced_native`]
+/// modifiers state.
+///
+/// [`winit`]: https://github.com/rust-windowing/winit
+/// [`iced_native`]: https://github.com/hecrj/iced/tree/master/native
+pub fn modifiers_state(
+    modifiers: winit::event::ModifiersState,
+) -> ModifiersState {
+    ModifiersState {
+        shift: modifiers.shift,
+        control: modifiers.ctrl,
+        alt: modifiers.alt,
+        logo: modifiers.logo,
+    }
+}
+
 /// Convert a `VirtualKeyCode` from [`winit`] to an [`iced_native`] key code.
 ///
 /// [`winit`]: https://github.com/rust-windowing/winit
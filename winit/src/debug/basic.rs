@@ -138,7 +138,7 @@ impl Debug {
         self.message_count += 1;
     }
 
-    pub fn overlay(&self) -> Vec<String> {
+    pub fn overlay(&self, renderer_info: &[String]) -> Vec<String> {
         if !self.is_enabled {
             return Vec::new();
         }
@@ -168,6 +168,7 @@ impl Debug {
             self.draw_durations.average(),
         ));
         lines.push(key_value("Render:", self.render_durations.average()));
+        lines.extend(renderer_info.iter().cloned());
         lines.push(key_value("Message count:", self.message_count));
         lines.push(String::from("Last messages:"));
         lines.extend(
@@ -42,7 +42,7 @@ impl Debug {
     ) {
     }
 
-    pub fn overlay(&self) -> Vec<String> {
+    pub fn overlay(&self, _renderer_info: &[String]) -> Vec<String> {
         Vec::new()
     }
 }
@@ -29,8 +29,10 @@ pub mod conversion;
 pub mod settings;
 
 mod application;
+mod clipboard;
 
 pub use application::Application;
+pub use clipboard::Clipboard;
 pub use settings::Settings;
 
 // We disable debug capabilities on release builds unless the `debug` feature